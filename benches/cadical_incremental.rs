@@ -0,0 +1,50 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+use cadical::statik::Cadical;
+use sat_nexus::core::solver::Solver;
+use sat_nexus::wrappers::cadical_static::CadicalStaticSolver;
+use sat_nexus_test_utils::{pigeonhole, time_bootstrap, time_bootstrap_and_solve};
+
+fn new_solver() -> CadicalStaticSolver {
+    CadicalStaticSolver::new(Cadical::new())
+}
+
+fn cadical_benches(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Cadical incremental");
+
+    // Clause-add throughput:
+    let cnf = pigeonhole(40, 40);
+    group.bench_function("bootstrap", |b| {
+        b.iter_batched(new_solver, |mut solver| time_bootstrap(&mut solver, &cnf), BatchSize::SmallInput)
+    });
+
+    // Bootstrap + solve of a fixed moderate UNSAT instance:
+    let cnf = pigeonhole(7, 6);
+    group.bench_function("bootstrap + solve", |b| {
+        b.iter_batched(
+            new_solver,
+            |mut solver| time_bootstrap_and_solve(&mut solver, &cnf),
+            BatchSize::SmallInput,
+        )
+    });
+
+    // Latency of repeated solves under assumptions on an already loaded solver:
+    let holes = 20;
+    let cnf = pigeonhole(holes, holes);
+    let mut solver = new_solver();
+    time_bootstrap(&mut solver, &cnf);
+    let mut k = 0;
+    group.bench_function("incremental solve", |b| {
+        b.iter(|| {
+            // Put pigeon 0 into the next hole:
+            solver.assume((k % holes + 1) as i32);
+            k += 1;
+            solver.solve()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, cadical_benches);
+criterion_main!(benches);
@@ -2,11 +2,12 @@ use std::fmt::{Display, Formatter};
 
 use itertools::Itertools;
 use rand::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use simple_sat::utils::DisplaySlice;
 use simple_sat::var::Var;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Backdoor {
     pub(crate) variables: Vec<Var>,
 }
@@ -28,6 +29,33 @@ impl Backdoor {
     }
 }
 
+/// Backdoor is serialized as a list of external (1-based) variables, e.g. `[3,1,7]`.
+impl Serialize for Backdoor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.variables.iter().map(|v| v.to_external()))
+    }
+}
+
+impl<'de> Deserialize<'de> for Backdoor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let vars = Vec::<u32>::deserialize(deserializer)?;
+        if vars.contains(&0) {
+            return Err(serde::de::Error::custom("backdoor variable cannot be zero"));
+        }
+        Ok(Self::new(vars.into_iter().map(Var::from_external).collect()))
+    }
+}
+
+impl Backdoor {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    pub fn from_json(s: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(s)
+    }
+}
+
 impl Display for Backdoor {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         if f.alternate() {
@@ -20,7 +20,7 @@ use backdoor::solver::Solver;
 use backdoor::utils::*;
 
 use cadical::statik::Cadical;
-use cadical::{LitValue, SolveResponse};
+use cadical::SolveResponse;
 use simple_sat::lit::Lit;
 use simple_sat::trie::Trie;
 use simple_sat::utils::{display_slice, parse_dimacs};
@@ -284,15 +284,7 @@ fn solve(args: Cli) -> color_eyre::Result<SolveResult> {
             }
             SolveResponse::Sat => {
                 info!("SAT in {:.1} s", time_solve.as_secs_f64());
-                let model = (1..=searcher.solver.0.vars())
-                    .map(|i| {
-                        let v = Var::from_external(i as u32);
-                        match searcher.solver.0.val(i as i32).unwrap() {
-                            LitValue::True => Lit::new(v, false),
-                            LitValue::False => Lit::new(v, true),
-                        }
-                    })
-                    .collect_vec();
+                let model = searcher.solver.model();
                 return Ok(SolveResult::SAT(model));
             }
         }
@@ -349,15 +341,7 @@ fn solve(args: Cli) -> color_eyre::Result<SolveResult> {
                     }
                     SolveResponse::Sat => {
                         info!("SAT in {:.1} s", time_solve.as_secs_f64());
-                        let model = (1..=searcher.solver.0.vars())
-                            .map(|i| {
-                                let v = Var::from_external(i as u32);
-                                match searcher.solver.0.val(i as i32).unwrap() {
-                                    LitValue::True => Lit::new(v, false),
-                                    LitValue::False => Lit::new(v, true),
-                                }
-                            })
-                            .collect_vec();
+                        let model = searcher.solver.model();
                         return Ok(SolveResult::SAT(model));
                     }
                 }
@@ -478,15 +462,7 @@ fn solve(args: Cli) -> color_eyre::Result<SolveResult> {
                     }
                     SolveResponse::Sat => {
                         info!("SAT in {:.1} s", time_solve.as_secs_f64());
-                        let model = (1..=searcher.solver.0.vars())
-                            .map(|i| {
-                                let v = Var::from_external(i as u32);
-                                match searcher.solver.0.val(i as i32).unwrap() {
-                                    LitValue::True => Lit::new(v, false),
-                                    LitValue::False => Lit::new(v, true),
-                                }
-                            })
-                            .collect_vec();
+                        let model = searcher.solver.model();
                         return Ok(SolveResult::SAT(model));
                     }
                 }
@@ -724,15 +700,7 @@ fn solve(args: Cli) -> color_eyre::Result<SolveResult> {
                     }
                     SolveResponse::Sat => {
                         info!("SAT in {:.1} s", time_solve.as_secs_f64());
-                        let model = (1..=searcher.solver.0.vars())
-                            .map(|i| {
-                                let v = Var::from_external(i as u32);
-                                match searcher.solver.0.val(i as i32).unwrap() {
-                                    LitValue::True => Lit::new(v, false),
-                                    LitValue::False => Lit::new(v, true),
-                                }
-                            })
-                            .collect_vec();
+                        let model = searcher.solver.model();
                         return Ok(SolveResult::SAT(model));
                     }
                 }
@@ -1008,15 +976,7 @@ fn solve(args: Cli) -> color_eyre::Result<SolveResult> {
                                     display_slice(&cubes_product[best_cube])
                                 );
                             }
-                            let model = (1..=searcher.solver.0.vars())
-                                .map(|i| {
-                                    let v = Var::from_external(i as u32);
-                                    match searcher.solver.0.val(i as i32).unwrap() {
-                                        LitValue::True => Lit::new(v, false),
-                                        LitValue::False => Lit::new(v, true),
-                                    }
-                                })
-                                .collect_vec();
+                            let model = searcher.solver.model();
                             final_model = Some(model);
                             break;
                         }
@@ -1126,15 +1086,7 @@ fn solve(args: Cli) -> color_eyre::Result<SolveResult> {
                         false
                     }
                     SolveResponse::Sat => {
-                        let model = (1..=searcher.solver.0.vars())
-                            .map(|i| {
-                                let v = Var::from_external(i as u32);
-                                match searcher.solver.0.val(i as i32).unwrap() {
-                                    LitValue::True => Lit::new(v, false),
-                                    LitValue::False => Lit::new(v, true),
-                                }
-                            })
-                            .collect_vec();
+                        let model = searcher.solver.model();
                         final_model = Some(model);
                         // TODO: break out of the outer loop (currently not possible due to closure in retain)
                         false
@@ -1203,15 +1155,7 @@ fn solve(args: Cli) -> color_eyre::Result<SolveResult> {
                 }
                 SolveResponse::Sat => {
                     info!("SAT in {:.1} s", time_solve.as_secs_f64());
-                    let model = (1..=searcher.solver.0.vars())
-                        .map(|i| {
-                            let v = Var::from_external(i as u32);
-                            match searcher.solver.0.val(i as i32).unwrap() {
-                                LitValue::True => Lit::new(v, false),
-                                LitValue::False => Lit::new(v, true),
-                            }
-                        })
-                        .collect_vec();
+                    let model = searcher.solver.model();
                     return Ok(SolveResult::SAT(model));
                 }
             }
@@ -1305,15 +1249,7 @@ fn solve(args: Cli) -> color_eyre::Result<SolveResult> {
             }
             SolveResponse::Sat => {
                 info!("SAT in {:.1} s", time_solve.as_secs_f64());
-                let model = (1..=searcher.solver.0.vars())
-                    .map(|i| {
-                        let v = Var::from_external(i as u32);
-                        match searcher.solver.0.val(i as i32).unwrap() {
-                            LitValue::True => Lit::new(v, false),
-                            LitValue::False => Lit::new(v, true),
-                        }
-                    })
-                    .collect_vec();
+                let model = searcher.solver.model();
                 return Ok(SolveResult::SAT(model));
             }
         }
@@ -8,7 +8,7 @@ use itertools::Itertools;
 use log::{debug, info};
 
 use backdoor::derivation::derive_clauses;
-use backdoor::searcher::{BackdoorSearcher, Options, DEFAULT_OPTIONS};
+use backdoor::searcher::{BackdoorSearcher, Checkpoint, Options, DEFAULT_OPTIONS};
 use backdoor::solver::Solver;
 use backdoor::utils::*;
 
@@ -48,6 +48,15 @@ struct Cli {
     #[arg(short = 'o', long = "output", value_name = "FILE")]
     path_output: Option<PathBuf>,
 
+    /// Path to a checkpoint file, updated after each EA run.
+    #[arg(long = "checkpoint", value_name = "FILE")]
+    path_checkpoint: Option<PathBuf>,
+
+    /// Resume the search from the checkpoint file.
+    /// Note: derived clauses are not stored in the checkpoint.
+    #[arg(long = "resume", value_name = "PATH")]
+    path_resume: Option<PathBuf>,
+
     /// Random seed.
     #[arg(long, value_name = "INT", default_value_t = DEFAULT_OPTIONS.seed)]
     seed: u64,
@@ -238,7 +247,25 @@ fn _main(args: &Cli) -> color_eyre::Result<()> {
         }
     }
 
-    for run_number in 1..=args.num_runs {
+    // Best backdoors from all runs:
+    let mut best_backdoors = Vec::new();
+    let mut first_run = 1;
+
+    // Resume from the checkpoint:
+    if let Some(path) = &args.path_resume {
+        let checkpoint = Checkpoint::load(path)?;
+        info!(
+            "Resuming from checkpoint '{}' after {} runs with {} backdoors",
+            path.display(),
+            checkpoint.generation,
+            checkpoint.best_backdoors.len()
+        );
+        searcher.restore(&checkpoint);
+        first_run = checkpoint.generation + 1;
+        best_backdoors = checkpoint.best_backdoors;
+    }
+
+    for run_number in first_run..=args.num_runs {
         info!("Run {} / {}", run_number, args.num_runs);
         let time_run = Instant::now();
 
@@ -493,6 +520,12 @@ fn _main(args: &Cli) -> color_eyre::Result<()> {
             )?;
         }
 
+        // Save the checkpoint:
+        best_backdoors.push(result.best_instance.clone());
+        if let Some(path) = &args.path_checkpoint {
+            searcher.checkpoint(run_number, best_backdoors.clone()).save(path)?;
+        }
+
         // Write the run records:
         if args.dump_records {
             let mut writer = csv::Writer::from_path(format!("run_{}.csv", run_number))?;
@@ -1,5 +1,10 @@
 use std::cmp::Ordering;
 
+use simple_sat::lit::Lit;
+
+use crate::backdoor::Backdoor;
+use crate::solver::Solver;
+
 #[derive(Debug, Clone)]
 pub struct Fitness {
     pub value: f64,
@@ -26,3 +31,115 @@ impl PartialOrd<Self> for Fitness {
         Some(self.cmp(other))
     }
 }
+
+/// Fitness function guiding the search for backdoors.
+pub trait FitnessFunction {
+    /// Fitness value of the `backdoor`, the lower the better.
+    fn evaluate(&self, backdoor: &Backdoor, solver: &Solver) -> f64;
+
+    /// Full fitness of the `backdoor`, including the [`rho`][Fitness::rho] and the number of hard tasks.
+    ///
+    /// The `best` fitness found so far can be used to cut the computation short.
+    fn calculate(&self, backdoor: &Backdoor, _best: Option<&Fitness>, solver: &Solver) -> Fitness {
+        let vars = backdoor.get_variables();
+        assert!(vars.len() < 32);
+        let num_hard = solver.propcheck_all_tree(&vars, 0);
+        let num_total = 1u64 << vars.len();
+        let rho = 1.0 - (num_hard as f64 / num_total as f64);
+        let value = self.evaluate(backdoor, solver);
+        Fitness { value, rho, num_hard }
+    }
+}
+
+/// Fraction of hard tasks (not refuted by unit propagation), i.e. `1 - rho`.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct RhoFitness;
+
+impl FitnessFunction for RhoFitness {
+    fn evaluate(&self, backdoor: &Backdoor, solver: &Solver) -> f64 {
+        self.calculate(backdoor, None, solver).value
+    }
+
+    fn calculate(&self, backdoor: &Backdoor, best: Option<&Fitness>, solver: &Solver) -> Fitness {
+        let vars = backdoor.get_variables();
+        assert!(vars.len() < 32);
+
+        // Compute rho:
+        // let limit = 0;
+        let limit = best.map_or(0, |b| b.num_hard + 1);
+        let num_hard = solver.propcheck_all_tree(&vars, limit);
+        let num_total = 1u64 << vars.len();
+        let rho = 1.0 - (num_hard as f64 / num_total as f64);
+
+        // Calculate the fitness value:
+        let value = 1.0 - rho;
+
+        Fitness { value, rho, num_hard }
+    }
+}
+
+/// Negated average number of literals propagated by each literal of the backdoor variables,
+/// i.e. favors the backdoors with the most propagating variables.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct PropagationFitness;
+
+impl FitnessFunction for PropagationFitness {
+    fn evaluate(&self, backdoor: &Backdoor, solver: &Solver) -> f64 {
+        let vars = backdoor.get_variables();
+        if vars.is_empty() {
+            return 0.0;
+        }
+        let mut total = 0;
+        for &var in vars.iter() {
+            for lit in [Lit::new(var, false), Lit::new(var, true)] {
+                let (_res, num_propagated) = solver.propcheck(&[lit]);
+                total += num_propagated;
+            }
+        }
+        -(total as f64 / (2 * vars.len()) as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cadical::statik::Cadical;
+    use itertools::Itertools;
+    use simple_sat::var::Var;
+
+    use super::*;
+
+    fn rank<F: FitnessFunction>(fitness_function: &F, candidates: &[Backdoor], solver: &Solver) -> Vec<usize> {
+        (0..candidates.len())
+            .sorted_by(|&i, &j| {
+                let a = fitness_function.evaluate(&candidates[i], solver);
+                let b = fitness_function.evaluate(&candidates[j], solver);
+                a.total_cmp(&b)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_fitness_functions() {
+        let cadical = Cadical::new();
+        // x1 propagates a lot, but never leads to a conflict:
+        for x in [2, 4, 5, 8, 9] {
+            cadical.add_clause([-1, x]);
+        }
+        cadical.add_clause([1, 6]);
+        // x3 propagates almost nothing, but its negation leads to a conflict:
+        cadical.add_clause([3, 7]);
+        cadical.add_clause([3, -7]);
+        let solver = Solver::new(cadical);
+
+        let candidates = [
+            Backdoor::new(vec![Var::from_external(1)]),
+            Backdoor::new(vec![Var::from_external(3)]),
+        ];
+
+        // Half of the tasks for {x3} are easy, while both tasks for {x1} are hard:
+        assert_eq!(RhoFitness.evaluate(&candidates[0], &solver), 1.0);
+        assert_eq!(RhoFitness.evaluate(&candidates[1], &solver), 0.5);
+        assert_eq!(rank(&RhoFitness, &candidates, &solver), [1, 0]);
+        assert_eq!(rank(&PropagationFitness, &candidates, &solver), [0, 1]);
+    }
+}
@@ -1,21 +1,26 @@
+use std::fs::File;
+use std::io;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
 use std::time::{Duration, Instant};
 
 use ahash::{AHashMap, AHashSet};
 use indicatif::ProgressIterator;
-use itertools::zip_eq;
+use itertools::{zip_eq, Itertools};
 use log::{debug, info, trace};
 use rand::distributions::{Bernoulli, Distribution};
 use rand::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use simple_sat::lit::Lit;
 use simple_sat::var::Var;
 
 use crate::backdoor::Backdoor;
-use crate::fitness::Fitness;
+use crate::fitness::{Fitness, FitnessFunction, RhoFitness};
 use crate::solver::Solver;
 
 #[derive(Debug)]
-pub struct BackdoorSearcher {
+pub struct BackdoorSearcher<F = RhoFitness> {
     pub solver: Solver,
     pub global_pool: Vec<Var>,
     pub banned_vars: AHashSet<Var>,
@@ -24,10 +29,17 @@ pub struct BackdoorSearcher {
     pub cache_hits: usize,
     pub cache_misses: usize,
     pub options: Options,
+    pub fitness_function: F,
 }
 
 impl BackdoorSearcher {
     pub fn new(solver: Solver, pool: Vec<Var>, options: Options) -> Self {
+        Self::with_fitness_function(solver, pool, options, RhoFitness)
+    }
+}
+
+impl<F: FitnessFunction> BackdoorSearcher<F> {
+    pub fn with_fitness_function(solver: Solver, pool: Vec<Var>, options: Options, fitness_function: F) -> Self {
         Self {
             solver,
             global_pool: pool,
@@ -37,6 +49,7 @@ impl BackdoorSearcher {
             cache_hits: 0,
             cache_misses: 0,
             options,
+            fitness_function,
         }
     }
 }
@@ -75,7 +88,53 @@ pub struct Record {
     pub time: Duration,
 }
 
-impl BackdoorSearcher {
+/// Snapshot of the search state between EA runs, which allows to resume the interrupted search.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// Random seed of the searcher.
+    pub seed: u64,
+    /// Number of completed EA runs.
+    pub generation: usize,
+    /// Best backdoors found so far, one per completed run.
+    pub best_backdoors: Vec<Backdoor>,
+    /// Banned variables (external, 1-based), see [`Options::ban_used_variables`].
+    pub banned_vars: Vec<u32>,
+}
+
+impl Checkpoint {
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(writer, self)?;
+        Ok(())
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let reader = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+}
+
+impl<F: FitnessFunction> BackdoorSearcher<F> {
+    pub fn checkpoint(&self, generation: usize, best_backdoors: Vec<Backdoor>) -> Checkpoint {
+        Checkpoint {
+            seed: self.options.seed,
+            generation,
+            best_backdoors,
+            banned_vars: self.banned_vars.iter().map(|v| v.to_external()).sorted().collect(),
+        }
+    }
+
+    /// Restore the state from the `checkpoint`.
+    ///
+    /// Note that the exact RNG state is not stored, so the RNG is re-seeded
+    /// from the stored seed and generation. Thus, the resumed search is reproducible,
+    /// but differs from the uninterrupted one.
+    pub fn restore(&mut self, checkpoint: &Checkpoint) {
+        self.options.seed = checkpoint.seed;
+        self.rng = StdRng::seed_from_u64(checkpoint.seed.wrapping_add(checkpoint.generation as u64));
+        self.banned_vars = checkpoint.banned_vars.iter().map(|&v| Var::from_external(v)).collect();
+    }
+
     pub fn run(
         &mut self,
         backdoor_size: usize,
@@ -291,7 +350,7 @@ impl BackdoorSearcher {
             fit.clone()
         } else {
             self.cache_misses += 1;
-            let fit = calculate_fitness(instance, best, &self.solver);
+            let fit = self.fitness_function.calculate(instance, best, &self.solver);
             self.cache.insert(key, fit.clone());
             fit
         }
@@ -302,23 +361,6 @@ impl BackdoorSearcher {
     }
 }
 
-fn calculate_fitness(instance: &Backdoor, best: Option<&Fitness>, solver: &Solver) -> Fitness {
-    let vars = instance.get_variables();
-    assert!(vars.len() < 32);
-
-    // Compute rho:
-    // let limit = 0;
-    let limit = best.map_or(0, |b| b.num_hard + 1);
-    let num_hard = solver.propcheck_all_tree(&vars, limit);
-    let num_total = 1u64 << vars.len();
-    let rho = 1.0 - (num_hard as f64 / num_total as f64);
-
-    // Calculate the fitness value:
-    let value = 1.0 - rho;
-
-    Fitness { value, rho, num_hard }
-}
-
 fn mutate(instance: &mut Backdoor, pool: &[Var], rng: &mut impl Rng) {
     assert!(pool.len() >= instance.len());
 
@@ -391,4 +433,27 @@ mod tests {
             assert_eq!(instance.len(), len);
         }
     }
+
+    #[test]
+    fn test_checkpoint_roundtrip() {
+        let vars = |xs: &[u32]| xs.iter().map(|&x| Var::from_external(x)).collect_vec();
+
+        let backdoor = Backdoor::new(vars(&[5, 2, 9]));
+        let json = backdoor.to_json();
+        assert_eq!(json, "[5,2,9]");
+        assert_eq!(Backdoor::from_json(&json).unwrap(), backdoor);
+        assert!(Backdoor::from_json("[1,0]").is_err());
+
+        let checkpoint = Checkpoint {
+            seed: 42,
+            generation: 3,
+            best_backdoors: vec![backdoor, Backdoor::new(vars(&[1, 3]))],
+            banned_vars: vec![2, 5, 9],
+        };
+        let json = serde_json::to_string(&checkpoint).unwrap();
+        let restored: Checkpoint = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.best_backdoors, checkpoint.best_backdoors);
+        assert_eq!(restored.generation, checkpoint.generation);
+        assert_eq!(restored, checkpoint);
+    }
 }
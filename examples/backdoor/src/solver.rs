@@ -1,5 +1,5 @@
 use cadical::statik::Cadical;
-use cadical::{FixedResponse, SolveResponse};
+use cadical::{FixedResponse, LitValue, SolveResponse};
 use simple_sat::lit::Lit;
 use simple_sat::var::Var;
 
@@ -46,6 +46,19 @@ impl Solver {
         self.0.failed(lit.to_external()).unwrap()
     }
 
+    /// Model found by the last `solve`, which must have returned SAT.
+    pub fn model(&self) -> Vec<Lit> {
+        (1..=self.0.vars())
+            .map(|i| {
+                let v = Var::from_external(i as u32);
+                match self.0.val(i as i32).unwrap() {
+                    LitValue::True => Lit::new(v, false),
+                    LitValue::False => Lit::new(v, true),
+                }
+            })
+            .collect()
+    }
+
     pub fn propcheck(&self, lits: &[Lit]) -> (bool, u64) {
         let lits_external = lits_to_external(lits);
         self.0.propcheck(&lits_external, false, false, false)
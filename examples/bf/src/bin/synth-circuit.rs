@@ -1,6 +1,6 @@
-use cadical::statik::Cadical;
-use cadical::SolveResponse;
 use sat_nexus_core::encoder::CnfEncoder;
+use sat_nexus_core::solver::{SolveResponse, Solver};
+use sat_nexus_wrappers::cadical_static::CadicalStaticSolver;
 
 use bf::encoding_circuit::encode_circuit_synthesis;
 use bf::table::TruthTable;
@@ -37,7 +37,7 @@ fn main() -> color_eyre::Result<()> {
     // println!("{}", encoder.to_dimacs());
 
     println!("Initializing SAT solver...");
-    let solver = Cadical::new();
+    let mut solver = CadicalStaticSolver::default();
 
     println!("Adding clauses...");
     for clause in encoder.clauses.iter().cloned() {
@@ -45,7 +45,7 @@ fn main() -> color_eyre::Result<()> {
     }
 
     println!("Solving...");
-    let res = solver.solve()?;
+    let res = solver.solve();
     println!("res = {}", res);
 
     if res == SolveResponse::Sat {
@@ -62,7 +62,7 @@ fn main() -> color_eyre::Result<()> {
         println!("VALUE:");
         for (&pin, pin_value_var) in vars.pin_value.iter() {
             for (i, cube) in vars.unique_cubes.iter().enumerate() {
-                let pin_value = solver.val(pin_value_var[i].get())?;
+                let pin_value = solver.value(pin_value_var[i]);
                 println!(
                     "value[{:?}][{}] = {:?}",
                     pin,
@@ -1,6 +1,6 @@
-use cadical::statik::Cadical;
-use cadical::SolveResponse;
 use sat_nexus_core::encoder::CnfEncoder;
+use sat_nexus_core::solver::{SolveResponse, Solver};
+use sat_nexus_wrappers::cadical_static::CadicalStaticSolver;
 
 use bf::encoding_formula::encode_boolean_synthesis;
 use bf::table::TruthTable;
@@ -34,7 +34,7 @@ fn main() -> color_eyre::Result<()> {
     // println!("{}", encoder.to_dimacs());
 
     println!("Initializing SAT solver...");
-    let solver = Cadical::new();
+    let mut solver = CadicalStaticSolver::default();
 
     println!("Adding clauses...");
     for clause in encoder.clauses.iter().cloned() {
@@ -42,7 +42,7 @@ fn main() -> color_eyre::Result<()> {
     }
 
     println!("Solving...");
-    let res = solver.solve()?;
+    let res = solver.solve();
     println!("res = {}", res);
 
     if res == SolveResponse::Sat {
@@ -69,7 +69,7 @@ fn main() -> color_eyre::Result<()> {
         // println!("VALUE:");
         // for (&(node, row), &value_var) in vars.value.iter() {
         //     let &(ref inputs, _) = &table.rows[row];
-        //     let value = solver.val(value_var)?;
+        //     let value = solver.value(value_var);
         //     println!(
         //         "value[{}][{}:{}] = {:?}",
         //         node,
@@ -3,10 +3,10 @@ use std::iter::zip;
 
 use itertools::Itertools;
 
-use cadical::statik::Cadical;
 use sat_nexus_core::encoder::CnfEncoder;
 use sat_nexus_core::lit::Lit;
 use sat_nexus_core::map::Map;
+use sat_nexus_core::solver::Solver;
 
 use crate::circuit::{BooleanCircuit, LogicGate};
 use crate::table::TruthTable;
@@ -56,7 +56,7 @@ pub fn encode_circuit_synthesis(encoder: &mut CnfEncoder, num_gates: usize, trut
     let mut outputs_for_cube: HashMap<Vec<bool>, Vec<Option<bool>>> = HashMap::new();
     for (i, tt) in truth_tables.iter().enumerate() {
         for &(ref inputs, output) in tt.rows.iter() {
-            outputs_for_cube.entry(inputs.clone()).or_insert_with(|| vec![None; num_outputs])[i] = Some(output);
+            outputs_for_cube.entry(inputs.clone()).or_insert_with(|| vec![None; num_outputs])[i] = output;
         }
     }
     let unique_cubes: Vec<Vec<bool>> = outputs_for_cube.keys().cloned().sorted().collect();
@@ -313,7 +313,10 @@ pub fn encode_circuit_synthesis(encoder: &mut CnfEncoder, num_gates: usize, trut
 }
 
 impl CircuitSynthesis {
-    pub fn build_circuit(&self, solver: &Cadical) -> BooleanCircuit {
+    pub fn build_circuit<S>(&self, solver: &S) -> BooleanCircuit
+    where
+        S: Solver,
+    {
         let mut circuit = BooleanCircuit::new(self.num_inputs, self.num_outputs);
 
         let mut pin_mapping = HashMap::new();
@@ -1,7 +1,7 @@
-use cadical::statik::Cadical;
 use sat_nexus_core::encoder::CnfEncoder;
 use sat_nexus_core::lit::Lit;
 use sat_nexus_core::map::Map;
+use sat_nexus_core::solver::Solver;
 
 use crate::formula::BooleanFormula;
 use crate::table::TruthTable;
@@ -208,11 +208,11 @@ pub fn encode_boolean_synthesis(encoder: &mut CnfEncoder, num_nodes: usize, trut
         let root = 1;
         for (row, &(_, output)) in truth_table.rows.iter().enumerate() {
             let v = value_vars[(root, row)];
-            // (root's value is output)
-            if output {
-                encoder.add_clause(vec![v]);
-            } else {
-                encoder.add_clause(vec![-v]);
+            // (root's value is output), unless the output is don't-care
+            match output {
+                Some(true) => encoder.add_clause(vec![v]),
+                Some(false) => encoder.add_clause(vec![-v]),
+                None => {}
             }
         }
     }
@@ -290,7 +290,10 @@ pub fn encode_boolean_synthesis(encoder: &mut CnfEncoder, num_nodes: usize, trut
 }
 
 impl BooleanFormulaSynthesis {
-    pub fn build_formula(&self, solver: &Cadical) -> BooleanFormula {
+    pub fn build_formula<S>(&self, solver: &S) -> BooleanFormula
+    where
+        S: Solver,
+    {
         let num_nodes = self.node_type.len();
 
         let mut formula: Vec<Option<BooleanFormula>> = vec![None; num_nodes];
@@ -325,3 +328,108 @@ impl BooleanFormulaSynthesis {
         formula[0].take().unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use sat_nexus_core::cnf::Cnf;
+    use sat_nexus_core::solver::SolveResponse;
+    use sat_nexus_wrappers::cadical_static::CadicalStaticSolver;
+    use sat_nexus_wrappers::simplesat::SimpleSatSolver;
+
+    use super::*;
+
+    /// Synthesize a formula with the minimal number of nodes implementing the truth table,
+    /// using fresh solvers from `new_solver`.
+    fn synthesize<S, F>(truth_table: &TruthTable, mut new_solver: F) -> (usize, BooleanFormula)
+    where
+        S: Solver,
+        F: FnMut() -> S,
+    {
+        for num_nodes in 1..=10 {
+            let mut encoder = CnfEncoder::default();
+            let vars = encode_boolean_synthesis(&mut encoder, num_nodes, truth_table);
+            let mut solver = new_solver();
+            for clause in encoder.clauses.iter().cloned() {
+                solver.add_clause(clause);
+            }
+            if solver.solve() == SolveResponse::Sat {
+                return (num_nodes, vars.build_formula(&solver));
+            }
+        }
+        panic!("Could not synthesize a formula with at most 10 nodes");
+    }
+
+    /// Find the minimal number of nodes of a formula implementing the truth table.
+    fn min_nodes(truth_table: &TruthTable) -> usize {
+        synthesize(truth_table, CadicalStaticSolver::default).0
+    }
+
+    #[test]
+    fn test_synthesis_via_solver_trait() {
+        // x3 | (x1 & x2)
+        let mut table = TruthTable::new(3);
+        table.add_row(vec![false, false, false], false);
+        table.add_row(vec![false, false, true], true);
+        table.add_row(vec![false, true, false], false);
+        table.add_row(vec![false, true, true], true);
+        table.add_row(vec![true, false, false], false);
+        table.add_row(vec![true, false, true], true);
+        table.add_row(vec![true, true, false], true);
+        table.add_row(vec![true, true, true], true);
+
+        let (cadical_nodes, cadical_formula) = synthesize(&table, CadicalStaticSolver::default);
+        let (simple_nodes, simple_formula) = synthesize(&table, SimpleSatSolver::new);
+        assert_eq!(cadical_nodes, 5);
+        assert_eq!(simple_nodes, cadical_nodes);
+        for formula in [cadical_formula, simple_formula] {
+            for (inputs, output) in table.rows.iter() {
+                assert_eq!(Some(formula.eval(inputs)), *output, "{} on {:?}", formula, inputs);
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_dimacs() {
+        let mut table = TruthTable::new(2);
+        table.add_row(vec![false, false], false);
+        table.add_row(vec![false, true], true);
+        table.add_row(vec![true, false], true);
+        table.add_row(vec![true, true], false);
+
+        let mut encoder = CnfEncoder::default();
+        encode_boolean_synthesis(&mut encoder, 3, &table);
+
+        let path = std::env::temp_dir().join(format!("bf-test-to-dimacs-{}.cnf", std::process::id()));
+        encoder.write_dimacs(&path).unwrap();
+        let cnf = Cnf::from_file(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(cnf.clauses.len(), encoder.clauses.len());
+        assert_eq!(cnf.max_var, encoder.num_vars);
+        for (clause, lits) in cnf.clauses.iter().zip(encoder.clauses.iter()) {
+            assert_eq!(&clause.lits, lits);
+        }
+    }
+
+    #[test]
+    fn test_dont_care() {
+        // x1 & ~x2, which requires 4 nodes:
+        let mut forced = TruthTable::new(2);
+        forced.add_row(vec![false, false], false);
+        forced.add_row(vec![false, true], false);
+        forced.add_row(vec![true, false], true);
+        forced.add_row(vec![true, true], false);
+
+        // With the last row unspecified, `x1` alone suffices:
+        let mut relaxed = TruthTable::new(2);
+        relaxed.add_row(vec![false, false], false);
+        relaxed.add_row(vec![false, true], false);
+        relaxed.add_row(vec![true, false], true);
+        relaxed.add_dont_care(vec![true, true]);
+
+        let forced_nodes = min_nodes(&forced);
+        let relaxed_nodes = min_nodes(&relaxed);
+        assert_eq!(relaxed_nodes, 1);
+        assert!(relaxed_nodes < forced_nodes);
+    }
+}
@@ -26,6 +26,16 @@ impl BooleanFormula {
     pub fn or(left: BooleanFormula, right: BooleanFormula) -> Self {
         BooleanFormula::Or(Box::new(left), Box::new(right))
     }
+
+    /// Evaluate the formula on the given inputs, where `x{i}` is `inputs[i - 1]`.
+    pub fn eval(&self, inputs: &[bool]) -> bool {
+        match self {
+            BooleanFormula::Var(index) => inputs[index - 1],
+            BooleanFormula::Not(formula) => !formula.eval(inputs),
+            BooleanFormula::And(left, right) => left.eval(inputs) && right.eval(inputs),
+            BooleanFormula::Or(left, right) => left.eval(inputs) || right.eval(inputs),
+        }
+    }
 }
 
 impl Display for BooleanFormula {
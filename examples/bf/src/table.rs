@@ -1,9 +1,14 @@
+use std::collections::HashMap;
 use std::fmt::Write as _;
 
+use sat_nexus_core::formula::expr::Expr;
+use sat_nexus_core::formula::var::Var;
+
 #[derive(Debug, Clone)]
 pub struct TruthTable {
     pub variables: usize,
-    pub rows: Vec<(Vec<bool>, bool)>,
+    /// Rows of the table, with `None` output for don't-care rows.
+    pub rows: Vec<(Vec<bool>, Option<bool>)>,
 }
 
 impl TruthTable {
@@ -17,7 +22,12 @@ impl TruthTable {
 
     /// Add a row to the truth table.
     pub fn add_row(&mut self, inputs: Vec<bool>, output: bool) {
-        self.rows.push((inputs, output));
+        self.rows.push((inputs, Some(output)));
+    }
+
+    /// Add a row with unspecified (don't-care) output to the truth table.
+    pub fn add_dont_care(&mut self, inputs: Vec<bool>) {
+        self.rows.push((inputs, None));
     }
 
     /// Create a complete truth table with `n` variables.
@@ -30,14 +40,37 @@ impl TruthTable {
         table
     }
 
+    /// Create a complete truth table with `num_inputs` variables,
+    /// with outputs given by the expression over `x1..=x{num_inputs}`.
+    ///
+    /// Rows are ordered as in [`complete`][TruthTable::complete].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the expression references a variable outside `1..=num_inputs`.
+    pub fn from_expr(expr: &Expr<Var>, num_inputs: usize) -> Self {
+        if let Some(var) = expr.vars().into_iter().find(|v| v.0 == 0 || v.0 as usize > num_inputs) {
+            panic!("Expression references variable {:#} outside x1..=x{}", var, num_inputs);
+        }
+
+        let mut table = TruthTable::complete(num_inputs);
+        for (inputs, output) in table.rows.iter_mut() {
+            let mapping: HashMap<Var, bool> = inputs.iter().enumerate().map(|(i, &b)| (Var(i as u32 + 1), b)).collect();
+            *output = Some(expr.eval(&mapping));
+        }
+        table
+    }
+
     /// Display the truth table in a simple format.
     ///
     /// ```txt
     /// 0 0 | 0
     /// 0 1 | 1
     /// 1 0 | 1
-    /// 1 1 | 0
+    /// 1 1 | -
     /// ```
+    ///
+    /// Don't-care outputs are displayed as `-`.
     pub fn display_simple(&self) -> String {
         let mut output = String::new();
 
@@ -45,9 +78,49 @@ impl TruthTable {
             for &input in inputs.iter() {
                 write!(output, "{} ", if input { "1" } else { "0" }).unwrap();
             }
-            writeln!(output, "| {}", if *result { "1" } else { "0" }).unwrap();
+            let result = match result {
+                Some(true) => "1",
+                Some(false) => "0",
+                None => "-",
+            };
+            writeln!(output, "| {}", result).unwrap();
         }
 
         output
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_expr() {
+        let expr = Expr::parse_flat("x1 & x2 | x3").unwrap();
+        let table = TruthTable::from_expr(&expr, 3);
+        let expected = [
+            // (x1, x2, x3) -> output
+            ([false, false, false], false),
+            ([true, false, false], false),
+            ([false, true, false], false),
+            ([true, true, false], true),
+            ([false, false, true], true),
+            ([true, false, true], true),
+            ([false, true, true], true),
+            ([true, true, true], true),
+        ];
+        assert_eq!(table.variables, 3);
+        assert_eq!(table.rows.len(), expected.len());
+        for ((inputs, output), (expected_inputs, expected_output)) in table.rows.iter().zip(expected.iter()) {
+            assert_eq!(inputs, expected_inputs);
+            assert_eq!(*output, Some(*expected_output));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "outside")]
+    fn test_from_expr_rejects_extra_vars() {
+        let expr = Expr::parse_flat("x1 & x4").unwrap();
+        TruthTable::from_expr(&expr, 3);
+    }
+}
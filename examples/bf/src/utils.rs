@@ -1,14 +1,11 @@
-use cadical::statik::Cadical;
-use cadical::LitValue;
 use sat_nexus_core::lit::Lit;
 use sat_nexus_core::map::Map;
+use sat_nexus_core::solver::{LitValue, Solver};
 
-pub fn decode_onehot<'a, T>(var: &'a Map<T, Lit>, solver: &Cadical) -> Option<&'a T> {
-    var.iter().find_map(|(key, &t)| {
-        if solver.val(t.get()).unwrap() == LitValue::True {
-            Some(key)
-        } else {
-            None
-        }
-    })
+pub fn decode_onehot<'a, T, S>(var: &'a Map<T, Lit>, solver: &S) -> Option<&'a T>
+where
+    S: Solver,
+{
+    var.iter()
+        .find_map(|(key, &t)| if solver.value(t) == LitValue::True { Some(key) } else { None })
 }
@@ -150,7 +150,7 @@ fn main() -> color_eyre::Result<()> {
     let response = solver.solve();
     println!("=> Solver returned: {:?}", response);
 
-    if matches!(response, SolveResponse::Sat) {
+    if response.is_sat() {
         let color = context.get_named::<ArrayD<DomainVar<usize>>, _>("color")?;
 
         assert!(matches!(solver.eval(&color[[0]].eq(1)), LitValue::True));
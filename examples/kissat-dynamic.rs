@@ -16,12 +16,12 @@ fn main() -> color_eyre::Result<()> {
     solver.add_clause(vec![-3, -4]);
     solver.add_clause([5, -5]);
 
-    let response = solver.solve();
+    let response = solver.solve()?;
     println!("solve() = {:?}", response);
     assert!(matches!(response, SolveResponse::Sat));
 
     for i in 1..=5 {
-        println!("value({}) = {:?}", i, solver.value(i));
+        println!("value({}) = {:?}", i, solver.value(i)?);
     }
 
     Ok(())
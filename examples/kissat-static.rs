@@ -27,7 +27,7 @@ fn main() -> color_eyre::Result<()> {
     solver.add_clause(vec![lit5, -lit5]);
 
     // Solving without assumptions => SAT
-    let response = solver.solve();
+    let response = solver.solve()?;
     println!("Solver returned: {:?}", response);
     assert_eq!(response, SolveResponse::Sat);
 
@@ -46,7 +46,7 @@ fn main() -> color_eyre::Result<()> {
     // assert_eq!(response, SolveResponse::Sat);
 
     for lit in [lit1, lit2, lit3, lit4, lit5] {
-        println!("solver.val({}) = {:?}", lit, solver.value(lit));
+        println!("solver.val({}) = {:?}", lit, solver.value(lit)?);
     }
 
     Ok(())
@@ -36,7 +36,7 @@ fn main() -> color_eyre::Result<()> {
     assert!(response);
 
     for lit in [lit1, lit2, lit3, lit4, lit5] {
-        println!("solver.val({}) = {:?}", lit, solver.model_value_lit(lit));
+        println!("solver.val({}) = {:?}", lit, solver.model_value_lit(lit)?);
     }
 
     Ok(())
@@ -1,7 +1,16 @@
 use std::fmt::Display;
+use std::time::{Duration, Instant};
 
+use sat_nexus::core::cnf::Cnf;
 use sat_nexus::core::context::Context;
+use sat_nexus::core::lit::Lit;
+use sat_nexus::core::solver::simple::SimpleSolver;
 use sat_nexus::core::solver::*;
+use sat_nexus::core::utils::bootstrap_solver_from_cnf;
+use sat_nexus::wrappers::dispatch::DispatchSolver;
+use sat_nexus::wrappers::simplesat::SimpleSatSolver;
+
+pub use sat_nexus::core::utils::pigeonhole;
 
 pub fn run_test_1<S>(mut solver: S) -> color_eyre::Result<()>
 where
@@ -24,17 +33,17 @@ where
     solver.add_unit(5);
     let response = solver.solve();
     println!("Solver returned: {:?}", response);
-    assert!(matches!(response, SolveResponse::Sat));
+    assert!(response.is_sat());
 
     solver.assume(1);
     solver.assume(2);
     let response = solver.solve();
     println!("Solver returned: {:?}", response);
-    assert!(matches!(response, SolveResponse::Unsat));
+    assert!(response.is_unsat());
 
     let response = solver.solve();
     println!("Solver returned: {:?}", response);
-    assert!(matches!(response, SolveResponse::Sat));
+    assert!(response.is_sat());
 
     for i in 1..=5 {
         println!("solver.val({}) = {:?}", i, solver.value(i));
@@ -42,3 +51,147 @@ where
 
     Ok(())
 }
+
+/// Same as [`run_test_1`], but through the [`SimpleSolver`] trait object.
+///
+/// Pass `use_assumptions = false` for solvers not supporting assumptions (e.g. Kissat).
+pub fn run_test_simple(solver: &mut dyn SimpleSolver, use_assumptions: bool) -> color_eyre::Result<()> {
+    println!("Solver signature: {}", solver.signature());
+
+    let lits: Vec<Lit> = (1..=5).map(Lit::new).collect();
+    solver.add_clause(&[lits[0], lits[1]]);
+    solver.add_clause(&[lits[2], lits[3]]);
+    solver.add_clause(&[-lits[0], -lits[1]]);
+    solver.add_clause(&[-lits[2], -lits[3]]);
+    solver.add_clause(&[lits[4]]);
+    let response = solver.solve();
+    println!("Solver returned: {:?}", response);
+    assert!(response.is_sat());
+    assert_eq!(solver.value(lits[4]), LitValue::True);
+
+    if use_assumptions {
+        solver.assume(lits[0]);
+        solver.assume(lits[1]);
+        let response = solver.solve();
+        println!("Solver returned: {:?}", response);
+        assert!(response.is_unsat());
+
+        let response = solver.solve();
+        println!("Solver returned: {:?}", response);
+        assert!(response.is_sat());
+    }
+
+    Ok(())
+}
+
+/// Check that `value(lit)` and `value(-lit)` are opposite for all variables in the `solver`,
+/// which must be in the SAT state.
+pub fn assert_value_sign_consistency<S>(solver: &S)
+where
+    S: Solver,
+{
+    for var in 1..=solver.num_vars() as i32 {
+        let pos = solver.value(var);
+        let neg = solver.value(-var);
+        let expected = match pos {
+            LitValue::True => LitValue::False,
+            LitValue::False => LitValue::True,
+            LitValue::DontCare => LitValue::DontCare,
+        };
+        assert_eq!(
+            neg,
+            expected,
+            "Inconsistent values in '{}': value({}) = {:?}, value({}) = {:?}",
+            solver.signature(),
+            var,
+            pos,
+            -var,
+            neg
+        );
+    }
+}
+
+/// Wall-clock timings of [`time_bootstrap_and_solve`].
+#[derive(Debug, Copy, Clone)]
+pub struct SolveTimings {
+    pub bootstrap: Duration,
+    pub solve: Duration,
+    pub response: SolveResponse,
+}
+
+/// Add all clauses of `cnf` to the `solver`, returning the elapsed time.
+pub fn time_bootstrap<S>(solver: &mut S, cnf: &Cnf) -> Duration
+where
+    S: Solver,
+{
+    let time_start = Instant::now();
+    bootstrap_solver_from_cnf(solver, cnf);
+    time_start.elapsed()
+}
+
+/// Solve, returning the response together with the elapsed time.
+pub fn time_solve<S>(solver: &mut S) -> (SolveResponse, Duration)
+where
+    S: Solver,
+{
+    let time_start = Instant::now();
+    let response = solver.solve();
+    (response, time_start.elapsed())
+}
+
+/// Bootstrap the `solver` from `cnf` and solve, timing both steps.
+pub fn time_bootstrap_and_solve<S>(solver: &mut S, cnf: &Cnf) -> SolveTimings
+where
+    S: Solver,
+{
+    let bootstrap = time_bootstrap(solver, cnf);
+    let (response, solve) = time_solve(solver);
+    SolveTimings {
+        bootstrap,
+        solve,
+        response,
+    }
+}
+
+/// Check whether the current model of the `solver` satisfies all clauses of the `cnf`.
+pub fn satisfies<S>(solver: &S, cnf: &Cnf) -> bool
+where
+    S: Solver,
+{
+    cnf.iter()
+        .all(|clause| clause.lits.iter().any(|&lit| solver.value(lit) == LitValue::True))
+}
+
+/// Solve the `cnf` with all backends (simple-sat, CaDiCaL, Kissat, MiniSat),
+/// asserting that they agree on SAT/UNSAT and that all the found models satisfy the `cnf`.
+///
+/// Returns the common response.
+pub fn differential_check(cnf: &Cnf) -> SolveResponse {
+    let backends = [
+        ("simple-sat", DispatchSolver::new_delegate_wrap(SimpleSatSolver::new())),
+        ("cadical", DispatchSolver::new_cadical()),
+        ("kissat", DispatchSolver::new_kissat()),
+        ("minisat", DispatchSolver::new_minisat()),
+    ];
+
+    let mut expected: Option<(&str, SolveResponse)> = None;
+    for (name, mut solver) in backends {
+        bootstrap_solver_from_cnf(&mut solver, cnf);
+        let response = solver.solve();
+        assert!(!response.is_unknown(), "{} returned {} on {}", name, response, cnf);
+        if response.is_sat() {
+            assert!(satisfies(&solver, cnf), "Model found by {} does not satisfy {}", name, cnf);
+        }
+        match expected {
+            None => expected = Some((name, response)),
+            Some((expected_name, expected_response)) => {
+                assert_eq!(
+                    response, expected_response,
+                    "{} returned {}, but {} returned {} on {}",
+                    name, response, expected_name, expected_response, cnf
+                );
+            }
+        }
+    }
+    expected.unwrap().1
+}
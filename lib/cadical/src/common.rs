@@ -10,6 +10,23 @@ pub enum CadicalError {
     #[snafu(display("Literal must be non-zero"))]
     ZeroLiteral,
 
+    #[snafu(display("Invalid literal: {}", lit))]
+    InvalidLiteral { lit: i32 },
+
+    /// The method requires a different solver state, e.g. `val` is only allowed while
+    /// the last `solve` returned SAT and no clauses, constraints or assumptions were added since then.
+    #[snafu(display("Invalid solver state: {}", message))]
+    InvalidState { message: String },
+
+    #[snafu(display("Usage error: {}", message))]
+    UsageError { message: String },
+
+    #[snafu(display("Solver was already released"))]
+    Released,
+
+    #[snafu(display("Invalid option or configuration: '{}'", name))]
+    OptionError { name: String },
+
     #[snafu(display("Invalid response from `constraint_failed()`: {}", value))]
     InvalidResponseConstraintFailed { value: i32 },
 
@@ -32,6 +49,13 @@ pub enum CadicalError {
     InvalidResponseFrozen { lit: i32, value: i32 },
 }
 
+/// Check that `lit` is a valid literal, i.e. non-zero and not `i32::MIN`.
+pub(crate) fn check_lit(lit: i32) -> Result<()> {
+    snafu::ensure!(lit != 0, ZeroLiteralSnafu);
+    snafu::ensure!(lit != i32::MIN, InvalidLiteralSnafu { lit });
+    Ok(())
+}
+
 /// Possible responses from a call to `Cadical::simplify`.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum SimplifyResponse {
@@ -88,3 +112,15 @@ pub enum FixedResponse {
     /// It is unclear at this point whether the literal is implied by the formula.
     Unclear = 0,
 }
+
+/// Outcome of the last `solve`, see `Cadical::result_summary`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResultSummary {
+    /// The model, as a list of literals over all variables.
+    Sat { model: Vec<i32> },
+    /// The failed assumptions (not necessarily a minimal core),
+    /// which are empty if the formula is unsatisfiable without assumptions.
+    Unsat { core: Vec<i32> },
+    /// The solver was interrupted, not solved yet, or modified since the last `solve`.
+    Unknown,
+}
@@ -1,3 +1,4 @@
+use std::cell::Cell;
 use std::ffi::CString;
 use std::fmt::{Debug, Display, Formatter};
 
@@ -38,6 +39,8 @@ use super::ffi::*;
 pub struct Cadical {
     ffi: &'static CCadicalFFI,
     ptr: CCadicalPtr,
+    /// Response of the last `solve`, reset when the formula or assumptions are modified.
+    last_response: Cell<Option<SolveResponse>>,
 }
 
 impl Cadical {
@@ -49,6 +52,7 @@ impl Cadical {
         Cadical {
             ffi,
             ptr: unsafe { ffi.ccadical_init() },
+            last_response: Cell::new(None),
         }
     }
 }
@@ -83,6 +87,10 @@ impl Cadical {
         unsafe { cstr2str(self.ffi.ccadical_signature()) }
     }
 
+    /// Release the underlying solver. Releasing it again (e.g. on drop) is a no-op.
+    ///
+    /// After the release, the methods returning a [`Result`] fail with [`CadicalError::Released`],
+    /// and the other ones panic.
     pub fn release(&mut self) {
         if !self.ptr.is_null() {
             unsafe { self.ffi.ccadical_release(self.ptr) }
@@ -90,6 +98,38 @@ impl Cadical {
         }
     }
 
+    /// Pointer to the underlying solver, or [`CadicalError::Released`] if it was already released.
+    ///
+    /// All accessors go through this check, either directly or via [`live_ptr`][Cadical::live_ptr].
+    fn ptr(&self) -> Result<CCadicalPtr> {
+        ensure!(!self.ptr.is_null(), ReleasedSnafu);
+        Ok(self.ptr)
+    }
+
+    /// Pointer to the underlying solver for the methods not returning a [`Result`].
+    ///
+    /// # Panics
+    ///
+    /// Panics (naming the `method`) if the solver was already released.
+    fn live_ptr(&self, method: &str) -> CCadicalPtr {
+        match self.ptr() {
+            Ok(ptr) => ptr,
+            Err(e) => panic!("{}, cannot call `{}`", e, method),
+        }
+    }
+
+    /// Ensure that the last `solve` returned `expected`,
+    /// and the solver was not modified since then.
+    fn ensure_state(&self, expected: SolveResponse, method: &str) -> Result<()> {
+        ensure!(
+            self.last_response.get() == Some(expected),
+            InvalidStateSnafu {
+                message: format!("`{}` requires the last `solve` to return {}", method, expected),
+            }
+        );
+        Ok(())
+    }
+
     /// Adds a literal to the constraint clause. Same functionality as 'add' but
     /// the clause only exists for the next call to solve (same lifetime as
     /// assumptions). Only one constraint may exists at a time. A new constraint
@@ -99,13 +139,21 @@ impl Cadical {
     ///
     /// Add valid literal to the constraint clause or zero to terminate it.
     pub fn constrain(&self, lit_or_zero: i32) {
-        unsafe { self.ffi.ccadical_constrain(self.ptr, lit_or_zero) }
+        let ptr = self.live_ptr("constrain");
+        assert_ne!(lit_or_zero, i32::MIN, "Invalid literal: {}", lit_or_zero);
+        self.last_response.set(None);
+        unsafe { self.ffi.ccadical_constrain(ptr, lit_or_zero) }
     }
 
     /// Determine whether the constraint was used to proof the unsatisfiability.
     /// Note that the formula might still be unsatisfiable without the constraint.
+    ///
+    /// Fails with [`CadicalError::InvalidState`] unless the last `solve` returned [`SolveResponse::Unsat`]
+    /// and the solver was not modified since then.
     pub fn constraint_failed(&self) -> Result<bool> {
-        match unsafe { self.ffi.ccadical_constraint_failed(self.ptr) } {
+        let ptr = self.ptr()?;
+        self.ensure_state(SolveResponse::Unsat, "constraint_failed")?;
+        match unsafe { self.ffi.ccadical_constraint_failed(ptr) } {
             0 => Ok(false),
             1 => Ok(true),
             invalid => InvalidResponseConstraintFailedSnafu { value: invalid }.fail(),
@@ -115,8 +163,9 @@ impl Cadical {
     // Overwrite (some) options with the forced values of the configuration.
     // The result is 'true' iff the 'name' is a valid configuration.
     pub fn configure(&self, name: &'static str) {
+        let ptr = self.live_ptr("configure");
         let c_string = CString::new(name).expect("CString::new failed");
-        let ok = unsafe { self.ffi.ccadical_configure(self.ptr, c_string.as_ptr()) };
+        let ok = unsafe { self.ffi.ccadical_configure(ptr, c_string.as_ptr()) };
         assert!(ok, "ccadical_configure returned false");
     }
 
@@ -127,16 +176,18 @@ impl Cadical {
     ///
     /// Options can only be set right after initialization.
     pub fn set_option(&self, name: &'static str, val: i32) {
+        let ptr = self.live_ptr("set_option");
         let c_string = CString::new(name).expect("CString::new failed");
-        let ok = unsafe { self.ffi.ccadical_set_option(self.ptr, c_string.as_ptr(), val) };
+        let ok = unsafe { self.ffi.ccadical_set_option(ptr, c_string.as_ptr(), val) };
         assert!(ok, "ccadical_set_option returned false");
     }
 
     /// Get the current value of the option 'name'.  If 'name' is invalid then
     /// zero is returned.  Here '--...' arguments as invalid options.
     pub fn get_option(&self, name: &'static str) -> i32 {
+        let ptr = self.live_ptr("get_option");
         let c_string = CString::new(name).expect("CString::new failed");
-        unsafe { self.ffi.ccadical_get_option(self.ptr, c_string.as_ptr()) }
+        unsafe { self.ffi.ccadical_get_option(ptr, c_string.as_ptr()) }
     }
 
     /// Specify search limits, where currently 'name' can be "conflicts",
@@ -153,22 +204,28 @@ impl Cadical {
     /// 'lookahead').  We actually also have an internal "terminate" limit
     /// which however should only be used for testing and debugging.
     pub fn limit(&self, name: &str, limit: i32) {
+        let ptr = self.live_ptr("limit");
         let c_string = CString::new(name).expect("CString::new failed");
-        let ok = unsafe { self.ffi.ccadical_limit(self.ptr, c_string.as_ptr(), limit) };
+        let ok = unsafe { self.ffi.ccadical_limit(ptr, c_string.as_ptr(), limit) };
         assert!(ok, "ccadical_limit returned false");
     }
 
     /// Add valid literal to clause or zero to terminate clause.
     pub fn add(&self, lit_or_zero: i32) {
-        unsafe { self.ffi.ccadical_add(self.ptr, lit_or_zero) }
+        let ptr = self.live_ptr("add");
+        assert_ne!(lit_or_zero, i32::MIN, "Invalid literal: {}", lit_or_zero);
+        self.last_response.set(None);
+        unsafe { self.ffi.ccadical_add(ptr, lit_or_zero) }
     }
 
     /// Assume valid non zero literal for next call to 'solve'.
     /// These assumptions are reset after the call to 'solve'
     /// as well as after returning from 'simplify' and 'lookahead'.
     pub fn assume(&self, lit: i32) -> Result<()> {
-        ensure!(lit != 0, ZeroLiteralSnafu);
-        unsafe { self.ffi.ccadical_assume(self.ptr, lit) }
+        let ptr = self.ptr()?;
+        check_lit(lit)?;
+        self.last_response.set(None);
+        unsafe { self.ffi.ccadical_assume(ptr, lit) }
         Ok(())
     }
 
@@ -180,33 +237,50 @@ impl Cadical {
     ///
     /// Internally, the default number of rounds is 3.
     pub fn simplify(&self) -> Result<SimplifyResponse> {
-        match unsafe { self.ffi.ccadical_simplify(self.ptr) } {
-            0 => Ok(SimplifyResponse::Unknown),
-            10 => Ok(SimplifyResponse::Sat),
-            20 => Ok(SimplifyResponse::Unsat),
-            invalid => InvalidResponseSimplifySnafu { value: invalid }.fail(),
-        }
+        let ptr = self.ptr()?;
+        let response = match unsafe { self.ffi.ccadical_simplify(ptr) } {
+            0 => SimplifyResponse::Unknown,
+            10 => SimplifyResponse::Sat,
+            20 => SimplifyResponse::Unsat,
+            invalid => return InvalidResponseSimplifySnafu { value: invalid }.fail(),
+        };
+        self.last_response.set(match response {
+            SimplifyResponse::Unknown => None,
+            SimplifyResponse::Sat => Some(SolveResponse::Sat),
+            SimplifyResponse::Unsat => Some(SolveResponse::Unsat),
+        });
+        Ok(response)
     }
 
     /// Try to solve the current formula.
     pub fn solve(&self) -> Result<SolveResponse> {
-        match unsafe { self.ffi.ccadical_solve(self.ptr) } {
-            0 => Ok(SolveResponse::Interrupted),
-            10 => Ok(SolveResponse::Sat),
-            20 => Ok(SolveResponse::Unsat),
-            invalid => InvalidResponseSolveSnafu { value: invalid }.fail(),
-        }
+        let ptr = self.ptr()?;
+        let response = match unsafe { self.ffi.ccadical_solve(ptr) } {
+            0 => SolveResponse::Interrupted,
+            10 => SolveResponse::Sat,
+            20 => SolveResponse::Unsat,
+            invalid => return InvalidResponseSolveSnafu { value: invalid }.fail(),
+        };
+        self.last_response.set(Some(response));
+        Ok(response)
     }
 
     /// Force termination of 'solve' asynchronously.
     pub fn terminate(&self) {
-        unsafe { self.ffi.ccadical_terminate(self.ptr) }
+        let ptr = self.live_ptr("terminate");
+        unsafe { self.ffi.ccadical_terminate(ptr) }
     }
 
     /// Get value of valid non-zero literal.
+    ///
+    /// The model is only available while the last `solve` returned [`SolveResponse::Sat`]:
+    /// adding clauses, constraints or assumptions after it invalidates the model (CaDiCaL itself
+    /// aborts on `val` in this case), so `val` fails with [`CadicalError::InvalidState`] until the next `solve`.
     pub fn val(&self, lit: i32) -> Result<LitValue> {
-        ensure!(lit != 0, ZeroLiteralSnafu);
-        match unsafe { self.ffi.ccadical_val(self.ptr, lit) } {
+        let ptr = self.ptr()?;
+        check_lit(lit)?;
+        self.ensure_state(SolveResponse::Sat, "val")?;
+        match unsafe { self.ffi.ccadical_val(ptr, lit) } {
             p if p == lit => Ok(LitValue::True),
             n if n == -lit => Ok(LitValue::False),
             invalid => InvalidResponseValSnafu { lit, value: invalid }.fail(),
@@ -216,57 +290,80 @@ impl Cadical {
     /// Determine whether the valid non-zero literal is in the core.
     /// Returns `true` if the literal is in the core and `false` otherwise.
     /// Note that the core does not have to be minimal.
+    ///
+    /// Same as for [`val`][Cadical::val], fails with [`CadicalError::InvalidState`]
+    /// unless the last `solve` returned [`SolveResponse::Unsat`] and the solver was not modified since then.
     pub fn failed(&self, lit: i32) -> Result<bool> {
-        ensure!(lit != 0, ZeroLiteralSnafu);
-        let res = unsafe { self.ffi.ccadical_failed(self.ptr, lit) };
+        let ptr = self.ptr()?;
+        check_lit(lit)?;
+        self.ensure_state(SolveResponse::Unsat, "failed")?;
+        let res = unsafe { self.ffi.ccadical_failed(ptr, lit) };
         Ok(res)
     }
 
     pub fn print_statistics(&self) {
-        unsafe { self.ffi.ccadical_print_statistics(self.ptr) }
+        let ptr = self.live_ptr("print_statistics");
+        unsafe { self.ffi.ccadical_print_statistics(ptr) }
     }
 
     /// Number of variables.
     pub fn vars(&self) -> i64 {
-        unsafe { self.ffi.ccadical_vars(self.ptr) }
+        let ptr = self.live_ptr("vars");
+        unsafe { self.ffi.ccadical_vars(ptr) }
+    }
+
+    /// Increase the maximum variable index explicitly.
+    /// All variables up to `min_max_var` are allocated at once, which avoids
+    /// repeated reallocation of internal structures when adding a large formula.
+    /// The variables are also counted in 'vars'.
+    pub fn reserve(&self, min_max_var: i32) {
+        let ptr = self.live_ptr("reserve");
+        unsafe { self.ffi.ccadical_reserve(ptr, min_max_var) }
     }
 
     /// Number of active variables.
     pub fn active(&self) -> i64 {
-        unsafe { self.ffi.ccadical_active(self.ptr) }
+        let ptr = self.live_ptr("active");
+        unsafe { self.ffi.ccadical_active(ptr) }
     }
 
     /// Number of active irredundant clauses.
     pub fn irredundant(&self) -> i64 {
-        unsafe { self.ffi.ccadical_irredundant(self.ptr) }
+        let ptr = self.live_ptr("irredundant");
+        unsafe { self.ffi.ccadical_irredundant(ptr) }
     }
 
     /// Number of conflicts.
     pub fn conflicts(&self) -> i64 {
-        unsafe { self.ffi.ccadical_conflicts(self.ptr) }
+        let ptr = self.live_ptr("conflicts");
+        unsafe { self.ffi.ccadical_conflicts(ptr) }
     }
 
     /// Number of decisions.
     pub fn decisions(&self) -> i64 {
-        unsafe { self.ffi.ccadical_decisions(self.ptr) }
+        let ptr = self.live_ptr("decisions");
+        unsafe { self.ffi.ccadical_decisions(ptr) }
     }
 
     /// Number of restarts.
     pub fn restarts(&self) -> i64 {
-        unsafe { self.ffi.ccadical_restarts(self.ptr) }
+        let ptr = self.live_ptr("restarts");
+        unsafe { self.ffi.ccadical_restarts(ptr) }
     }
 
     /// Number of propagations.
     pub fn propagations(&self) -> i64 {
-        unsafe { self.ffi.ccadical_propagations(self.ptr) }
+        let ptr = self.live_ptr("propagations");
+        unsafe { self.ffi.ccadical_propagations(ptr) }
     }
 
     /// Root level assigned variables can be queried with this function.
     /// It returns '1' if the literal is implied by the formula, '-1' if its
     /// negation is implied, or '0' if this is unclear at this point.
     pub fn fixed(&self, lit: i32) -> Result<FixedResponse> {
-        ensure!(lit != 0, ZeroLiteralSnafu);
-        match unsafe { self.ffi.ccadical_fixed(self.ptr, lit) } {
+        let ptr = self.ptr()?;
+        check_lit(lit)?;
+        match unsafe { self.ffi.ccadical_fixed(ptr, lit) } {
             1 => Ok(FixedResponse::Positive),
             -1 => Ok(FixedResponse::Negative),
             0 => Ok(FixedResponse::Unclear),
@@ -274,21 +371,39 @@ impl Cadical {
         }
     }
 
+    /// All currently root-level fixed literals, in the order of variables.
+    /// The literal is positive if the variable is fixed to true, and negative otherwise.
+    pub fn fixed_literals(&self) -> Result<Vec<i32>> {
+        self.ptr()?;
+        let mut lits = Vec::new();
+        for var in 1..=self.vars() as i32 {
+            match self.fixed(var)? {
+                FixedResponse::Positive => lits.push(var),
+                FixedResponse::Negative => lits.push(-var),
+                FixedResponse::Unclear => {}
+            }
+        }
+        Ok(lits)
+    }
+
     pub fn frozen(&self, lit: i32) -> Result<bool> {
-        ensure!(lit != 0, ZeroLiteralSnafu);
-        let res = unsafe { self.ffi.ccadical_frozen(self.ptr, lit) };
+        let ptr = self.ptr()?;
+        check_lit(lit)?;
+        let res = unsafe { self.ffi.ccadical_frozen(ptr, lit) };
         Ok(res)
     }
 
     pub fn freeze(&self, lit: i32) -> Result<()> {
-        ensure!(lit != 0, ZeroLiteralSnafu);
-        unsafe { self.ffi.ccadical_freeze(self.ptr, lit) }
+        let ptr = self.ptr()?;
+        check_lit(lit)?;
+        unsafe { self.ffi.ccadical_freeze(ptr, lit) }
         Ok(())
     }
 
     pub fn melt(&self, lit: i32) -> Result<()> {
-        ensure!(lit != 0, ZeroLiteralSnafu);
-        unsafe { self.ffi.ccadical_melt(self.ptr, lit) }
+        let ptr = self.ptr()?;
+        check_lit(lit)?;
+        unsafe { self.ffi.ccadical_melt(ptr, lit) }
         Ok(())
     }
 }
@@ -298,6 +413,7 @@ impl Cadical {
     pub fn reset(&mut self) {
         self.release();
         self.ptr = unsafe { self.ffi.ccadical_init() };
+        self.last_response.set(None);
     }
 
     pub fn add_clause<I>(&self, lits: I)
@@ -306,11 +422,31 @@ impl Cadical {
         I::Item: Into<i32>,
     {
         for lit in lits.into_iter() {
-            self.add(lit.into());
+            let lit = lit.into();
+            assert_ne!(lit, 0, "Literal must be non-zero, clause is terminated automatically");
+            self.add(lit);
         }
         self.add(0);
     }
 
+    /// Add the clause only if it passes the `filter`, e.g., to drop tautologies.
+    ///
+    /// Returns `true` if the clause was added.
+    pub fn add_clause_filtered<I, F>(&self, lits: I, mut filter: F) -> bool
+    where
+        I: IntoIterator,
+        I::Item: Into<i32>,
+        F: FnMut(&[i32]) -> bool,
+    {
+        let lits: Vec<i32> = lits.into_iter().map(Into::into).collect();
+        if filter(&lits) {
+            self.add_clause(lits);
+            true
+        } else {
+            false
+        }
+    }
+
     // TODO: remove
     pub fn try_add_clause<I>(&self, lits: I) -> Result<(), <I::Item as TryInto<i32>>::Error>
     where
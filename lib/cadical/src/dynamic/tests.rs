@@ -81,3 +81,157 @@ fn test_simple_unsat() -> color_eyre::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_reserve() -> color_eyre::Result<()> {
+    let solver = Cadical::new();
+    assert_eq!(solver.vars(), 0);
+
+    solver.reserve(100_000);
+    println!("vars after reserve: {}", solver.vars());
+    assert_eq!(solver.vars(), 100_000);
+
+    // Adding clauses within the reserved range does not change the number of variables:
+    solver.add_clause([1, -50_000]);
+    solver.add_clause([-1, 100_000]);
+    assert_eq!(solver.vars(), 100_000);
+
+    let response = solver.solve()?;
+    assert_eq!(response, SolveResponse::Sat);
+
+    Ok(())
+}
+
+#[test]
+fn test_fixed_literals() -> color_eyre::Result<()> {
+    let solver = Cadical::new();
+
+    // Units 1 and -3 imply 2 and -4:
+    solver.add_clause([1]);
+    solver.add_clause([-3]);
+    solver.add_clause([-1, 2]);
+    solver.add_clause([3, -4, 5]);
+    solver.add_clause([-4, -5]);
+    solver.add_clause([3, -2, -4]);
+    // Variable 6 is unconstrained:
+    solver.add_clause([5, 6]);
+
+    let response = solver.simplify()?;
+    assert_ne!(response, SimplifyResponse::Unsat);
+
+    let fixed = solver.fixed_literals()?;
+    println!("fixed = {:?}", fixed);
+    assert_eq!(fixed, vec![1, 2, -3, -4]);
+
+    Ok(())
+}
+
+#[test]
+fn test_use_after_release() {
+    let mut solver = Cadical::new();
+    solver.add_clause([1, 2]);
+    solver.release();
+    // Second release is a no-op:
+    solver.release();
+
+    let res = solver.solve();
+    println!("solve after release: {:?}", res);
+    assert!(matches!(res, Err(CadicalError::Released)));
+    assert!(matches!(solver.val(1), Err(CadicalError::Released)));
+    assert!(matches!(solver.assume(1), Err(CadicalError::Released)));
+    // Note: `Drop` does not free the solver again.
+}
+
+#[test]
+#[should_panic(expected = "Solver was already released, cannot call `add`")]
+fn test_add_clause_after_release() {
+    let mut solver = Cadical::new();
+    solver.release();
+    // Methods not returning a `Result` panic, so the clause is not silently lost:
+    solver.add_clause([1, 2]);
+}
+
+#[test]
+fn test_error_kinds() -> color_eyre::Result<()> {
+    let solver = Cadical::new();
+    solver.add_clause([1, 2]);
+    solver.add_clause([-1, -2]);
+
+    assert_eq!(solver.solve()?, SolveResponse::Sat);
+    assert!(solver.val(1).is_ok());
+
+    // Invalid literals
+    assert!(matches!(solver.val(0), Err(CadicalError::ZeroLiteral)));
+    assert!(matches!(solver.val(i32::MIN), Err(CadicalError::InvalidLiteral { lit: i32::MIN })));
+    assert!(matches!(
+        solver.assume(i32::MIN),
+        Err(CadicalError::InvalidLiteral { lit: i32::MIN })
+    ));
+
+    solver.assume(1)?;
+    solver.assume(2)?;
+    assert_eq!(solver.solve()?, SolveResponse::Unsat);
+    assert!(solver.failed(1)? || solver.failed(2)?);
+
+    Ok(())
+}
+
+#[test]
+fn test_invalid_state() -> color_eyre::Result<()> {
+    let solver = Cadical::new();
+    solver.add_clause([1, 2]);
+    solver.add_clause([-1, -2]);
+
+    // Model is not available before `solve`
+    assert!(matches!(solver.val(1), Err(CadicalError::InvalidState { .. })));
+
+    assert_eq!(solver.solve()?, SolveResponse::Sat);
+    assert!(solver.val(1).is_ok());
+
+    // Core is only available in the unsatisfiable state
+    assert!(matches!(solver.failed(1), Err(CadicalError::InvalidState { .. })));
+    assert!(matches!(solver.constraint_failed(), Err(CadicalError::InvalidState { .. })));
+
+    // Modifying the formula invalidates the model
+    solver.add_clause([1, 3]);
+    match solver.val(1) {
+        Err(e @ CadicalError::InvalidState { .. }) => {
+            assert_eq!(e.to_string(), "Invalid solver state: `val` requires the last `solve` to return SAT");
+        }
+        other => panic!("Unexpected result: {:?}", other),
+    }
+
+    // Adding assumptions invalidates the model too
+    assert_eq!(solver.solve()?, SolveResponse::Sat);
+    solver.assume(1)?;
+    assert!(matches!(solver.val(1), Err(CadicalError::InvalidState { .. })));
+
+    solver.assume(2)?;
+    assert_eq!(solver.solve()?, SolveResponse::Unsat);
+    assert!(solver.failed(1)? || solver.failed(2)?);
+    assert!(matches!(solver.val(1), Err(CadicalError::InvalidState { .. })));
+
+    Ok(())
+}
+
+#[test]
+fn test_zero_literal() -> color_eyre::Result<()> {
+    let solver = Cadical::new();
+    solver.add_clause([1, 2]);
+    assert!(matches!(solver.assume(0), Err(CadicalError::ZeroLiteral)));
+    assert!(matches!(solver.fixed(0), Err(CadicalError::ZeroLiteral)));
+    assert!(matches!(solver.freeze(0), Err(CadicalError::ZeroLiteral)));
+    assert_eq!(solver.solve()?, SolveResponse::Sat);
+    match solver.val(0) {
+        Err(e @ CadicalError::ZeroLiteral) => assert_eq!(e.to_string(), "Literal must be non-zero"),
+        other => panic!("Unexpected result: {:?}", other),
+    }
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "Literal must be non-zero")]
+fn test_zero_literal_in_clause() {
+    let solver = Cadical::new();
+    solver.add_clause([1, 0, 2]);
+}
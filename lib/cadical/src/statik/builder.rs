@@ -0,0 +1,51 @@
+use super::cadical::*;
+
+#[derive(Debug, Clone)]
+enum Setting {
+    Configure(String),
+    Option(String, i32),
+}
+
+/// Builder for [`Cadical`], applying the configuration and options
+/// right after initialization, before any clause is added.
+///
+/// Settings are applied in the order they were specified.
+#[derive(Debug, Clone, Default)]
+pub struct CadicalBuilder {
+    settings: Vec<Setting>,
+}
+
+impl CadicalBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use the configuration `name` (e.g. "sat", "unsat", "plain").
+    pub fn configure(mut self, name: impl Into<String>) -> Self {
+        self.settings.push(Setting::Configure(name.into()));
+        self
+    }
+
+    /// Set the option `name` to `value`.
+    pub fn option(mut self, name: impl Into<String>, value: i32) -> Self {
+        self.settings.push(Setting::Option(name.into(), value));
+        self
+    }
+
+    pub fn build(&self) -> Result<Cadical> {
+        let solver = Cadical::new();
+        for setting in self.settings.iter() {
+            match setting {
+                Setting::Configure(name) => solver.try_configure(name)?,
+                Setting::Option(name, value) => solver.try_set_option(name, *value)?,
+            }
+        }
+        Ok(solver)
+    }
+}
+
+impl Cadical {
+    pub fn builder() -> CadicalBuilder {
+        CadicalBuilder::new()
+    }
+}
@@ -1,8 +1,10 @@
+use std::cell::{Cell, RefCell};
 use std::ffi::{c_int, c_void, CString};
 use std::fmt::{Debug, Display, Formatter};
 use std::path::Path;
 use std::ptr;
 use std::slice;
+use std::time::Instant;
 
 use itertools::{zip_eq, Itertools};
 use log::{debug, trace};
@@ -17,12 +19,29 @@ use super::ffi::*;
 #[derive(Debug)]
 pub struct Cadical {
     ptr: CCadicalPtr,
+    /// Response of the last `solve`, reset when the formula or assumptions are modified.
+    last_response: Cell<Option<SolveResponse>>,
+    /// Assumptions for the next `solve`.
+    assumptions: RefCell<Vec<i32>>,
+    /// Assumptions of the last `solve`, used to extract the core.
+    last_assumptions: RefCell<Vec<i32>>,
+    /// Activation literals of the open scopes, innermost last.
+    scopes: Vec<i32>,
+    /// Activation variables of all scopes, including the closed ones, in increasing order.
+    activations: Vec<i32>,
 }
 
 impl Cadical {
     pub fn new() -> Self {
         let ptr = unsafe { ccadical_init() };
-        Self { ptr }
+        Self {
+            ptr,
+            last_response: Cell::new(None),
+            assumptions: RefCell::new(Vec::new()),
+            last_assumptions: RefCell::new(Vec::new()),
+            scopes: Vec::new(),
+            activations: Vec::new(),
+        }
     }
 }
 
@@ -34,7 +53,7 @@ impl Default for Cadical {
 
 impl Drop for Cadical {
     fn drop(&mut self) {
-        unsafe { ccadical_release(self.ptr) }
+        self.release();
     }
 }
 
@@ -50,6 +69,10 @@ impl Cadical {
         unsafe { cstr2str(ccadical_signature()) }
     }
 
+    /// Release the underlying solver. Releasing it again (e.g. on drop) is a no-op.
+    ///
+    /// After the release, the methods returning a [`Result`] fail with [`CadicalError::Released`],
+    /// and the other ones panic.
     pub fn release(&mut self) {
         if !self.ptr.is_null() {
             unsafe { ccadical_release(self.ptr) }
@@ -57,8 +80,42 @@ impl Cadical {
         }
     }
 
+    /// Pointer to the underlying solver, or [`CadicalError::Released`] if it was already released.
+    ///
+    /// All accessors go through this check, either directly or via [`live_ptr`][Cadical::live_ptr].
+    fn ptr(&self) -> Result<CCadicalPtr> {
+        ensure!(!self.ptr.is_null(), ReleasedSnafu);
+        Ok(self.ptr)
+    }
+
+    /// Pointer to the underlying solver for the methods not returning a [`Result`].
+    ///
+    /// # Panics
+    ///
+    /// Panics (naming the `method`) if the solver was already released.
+    fn live_ptr(&self, method: &str) -> CCadicalPtr {
+        match self.ptr() {
+            Ok(ptr) => ptr,
+            Err(e) => panic!("{}, cannot call `{}`", e, method),
+        }
+    }
+
+    /// Ensure that the last `solve` returned `expected`,
+    /// and the solver was not modified since then.
+    fn ensure_state(&self, expected: SolveResponse, method: &str) -> Result<()> {
+        ensure!(
+            self.last_response.get() == Some(expected),
+            InvalidStateSnafu {
+                message: format!("`{}` requires the last `solve` to return {}", method, expected),
+            }
+        );
+        Ok(())
+    }
+
     pub fn copy_to(&self, other: &Cadical) {
-        unsafe { ccadical_copy(self.ptr, other.ptr) }
+        let ptr = self.live_ptr("copy_to");
+        let other_ptr = other.live_ptr("copy_to");
+        unsafe { ccadical_copy(ptr, other_ptr) }
     }
 
     /// Adds a literal to the constraint clause. Same functionality as 'add' but
@@ -70,13 +127,21 @@ impl Cadical {
     ///
     /// Add valid literal to the constraint clause or zero to terminate it.
     pub fn constrain(&self, lit_or_zero: i32) {
-        unsafe { ccadical_constrain(self.ptr, lit_or_zero) }
+        let ptr = self.live_ptr("constrain");
+        assert_ne!(lit_or_zero, i32::MIN, "Invalid literal: {}", lit_or_zero);
+        self.last_response.set(None);
+        unsafe { ccadical_constrain(ptr, lit_or_zero) }
     }
 
     /// Determine whether the constraint was used to proof the unsatisfiability.
     /// Note that the formula might still be unsatisfiable without the constraint.
+    ///
+    /// Fails with [`CadicalError::InvalidState`] unless the last `solve` returned [`SolveResponse::Unsat`]
+    /// and the solver was not modified since then.
     pub fn constraint_failed(&self) -> Result<bool> {
-        match unsafe { ccadical_constraint_failed(self.ptr) } {
+        let ptr = self.ptr()?;
+        self.ensure_state(SolveResponse::Unsat, "constraint_failed")?;
+        match unsafe { ccadical_constraint_failed(ptr) } {
             0 => Ok(false),
             1 => Ok(true),
             invalid => InvalidResponseConstraintFailedSnafu { value: invalid }.fail(),
@@ -86,11 +151,21 @@ impl Cadical {
     // Overwrite (some) options with the forced values of the configuration.
     // The result is 'true' iff the 'name' is a valid configuration.
     pub fn configure(&self, name: &str) {
+        let ptr = self.live_ptr("configure");
         let name = CString::new(name).expect("CString::new failed");
-        let res = unsafe { ccadical_configure(self.ptr, name.as_ptr()) };
+        let res = unsafe { ccadical_configure(ptr, name.as_ptr()) };
         assert!(res);
     }
 
+    /// Fallible version of [`configure`][Cadical::configure].
+    pub fn try_configure(&self, name: &str) -> Result<()> {
+        let ptr = self.ptr()?;
+        let c_string = CString::new(name).expect("CString::new failed");
+        let ok = unsafe { ccadical_configure(ptr, c_string.as_ptr()) };
+        ensure!(ok, OptionSnafu { name });
+        Ok(())
+    }
+
     /// Explicit version of setting an option.  If the option 'name' exists
     /// and 'val' can be parsed then 'true' is returned.  If the option value
     /// is out of range the actual value is computed as the closest (minimum or
@@ -98,16 +173,27 @@ impl Cadical {
     ///
     /// Options can only be set right after initialization.
     pub fn set_option(&self, name: &str, val: i32) {
+        let ptr = self.live_ptr("set_option");
         let name = CString::new(name).expect("CString::new failed");
-        let ok = unsafe { ccadical_set_option(self.ptr, name.as_ptr(), val) };
+        let ok = unsafe { ccadical_set_option(ptr, name.as_ptr(), val) };
         assert!(ok, "ccadical_set_option returned false");
     }
 
+    /// Fallible version of [`set_option`][Cadical::set_option].
+    pub fn try_set_option(&self, name: &str, val: i32) -> Result<()> {
+        let ptr = self.ptr()?;
+        let c_string = CString::new(name).expect("CString::new failed");
+        let ok = unsafe { ccadical_set_option(ptr, c_string.as_ptr(), val) };
+        ensure!(ok, OptionSnafu { name });
+        Ok(())
+    }
+
     /// Get the current value of the option 'name'.  If 'name' is invalid then
     /// zero is returned.  Here '--...' arguments as invalid options.
     pub fn get_option(&self, name: &str) -> i32 {
+        let ptr = self.live_ptr("get_option");
         let name = CString::new(name).expect("CString::new failed");
-        unsafe { ccadical_get_option(self.ptr, name.as_ptr()) }
+        unsafe { ccadical_get_option(ptr, name.as_ptr()) }
     }
 
     /// Specify search limits, where currently 'name' can be "conflicts",
@@ -124,22 +210,51 @@ impl Cadical {
     /// 'lookahead').  We actually also have an internal "terminate" limit
     /// which however should only be used for testing and debugging.
     pub fn limit(&self, name: &str, limit: i32) {
+        let ptr = self.live_ptr("limit");
         let name = CString::new(name).expect("CString::new failed");
-        let ok = unsafe { ccadical_limit(self.ptr, name.as_ptr(), limit) };
+        let ok = unsafe { ccadical_limit(ptr, name.as_ptr(), limit) };
         assert!(ok, "ccadical_limit returned false");
     }
 
+    /// Fallible version of [`limit`][Cadical::limit].
+    pub fn try_limit(&self, name: &str, limit: i32) -> Result<()> {
+        let ptr = self.ptr()?;
+        let c_string = CString::new(name).expect("CString::new failed");
+        let ok = unsafe { ccadical_limit(ptr, c_string.as_ptr(), limit) };
+        ensure!(
+            ok,
+            UsageSnafu {
+                message: format!("invalid limit '{}'", name),
+            }
+        );
+        Ok(())
+    }
+
     /// Add valid literal to clause or zero to terminate clause.
+    ///
+    /// Inside a scope (see [`push_scope`][Cadical::push_scope]), the clause
+    /// is extended with the negation of the innermost activation literal.
     pub fn add(&self, lit_or_zero: i32) {
-        unsafe { ccadical_add(self.ptr, lit_or_zero) }
+        let ptr = self.live_ptr("add");
+        assert_ne!(lit_or_zero, i32::MIN, "Invalid literal: {}", lit_or_zero);
+        self.last_response.set(None);
+        if lit_or_zero == 0 {
+            if let Some(&act) = self.scopes.last() {
+                unsafe { ccadical_add(ptr, -act) }
+            }
+        }
+        unsafe { ccadical_add(ptr, lit_or_zero) }
     }
 
     /// Assume valid non zero literal for next call to 'solve'.
     /// These assumptions are reset after the call to 'solve'
     /// as well as after returning from 'simplify' and 'lookahead'.
     pub fn assume(&self, lit: i32) -> Result<()> {
-        ensure!(lit != 0, ZeroLiteralSnafu);
-        unsafe { ccadical_assume(self.ptr, lit) }
+        let ptr = self.ptr()?;
+        check_lit(lit)?;
+        self.last_response.set(None);
+        self.assumptions.borrow_mut().push(lit);
+        unsafe { ccadical_assume(ptr, lit) }
         Ok(())
     }
 
@@ -151,59 +266,89 @@ impl Cadical {
     ///
     /// Internally, the default number of rounds is 3.
     pub fn simplify(&self) -> Result<SimplifyResponse> {
-        match unsafe { ccadical_simplify(self.ptr) } {
-            0 => Ok(SimplifyResponse::Unknown),
-            10 => Ok(SimplifyResponse::Sat),
-            20 => Ok(SimplifyResponse::Unsat),
-            invalid => InvalidResponseSimplifySnafu { value: invalid }.fail(),
-        }
+        let ptr = self.ptr()?;
+        let response = match unsafe { ccadical_simplify(ptr) } {
+            0 => SimplifyResponse::Unknown,
+            10 => SimplifyResponse::Sat,
+            20 => SimplifyResponse::Unsat,
+            invalid => return InvalidResponseSimplifySnafu { value: invalid }.fail(),
+        };
+        self.last_assumptions.replace(self.assumptions.take());
+        self.last_response.set(match response {
+            SimplifyResponse::Unknown => None,
+            SimplifyResponse::Sat => Some(SolveResponse::Sat),
+            SimplifyResponse::Unsat => Some(SolveResponse::Unsat),
+        });
+        Ok(response)
     }
 
     /// Try to solve the current formula.
+    ///
+    /// The activation literals of all open scopes are assumed in addition to the user assumptions.
     pub fn solve(&self) -> Result<SolveResponse> {
-        match unsafe { ccadical_solve(self.ptr) } {
-            0 => Ok(SolveResponse::Interrupted),
-            10 => Ok(SolveResponse::Sat),
-            20 => Ok(SolveResponse::Unsat),
-            invalid => InvalidResponseSolveSnafu { value: invalid }.fail(),
+        let ptr = self.ptr()?;
+        for &act in self.scopes.iter() {
+            unsafe { ccadical_assume(ptr, act) }
         }
+        let response = match unsafe { ccadical_solve(ptr) } {
+            0 => SolveResponse::Interrupted,
+            10 => SolveResponse::Sat,
+            20 => SolveResponse::Unsat,
+            invalid => return InvalidResponseSolveSnafu { value: invalid }.fail(),
+        };
+        self.last_assumptions.replace(self.assumptions.take());
+        self.last_response.set(Some(response));
+        Ok(response)
     }
 
     /// Force termination of 'solve' asynchronously.
     pub fn terminate(&self) {
-        unsafe { ccadical_terminate(self.ptr) }
+        let ptr = self.live_ptr("terminate");
+        unsafe { ccadical_terminate(ptr) }
     }
 
     pub fn reset_assumptions(&self) {
-        unsafe { ccadical_reset_assumptions(self.ptr) }
+        let ptr = self.live_ptr("reset_assumptions");
+        self.last_response.set(None);
+        self.assumptions.borrow_mut().clear();
+        unsafe { ccadical_reset_assumptions(ptr) }
     }
 
     pub fn reset_constraint(&self) {
-        unsafe { ccadical_reset_constraint(self.ptr) }
+        let ptr = self.live_ptr("reset_constraint");
+        self.last_response.set(None);
+        unsafe { ccadical_reset_constraint(ptr) }
     }
 
     /// Get value of valid non-zero literal.
+    ///
+    /// The model is only available while the last `solve` returned [`SolveResponse::Sat`]:
+    /// adding clauses, constraints or assumptions after it invalidates the model (CaDiCaL itself
+    /// aborts on `val` in this case), so `val` fails with [`CadicalError::InvalidState`] until the next `solve`.
     pub fn val(&self, lit: i32) -> Result<LitValue> {
-        ensure!(lit != 0, ZeroLiteralSnafu);
-        let res = unsafe { ccadical_val(self.ptr, lit) };
-        if res.abs() != lit.abs() {
-            return InvalidResponseValSnafu { lit, value: res }.fail();
-        }
-        if res > 0 {
-            Ok(LitValue::True)
-        } else if res < 0 {
-            Ok(LitValue::False)
-        } else {
-            InvalidResponseValSnafu { lit, value: res }.fail()
+        let ptr = self.ptr()?;
+        check_lit(lit)?;
+        self.ensure_state(SolveResponse::Sat, "val")?;
+        // Note: `val` returns the literal itself if it is satisfied, and its negation if falsified,
+        //  so the sign of the result alone does not determine the value of a negative literal.
+        match unsafe { ccadical_val(ptr, lit) } {
+            p if p == lit => Ok(LitValue::True),
+            n if n == -lit => Ok(LitValue::False),
+            invalid => InvalidResponseValSnafu { lit, value: invalid }.fail(),
         }
     }
 
     /// Determine whether the valid non-zero literal is in the core.
     /// Returns `true` if the literal is in the core and `false` otherwise.
     /// Note that the core does not have to be minimal.
+    ///
+    /// Same as for [`val`][Cadical::val], fails with [`CadicalError::InvalidState`]
+    /// unless the last `solve` returned [`SolveResponse::Unsat`] and the solver was not modified since then.
     pub fn failed(&self, lit: i32) -> Result<bool> {
-        ensure!(lit != 0, ZeroLiteralSnafu);
-        Ok(unsafe { ccadical_failed(self.ptr, lit) })
+        let ptr = self.ptr()?;
+        check_lit(lit)?;
+        self.ensure_state(SolveResponse::Unsat, "failed")?;
+        Ok(unsafe { ccadical_failed(ptr, lit) })
     }
 
     /// Triggers the conclusion of incremental proofs.
@@ -217,99 +362,165 @@ impl Cadical {
     /// Then it will trigger a conclude_unsat event with the id(s)
     /// of the newly learnt clauses or the id of the global conflict.
     pub fn conclude(&self) {
-        unsafe { ccadical_conclude(self.ptr) }
+        let ptr = self.live_ptr("conclude");
+        unsafe { ccadical_conclude(ptr) }
+    }
+
+    /// Summary of the last `solve`: the model if it returned SAT,
+    /// or the failed assumptions if it returned UNSAT.
+    ///
+    /// Activation variables of the scopes are not included in the model.
+    pub fn result_summary(&self) -> Result<ResultSummary> {
+        self.ptr()?;
+        match self.last_response.get() {
+            Some(SolveResponse::Sat) => {
+                let vars = self.vars() as i32;
+                let mut model = Vec::with_capacity(vars as usize);
+                for var in 1..=vars {
+                    if self.activations.binary_search(&var).is_ok() {
+                        continue;
+                    }
+                    model.push(match self.val(var)? {
+                        LitValue::True => var,
+                        LitValue::False => -var,
+                    });
+                }
+                Ok(ResultSummary::Sat { model })
+            }
+            Some(SolveResponse::Unsat) => {
+                let mut core = Vec::new();
+                for &lit in self.last_assumptions.borrow().iter() {
+                    if self.failed(lit)? && !core.contains(&lit) {
+                        core.push(lit);
+                    }
+                }
+                Ok(ResultSummary::Unsat { core })
+            }
+            _ => Ok(ResultSummary::Unknown),
+        }
     }
 
     pub fn trace_proof<P>(&self, path: P)
     where
         P: AsRef<Path>,
     {
+        let ptr = self.live_ptr("trace_proof");
         let path = path.as_ref();
         let path = path.to_str().expect("path is not valid UTF-8");
         let path = CString::new(path).expect("CString::new failed");
-        let ok = unsafe { ccadical_trace_proof(self.ptr, path.as_ptr()) };
+        let ok = unsafe { ccadical_trace_proof(ptr, path.as_ptr()) };
         assert!(ok, "ccadical_trace_proof returned false");
     }
 
     pub fn close_proof(&self) {
-        unsafe { ccadical_close_proof(self.ptr) }
+        let ptr = self.live_ptr("close_proof");
+        unsafe { ccadical_close_proof(ptr) }
     }
 
     pub fn read_dimacs<P>(&self, path: P, strict: i32)
     where
         P: AsRef<Path>,
     {
+        let ptr = self.live_ptr("read_dimacs");
         assert!(0 <= strict && strict <= 2);
         let path = path.as_ref();
         let path = path.to_str().expect("path is not valid UTF-8");
         let path = CString::new(path).expect("CString::new failed");
-        unsafe { ccadical_read_dimacs(self.ptr, path.as_ptr(), strict) }
+        unsafe { ccadical_read_dimacs(ptr, path.as_ptr(), strict) }
     }
 
     pub fn write_dimacs<P>(&self, path: P)
     where
         P: AsRef<Path>,
     {
+        let ptr = self.live_ptr("write_dimacs");
         let path = path.as_ref();
         let path = path.to_str().expect("path is not valid UTF-8");
         let path = CString::new(path).expect("CString::new failed");
-        unsafe { ccadical_write_dimacs(self.ptr, path.as_ptr()) }
+        unsafe { ccadical_write_dimacs(ptr, path.as_ptr()) }
     }
 
     pub fn print_statistics(&self) {
-        unsafe { ccadical_print_statistics(self.ptr) }
+        let ptr = self.live_ptr("print_statistics");
+        unsafe { ccadical_print_statistics(ptr) }
     }
 
     pub fn print_resources(&self) {
-        unsafe { ccadical_print_resources(self.ptr) }
+        let ptr = self.live_ptr("print_resources");
+        unsafe { ccadical_print_resources(ptr) }
     }
 
     /// Number of variables.
     pub fn vars(&self) -> i64 {
-        unsafe { ccadical_vars(self.ptr) }
+        let ptr = self.live_ptr("vars");
+        unsafe { ccadical_vars(ptr) }
+    }
+
+    /// Increase the maximum variable index explicitly.
+    /// All variables up to `min_max_var` are allocated at once, which avoids
+    /// repeated reallocation of internal structures when adding a large formula.
+    /// The variables are also counted in 'vars'.
+    pub fn reserve(&self, min_max_var: i32) {
+        let ptr = self.live_ptr("reserve");
+        unsafe { ccadical_reserve(ptr, min_max_var) }
+    }
+
+    /// Allocate a fresh variable right after the maximum variable index.
+    pub fn new_var(&self) -> i32 {
+        let var = self.vars() as i32 + 1;
+        self.reserve(var);
+        var
     }
 
     /// Number of active variables.
     pub fn active(&self) -> i64 {
-        unsafe { ccadical_active(self.ptr) }
+        let ptr = self.live_ptr("active");
+        unsafe { ccadical_active(ptr) }
     }
 
     /// Number of active redundant clauses.
     pub fn redundant(&self) -> i64 {
-        unsafe { ccadical_redundant(self.ptr) }
+        let ptr = self.live_ptr("redundant");
+        unsafe { ccadical_redundant(ptr) }
     }
 
     /// Number of active irredundant clauses.
     pub fn irredundant(&self) -> i64 {
-        unsafe { ccadical_irredundant(self.ptr) }
+        let ptr = self.live_ptr("irredundant");
+        unsafe { ccadical_irredundant(ptr) }
     }
 
     /// Number of conflicts.
     pub fn conflicts(&self) -> i64 {
-        unsafe { ccadical_conflicts(self.ptr) }
+        let ptr = self.live_ptr("conflicts");
+        unsafe { ccadical_conflicts(ptr) }
     }
 
     /// Number of decisions.
     pub fn decisions(&self) -> i64 {
-        unsafe { ccadical_decisions(self.ptr) }
+        let ptr = self.live_ptr("decisions");
+        unsafe { ccadical_decisions(ptr) }
     }
 
     /// Number of restarts.
     pub fn restarts(&self) -> i64 {
-        unsafe { ccadical_restarts(self.ptr) }
+        let ptr = self.live_ptr("restarts");
+        unsafe { ccadical_restarts(ptr) }
     }
 
     /// Number of propagations.
     pub fn propagations(&self) -> i64 {
-        unsafe { ccadical_propagations(self.ptr) }
+        let ptr = self.live_ptr("propagations");
+        unsafe { ccadical_propagations(ptr) }
     }
 
     /// Root level assigned variables can be queried with this function.
     /// It returns '1' if the literal is implied by the formula, '-1' if its
     /// negation is implied, or '0' if this is unclear at this point.
     pub fn fixed(&self, lit: i32) -> Result<FixedResponse> {
-        ensure!(lit != 0, ZeroLiteralSnafu);
-        match unsafe { ccadical_fixed(self.ptr, lit) } {
+        let ptr = self.ptr()?;
+        check_lit(lit)?;
+        match unsafe { ccadical_fixed(ptr, lit) } {
             1 => Ok(FixedResponse::Positive),
             -1 => Ok(FixedResponse::Negative),
             0 => Ok(FixedResponse::Unclear),
@@ -317,24 +528,71 @@ impl Cadical {
         }
     }
 
+    /// All currently root-level fixed literals, in the order of variables.
+    /// The literal is positive if the variable is fixed to true, and negative otherwise.
+    pub fn fixed_literals(&self) -> Result<Vec<i32>> {
+        self.ptr()?;
+        let mut lits = Vec::new();
+        for var in 1..=self.vars() as i32 {
+            match self.fixed(var)? {
+                FixedResponse::Positive => lits.push(var),
+                FixedResponse::Negative => lits.push(-var),
+                FixedResponse::Unclear => {}
+            }
+        }
+        Ok(lits)
+    }
+
     pub fn is_active(&self, lit: i32) -> bool {
-        unsafe { ccadical_is_active(self.ptr, lit) }
+        let ptr = self.live_ptr("is_active");
+        unsafe { ccadical_is_active(ptr, lit) }
     }
 
     pub fn frozen(&self, lit: i32) -> Result<bool> {
-        ensure!(lit != 0, ZeroLiteralSnafu);
-        Ok(unsafe { ccadical_frozen(self.ptr, lit) })
+        let ptr = self.ptr()?;
+        check_lit(lit)?;
+        Ok(unsafe { ccadical_frozen(ptr, lit) })
     }
 
     pub fn freeze(&self, lit: i32) -> Result<()> {
-        ensure!(lit != 0, ZeroLiteralSnafu);
-        unsafe { ccadical_freeze(self.ptr, lit) }
+        let ptr = self.ptr()?;
+        check_lit(lit)?;
+        unsafe { ccadical_freeze(ptr, lit) }
         Ok(())
     }
 
     pub fn melt(&self, lit: i32) -> Result<()> {
-        ensure!(lit != 0, ZeroLiteralSnafu);
-        unsafe { ccadical_melt(self.ptr, lit) }
+        let ptr = self.ptr()?;
+        check_lit(lit)?;
+        unsafe { ccadical_melt(ptr, lit) }
+        Ok(())
+    }
+
+    /// Set the default decision phase of the variable to the polarity of `lit`.
+    pub fn phase(&self, lit: i32) -> Result<()> {
+        let ptr = self.ptr()?;
+        check_lit(lit)?;
+        unsafe { ccadical_phase(ptr, lit) }
+        Ok(())
+    }
+
+    /// Reset the decision phase of the variable, previously set by [`phase`][Cadical::phase].
+    pub fn unphase(&self, lit: i32) -> Result<()> {
+        let ptr = self.ptr()?;
+        check_lit(lit)?;
+        unsafe { ccadical_unphase(ptr, lit) }
+        Ok(())
+    }
+
+    /// Warm-start the next `solve` from the `model` (e.g. of a related instance),
+    /// by setting the phase of each variable to its value in the model.
+    ///
+    /// Phases only guide the decisions, so the result of `solve` is not affected.
+    pub fn set_phases_from_model(&self, model: &[i32]) -> Result<()> {
+        debug!("Setting {} phases from the model", model.len());
+        for &lit in model {
+            self.phase(lit)?;
+        }
         Ok(())
     }
 
@@ -351,6 +609,7 @@ impl Cadical {
         F: FnMut(Vec<i32>),
         // F: 'static,
     {
+        let ptr = self.live_ptr("unsafe_set_learn");
         unsafe extern "C" fn trampoline<F>(user_data: *mut c_void, clause: *mut c_int)
         where
             F: FnMut(Vec<i32>),
@@ -375,14 +634,15 @@ impl Cadical {
         assert_eq!(size_of_val(&closure), size_of::<usize>());
         let cb = trampoline::<F>;
         unsafe {
-            ccadical_set_learn(self.ptr, Box::into_raw(closure) as *mut c_void, max_length as i32, Some(cb));
+            ccadical_set_learn(ptr, Box::into_raw(closure) as *mut c_void, max_length as i32, Some(cb));
         }
     }
 
     pub fn get_top_score_variables(&self, limit: usize) -> &[i32] {
+        let ptr = self.live_ptr("get_top_score_variables");
         unsafe {
             let mut size = 0;
-            let data = ccadical_get_top_score_variables(self.ptr, limit, &mut size);
+            let data = ccadical_get_top_score_variables(ptr, limit, &mut size);
             if data.is_null() || size == 0 {
                 eprintln!("ccadical_get_top_score_variables returned data = {:?}, size = {}", data, size);
                 &[]
@@ -391,46 +651,109 @@ impl Cadical {
             }
         }
     }
+
+    /// At most `k` distinct active variables with the highest scores, best first.
+    pub fn top_scored_vars(&self, k: usize) -> Vec<i32> {
+        self.get_top_score_variables(k)
+            .iter()
+            .map(|&lit| lit.abs())
+            .filter(|&var| self.is_active(var))
+            .unique()
+            .take(k)
+            .collect()
+    }
+
+    /// Score of the variable `var` in `[0, 1]`, higher for more important variables.
+    ///
+    /// Note: CaDiCaL does not export the raw scores (nor the VSIDS/VMTF activities),
+    /// so the score is approximated by the rank of `var` among the top-scored variables:
+    /// the best variable gets `1.0`, and the worst one gets `1/n`, where `n` is the number
+    /// of active variables. Inactive (e.g. fixed or eliminated) variables get `0.0`.
+    /// Computing the score requires ranking all variables, so prefer
+    /// [`top_scored_vars`][Cadical::top_scored_vars] when querying many variables.
+    pub fn variable_score(&self, var: i32) -> f64 {
+        assert!(var > 0, "Variable must be positive, got {}", var);
+        if var as i64 > self.vars() || !self.is_active(var) {
+            return 0.0;
+        }
+        let ranked = self.top_scored_vars(self.vars() as usize);
+        match ranked.iter().position(|&v| v == var) {
+            Some(rank) => (ranked.len() - rank) as f64 / ranked.len() as f64,
+            None => 0.0,
+        }
+    }
 }
 
 impl Cadical {
     pub fn internal_propagate(&self) -> bool {
-        unsafe { ccadical_internal_propagate(self.ptr) }
+        let ptr = self.live_ptr("internal_propagate");
+        unsafe { ccadical_internal_propagate(ptr) }
     }
 
     pub fn internal_reset_conflict(&self) {
-        unsafe { ccadical_internal_reset_conflict(self.ptr) }
+        let ptr = self.live_ptr("internal_reset_conflict");
+        unsafe { ccadical_internal_reset_conflict(ptr) }
     }
 
     pub fn internal_level(&self) -> usize {
-        unsafe { ccadical_internal_level(self.ptr) as usize }
+        let ptr = self.live_ptr("internal_level");
+        unsafe { ccadical_internal_level(ptr) as usize }
     }
 
     pub fn internal_val(&self, lit: i32) -> i8 {
+        let ptr = self.live_ptr("internal_val");
         assert_ne!(lit, 0);
-        unsafe { ccadical_internal_val(self.ptr, lit) }
+        unsafe { ccadical_internal_val(ptr, lit) }
     }
 
     pub fn internal_assume_decision(&self, lit: i32) {
+        let ptr = self.live_ptr("internal_assume_decision");
         // Note: lit can be 0, which creates a "dummy" level without a decision.
         unsafe {
-            ccadical_internal_assume_decision(self.ptr, lit);
+            ccadical_internal_assume_decision(ptr, lit);
         }
     }
 
     pub fn internal_backtrack(&self, new_level: usize) {
+        let ptr = self.live_ptr("internal_backtrack");
         unsafe {
-            ccadical_internal_backtrack(self.ptr, new_level as i32);
+            ccadical_internal_backtrack(ptr, new_level as i32);
         }
     }
 }
 
+/// Result of [`Cadical::propcheck_full`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropcheckResult {
+    /// Whether the propagation of the given literals did not lead to a conflict.
+    pub ok: bool,
+    /// Number of propagated literals.
+    pub num_propagated: u64,
+    /// Propagated literals.
+    pub propagated: Vec<i32>,
+    /// Subset of the given literals responsible for the conflict (empty if `ok`).
+    pub core: Vec<i32>,
+}
+
 impl Cadical {
+    /// Propagate the given literals, collecting the propagated literals and the core in one call.
+    /// The solver state is restored afterwards.
+    pub fn propcheck_full(&self, lits: &[i32]) -> PropcheckResult {
+        let (ok, num_propagated) = self.propcheck(lits, true, true, true);
+        PropcheckResult {
+            ok,
+            num_propagated,
+            propagated: self.propcheck_get_propagated(),
+            core: if ok { Vec::new() } else { self.propcheck_get_core() },
+        }
+    }
+
     pub fn propcheck(&self, lits: &[i32], restore: bool, save_propagated: bool, save_core: bool) -> (bool, u64) {
+        let ptr = self.live_ptr("propcheck");
         unsafe {
             let mut num_propagated = 0;
             let res = ccadical_propcheck(
-                self.ptr,
+                ptr,
                 lits.as_ptr(),
                 lits.len(),
                 restore,
@@ -442,23 +765,43 @@ impl Cadical {
         }
     }
 
+    /// Propagate each of the `cubes` in turn (restoring the state after each one) until the `deadline`.
+    ///
+    /// Returns the result of [`propcheck`][Cadical::propcheck] for each cube,
+    /// or `None` for the cubes not evaluated before the deadline.
+    pub fn propcheck_all_budgeted(&self, cubes: &[Vec<i32>], deadline: Instant) -> Vec<Option<(bool, u64)>> {
+        let mut results = Vec::with_capacity(cubes.len());
+        for cube in cubes.iter() {
+            if Instant::now() >= deadline {
+                break;
+            }
+            results.push(Some(self.propcheck(cube, true, false, false)));
+        }
+        debug!("Evaluated {} of {} cubes before the deadline", results.len(), cubes.len());
+        results.resize(cubes.len(), None);
+        results
+    }
+
     pub fn propcheck_get_propagated(&self) -> Vec<i32> {
+        let ptr = self.live_ptr("propcheck_get_propagated");
         unsafe {
             let mut size = 0;
-            let ptr = ccadical_propcheck_get_propagated(self.ptr, &mut size);
-            slice::from_raw_parts(ptr, size).to_vec()
+            let data = ccadical_propcheck_get_propagated(ptr, &mut size);
+            slice::from_raw_parts(data, size).to_vec()
         }
     }
 
     pub fn propcheck_get_core(&self) -> Vec<i32> {
+        let ptr = self.live_ptr("propcheck_get_core");
         unsafe {
             let mut size = 0;
-            let data = ccadical_propcheck_get_core(self.ptr, &mut size);
+            let data = ccadical_propcheck_get_core(ptr, &mut size);
             slice::from_raw_parts(data, size).to_vec()
         }
     }
 
     pub fn propcheck_all_tree(&self, vars: &[i32], limit: u64, valid: Option<&mut Vec<Vec<i32>>>) -> u64 {
+        let ptr = self.live_ptr("propcheck_all_tree");
         unsafe extern "C" fn trampoline<F>(lits: *const c_int, size: usize, user_data: *mut c_void)
         where
             F: FnMut(&[i32]),
@@ -483,7 +826,7 @@ impl Cadical {
             let cb = get_trampoline(&closure);
             unsafe {
                 ccadical_propcheck_all_tree(
-                    self.ptr,
+                    ptr,
                     vars.as_ptr(),
                     vars.len(),
                     limit,
@@ -492,7 +835,7 @@ impl Cadical {
                 )
             }
         } else {
-            unsafe { ccadical_propcheck_all_tree(self.ptr, vars.as_ptr(), vars.len(), limit, None, ptr::null_mut()) }
+            unsafe { ccadical_propcheck_all_tree(ptr, vars.as_ptr(), vars.len(), limit, None, ptr::null_mut()) }
         }
     }
 }
@@ -502,6 +845,7 @@ impl Cadical {
     where
         F: FnMut(&[i32]) -> bool,
     {
+        let ptr = self.live_ptr("traverse_clauses");
         unsafe extern "C" fn trampoline<F>(lits: *const c_int, size: usize, user_data: *mut c_void) -> bool
         where
             F: FnMut(&[i32]) -> bool,
@@ -514,7 +858,7 @@ impl Cadical {
         let mut closure = callback;
         assert_eq!(size_of_val(&&mut closure), size_of::<usize>());
         let cb = trampoline::<F>;
-        unsafe { ccadical_traverse_clauses(self.ptr, redundant, Some(cb), &mut closure as *mut _ as *mut c_void) }
+        unsafe { ccadical_traverse_clauses(ptr, redundant, Some(cb), &mut closure as *mut _ as *mut c_void) }
     }
 
     pub fn extract_clauses(&self, redundant: bool) -> Vec<Vec<i32>> {
@@ -527,6 +871,37 @@ impl Cadical {
         });
         clauses
     }
+
+    /// Check that the current model satisfies all irredundant clauses in the solver.
+    ///
+    /// Returns [`CadicalError::InvalidState`] unless the last `solve` returned [`SolveResponse::Sat`].
+    pub fn check_model(&self) -> Result<bool> {
+        let clauses = self.extract_clauses(false);
+        self.check_model_against(&clauses)
+    }
+
+    /// Check that the current model satisfies the given clauses,
+    /// e.g. the original formula before any simplification.
+    ///
+    /// Returns [`CadicalError::InvalidState`] unless the last `solve` returned [`SolveResponse::Sat`].
+    pub fn check_model_against(&self, clauses: &[Vec<i32>]) -> Result<bool> {
+        self.ptr()?;
+        self.ensure_state(SolveResponse::Sat, "check_model")?;
+        for clause in clauses {
+            let mut satisfied = false;
+            for &lit in clause {
+                if self.val(lit)? == LitValue::True {
+                    satisfied = true;
+                    break;
+                }
+            }
+            if !satisfied {
+                debug!("Clause {:?} is not satisfied by the model", clause);
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
 }
 
 /// Additional methods.
@@ -534,6 +909,47 @@ impl Cadical {
     pub fn reset(&mut self) {
         self.release();
         self.ptr = unsafe { ccadical_init() };
+        self.last_response.set(None);
+        self.assumptions.borrow_mut().clear();
+        self.last_assumptions.borrow_mut().clear();
+        self.scopes.clear();
+        self.activations.clear();
+    }
+
+    /// Open a new scope backed by a fresh activation literal.
+    ///
+    /// Clauses added inside the scope are extended with the negation of the activation literal,
+    /// which is assumed on each `solve`, until the scope is closed by [`pop_scope`][Cadical::pop_scope].
+    ///
+    /// The activation literal is allocated by [`new_var`][Cadical::new_var],
+    /// so variables must also be allocated by it (or [`reserve`][Cadical::reserve]d) to avoid a clash.
+    pub fn push_scope(&mut self) {
+        let act = self.new_var();
+        debug!("Opening scope #{} with activation literal {}", self.scopes.len() + 1, act);
+        self.scopes.push(act);
+        self.activations.push(act);
+    }
+
+    /// Close the innermost scope by permanently asserting the negation of its activation literal,
+    /// so the clauses added inside it no longer constrain the formula.
+    ///
+    /// **Panics** if there is no open scope.
+    pub fn pop_scope(&mut self) {
+        let ptr = self.live_ptr("pop_scope");
+        let act = self.scopes.pop().expect("No scope to pop");
+        debug!("Closing scope #{} with activation literal {}", self.scopes.len() + 1, act);
+        // Note: the unit must not be extended with the activation literal of the outer scope,
+        //  so it is added directly, bypassing `add`.
+        self.last_response.set(None);
+        unsafe {
+            ccadical_add(ptr, -act);
+            ccadical_add(ptr, 0);
+        }
+    }
+
+    /// Number of open scopes.
+    pub fn num_scopes(&self) -> usize {
+        self.scopes.len()
     }
 
     pub fn add_clause<I>(&self, lits: I)
@@ -542,11 +958,31 @@ impl Cadical {
         I::Item: Into<i32>,
     {
         for lit in lits.into_iter() {
-            self.add(lit.into());
+            let lit = lit.into();
+            assert_ne!(lit, 0, "Literal must be non-zero, clause is terminated automatically");
+            self.add(lit);
         }
         self.add(0);
     }
 
+    /// Add the clause only if it passes the `filter`, e.g., to drop tautologies.
+    ///
+    /// Returns `true` if the clause was added.
+    pub fn add_clause_filtered<I, F>(&self, lits: I, mut filter: F) -> bool
+    where
+        I: IntoIterator,
+        I::Item: Into<i32>,
+        F: FnMut(&[i32]) -> bool,
+    {
+        let lits: Vec<i32> = lits.into_iter().map(Into::into).collect();
+        if filter(&lits) {
+            self.add_clause(lits);
+            true
+        } else {
+            false
+        }
+    }
+
     // TODO: remove
     pub fn try_add_clause<I>(&self, lits: I) -> Result<(), <I::Item as TryInto<i32>>::Error>
     where
@@ -739,14 +1175,16 @@ impl Cadical {
 
 impl Cadical {
     pub fn add_unit_clause(&self, lit: i32) {
+        let ptr = self.live_ptr("add_unit_clause");
         assert_ne!(lit, 0);
         unsafe {
-            ccadical_add_unit_clause(self.ptr, lit);
+            ccadical_add_unit_clause(ptr, lit);
         }
     }
 
     pub fn add_derived(&self, lit_or_zero: i32) {
-        unsafe { ccadical_add_derived(self.ptr, lit_or_zero) }
+        let ptr = self.live_ptr("add_derived");
+        unsafe { ccadical_add_derived(ptr, lit_or_zero) }
     }
 
     pub fn add_derived_clause<I>(&self, lits: I)
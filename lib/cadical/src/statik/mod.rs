@@ -1,6 +1,10 @@
+pub use self::builder::*;
 pub use self::cadical::*;
+pub use self::sync::*;
 
+mod builder;
 mod cadical;
+mod sync;
 
 pub mod ffi {
     pub use cadical_sys::statik::*;
@@ -0,0 +1,157 @@
+use std::fmt::{Debug, Formatter};
+use std::ops::{Deref, DerefMut};
+use std::sync::{Mutex, MutexGuard};
+
+use super::cadical::*;
+
+/// Owned solver which can be moved to another thread.
+struct SendCadical(Cadical);
+
+// SAFETY: CaDiCaL does not use thread-local state, so the solver can be used from
+// any thread, as long as it is not used from several threads at the same time.
+// The latter is guaranteed by the `Mutex` in `SyncCadical`.
+unsafe impl Send for SendCadical {}
+
+/// Thread-safe handle to the [`Cadical`] solver.
+///
+/// All calls are serialized via the internal mutex, so concurrent calls
+/// (including concurrent `solve`s) do not run in parallel, but wait for each other.
+///
+/// Note that the incremental state (assumptions, constraint) is shared between
+/// all users of the handle. Use [`add_clause`][SyncCadical::add_clause] and
+/// [`solve_under_assumptions`][SyncCadical::solve_under_assumptions], which hold
+/// the lock for the whole operation, or [`lock`][SyncCadical::lock] the solver
+/// explicitly for a sequence of calls.
+pub struct SyncCadical {
+    inner: Mutex<SendCadical>,
+}
+
+impl SyncCadical {
+    pub fn new() -> Self {
+        Self::from(Cadical::new())
+    }
+
+    /// Lock the solver for exclusive access.
+    pub fn lock(&self) -> SyncCadicalGuard<'_> {
+        // Note: the solver is not left in an inconsistent state by a panic in Rust code,
+        //  so we can safely ignore the poisoning.
+        let guard = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        SyncCadicalGuard { guard }
+    }
+
+    /// Consume the handle, returning the underlying solver.
+    pub fn into_inner(self) -> Cadical {
+        self.inner.into_inner().unwrap_or_else(|e| e.into_inner()).0
+    }
+}
+
+impl Default for SyncCadical {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Cadical> for SyncCadical {
+    fn from(solver: Cadical) -> Self {
+        Self {
+            inner: Mutex::new(SendCadical(solver)),
+        }
+    }
+}
+
+impl Debug for SyncCadical {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SyncCadical").finish_non_exhaustive()
+    }
+}
+
+/// Exclusive access to the solver behind [`SyncCadical`].
+pub struct SyncCadicalGuard<'a> {
+    guard: MutexGuard<'a, SendCadical>,
+}
+
+impl Deref for SyncCadicalGuard<'_> {
+    type Target = Cadical;
+
+    fn deref(&self) -> &Self::Target {
+        &self.guard.0
+    }
+}
+
+impl DerefMut for SyncCadicalGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.guard.0
+    }
+}
+
+impl SyncCadical {
+    pub fn signature(&self) -> &'static str {
+        self.lock().signature()
+    }
+
+    pub fn configure(&self, name: &str) {
+        self.lock().configure(name)
+    }
+
+    pub fn set_option(&self, name: &str, val: i32) {
+        self.lock().set_option(name, val)
+    }
+
+    pub fn get_option(&self, name: &str) -> i32 {
+        self.lock().get_option(name)
+    }
+
+    pub fn limit(&self, name: &str, limit: i32) {
+        self.lock().limit(name, limit)
+    }
+
+    /// Add the clause atomically.
+    pub fn add_clause<I>(&self, lits: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<i32>,
+    {
+        self.lock().add_clause(lits)
+    }
+
+    pub fn solve(&self) -> Result<SolveResponse> {
+        self.lock().solve()
+    }
+
+    /// Assume the given literals and solve, atomically.
+    pub fn solve_under_assumptions<I>(&self, assumptions: I) -> Result<SolveResponse>
+    where
+        I: IntoIterator,
+        I::Item: Into<i32>,
+    {
+        let solver = self.lock();
+        for lit in assumptions.into_iter() {
+            solver.assume(lit.into())?;
+        }
+        solver.solve()
+    }
+
+    pub fn val(&self, lit: i32) -> Result<LitValue> {
+        self.lock().val(lit)
+    }
+
+    pub fn failed(&self, lit: i32) -> Result<bool> {
+        self.lock().failed(lit)
+    }
+
+    pub fn fixed(&self, lit: i32) -> Result<FixedResponse> {
+        self.lock().fixed(lit)
+    }
+
+    pub fn vars(&self) -> i64 {
+        self.lock().vars()
+    }
+
+    pub fn irredundant(&self) -> i64 {
+        self.lock().irredundant()
+    }
+
+    pub fn conflicts(&self) -> i64 {
+        self.lock().conflicts()
+    }
+}
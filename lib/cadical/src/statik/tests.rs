@@ -3,6 +3,8 @@ use super::*;
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use itertools::Itertools;
+
 #[test]
 fn test_cadical_solver() -> color_eyre::Result<()> {
     let solver = Cadical::new();
@@ -240,4 +242,502 @@ fn test_top_score_variables() {
     let limit = 100;
     let top_score_vars = solver.get_top_score_variables(limit);
     println!("Top {} vars with highest score: {:?}", limit, top_score_vars);
+
+    let k = 10;
+    let top = solver.top_scored_vars(k);
+    println!("Top {} active vars: {:?}", k, top);
+    assert!(top.len() <= k);
+    assert_eq!(top.iter().unique().count(), top.len());
+    for &var in top.iter() {
+        assert!(solver.is_active(var));
+        assert!(solver.variable_score(var) > 0.0);
+    }
+    if let [first, second, ..] = top[..] {
+        assert!(solver.variable_score(first) > solver.variable_score(second));
+    }
+}
+
+#[test]
+fn test_result_summary() -> color_eyre::Result<()> {
+    let solver = Cadical::new();
+    assert_eq!(solver.result_summary()?, ResultSummary::Unknown);
+
+    // (1 or 2) and (not 1 or 3) and (2 or 4)
+    solver.add_clause([1, 2]);
+    solver.add_clause([-1, 3]);
+    solver.add_clause([2, 4]);
+
+    // SAT under 1 => model contains 1 and 3:
+    solver.assume(1)?;
+    assert_eq!(solver.solve()?, SolveResponse::Sat);
+    match solver.result_summary()? {
+        ResultSummary::Sat { model } => {
+            assert_eq!(model.len(), 4);
+            assert!(model.contains(&1));
+            assert!(model.contains(&3));
+        }
+        summary => panic!("Expected SAT summary, got {:?}", summary),
+    }
+
+    // UNSAT under (1, not 3, 4) => core is (1, not 3):
+    solver.assume(1)?;
+    solver.assume(-3)?;
+    solver.assume(4)?;
+    assert_eq!(solver.solve()?, SolveResponse::Unsat);
+    match solver.result_summary()? {
+        ResultSummary::Unsat { mut core } => {
+            core.sort();
+            assert_eq!(core, vec![-3, 1]);
+        }
+        summary => panic!("Expected UNSAT summary, got {:?}", summary),
+    }
+
+    // UNSAT without assumptions => empty core, even if the previous `solve` had some:
+    solver.assume(3)?;
+    solver.reset_assumptions();
+    solver.add_clause([-2]);
+    solver.add_clause([-4]);
+    assert_eq!(solver.solve()?, SolveResponse::Unsat);
+    assert_eq!(solver.result_summary()?, ResultSummary::Unsat { core: vec![] });
+
+    // Modified since the last `solve`:
+    solver.add_clause([2, 3]);
+    assert_eq!(solver.result_summary()?, ResultSummary::Unknown);
+
+    Ok(())
+}
+
+#[test]
+fn test_set_phases_from_model() -> color_eyre::Result<()> {
+    // (1 or 2) and (not 1 or 3) and (2 or 4) and (not 3 or not 4)
+    let clauses = [vec![1, 2], vec![-1, 3], vec![2, 4], vec![-3, -4]];
+
+    let solver = Cadical::new();
+    for clause in clauses.iter() {
+        solver.add_clause(clause.iter().copied());
+    }
+    assert_eq!(solver.solve()?, SolveResponse::Sat);
+    let mut model = Vec::new();
+    for v in 1..=4 {
+        model.push(if solver.val(v)? == LitValue::True { v } else { -v });
+    }
+
+    // Perturbed instance: additionally (not 2 or not 1)
+    let solver = Cadical::new();
+    for clause in clauses.iter() {
+        solver.add_clause(clause.iter().copied());
+    }
+    solver.add_clause([-2, -1]);
+    solver.set_phases_from_model(&model)?;
+    assert_eq!(solver.solve()?, SolveResponse::Sat);
+    assert!(solver.check_model()?);
+
+    // Phases do not change the satisfiability:
+    solver.add_clause([1]);
+    solver.add_clause([4]);
+    solver.set_phases_from_model(&model)?;
+    assert_eq!(solver.solve()?, SolveResponse::Unsat);
+
+    Ok(())
+}
+
+#[test]
+fn test_push_pop_scope() -> color_eyre::Result<()> {
+    let mut solver = Cadical::new();
+
+    // (1 or 2) and (not 1 or 3)
+    solver.add_clause([1, 2]);
+    solver.add_clause([-1, 3]);
+    assert_eq!(solver.solve()?, SolveResponse::Sat);
+
+    solver.push_scope();
+    assert_eq!(solver.num_scopes(), 1);
+    // In scope: (not 2)
+    solver.add_clause([-2]);
+    assert_eq!(solver.solve()?, SolveResponse::Sat);
+    assert_eq!(solver.val(2)?, LitValue::False);
+    // In nested scope: (not 3), which together with (not 2) and the base clauses is UNSAT
+    solver.push_scope();
+    solver.add_clause([-3]);
+    assert_eq!(solver.solve()?, SolveResponse::Unsat);
+
+    // Popping the inner scope removes (not 3):
+    solver.pop_scope();
+    assert_eq!(solver.solve()?, SolveResponse::Sat);
+    assert_eq!(solver.val(1)?, LitValue::True);
+    assert_eq!(solver.val(3)?, LitValue::True);
+
+    // Popping the outer scope removes (not 2):
+    solver.pop_scope();
+    assert_eq!(solver.num_scopes(), 0);
+    solver.assume(2)?;
+    solver.assume(-3)?;
+    assert_eq!(solver.solve()?, SolveResponse::Sat);
+
+    // Activation variables are not included in the summary:
+    match solver.result_summary()? {
+        ResultSummary::Sat { model } => assert_eq!(model, vec![-1, 2, -3]),
+        summary => panic!("Expected SAT summary, got {:?}", summary),
+    }
+
+    // Variables allocated by `new_var` do not clash with the activation variables:
+    solver.push_scope();
+    let x = solver.new_var();
+    solver.add_clause([x]);
+    solver.assume(-x)?;
+    assert_eq!(solver.solve()?, SolveResponse::Unsat);
+    assert_eq!(solver.result_summary()?, ResultSummary::Unsat { core: vec![-x] });
+    solver.pop_scope();
+    solver.assume(-x)?;
+    assert_eq!(solver.solve()?, SolveResponse::Sat);
+
+    Ok(())
+}
+
+#[test]
+fn test_reserve() -> color_eyre::Result<()> {
+    let solver = Cadical::new();
+    assert_eq!(solver.vars(), 0);
+
+    solver.reserve(100_000);
+    println!("vars after reserve: {}", solver.vars());
+    assert_eq!(solver.vars(), 100_000);
+
+    // Adding clauses within the reserved range does not change the number of variables:
+    solver.add_clause([1, -50_000]);
+    solver.add_clause([-1, 100_000]);
+    assert_eq!(solver.vars(), 100_000);
+
+    let response = solver.solve()?;
+    assert_eq!(response, SolveResponse::Sat);
+
+    Ok(())
+}
+
+#[test]
+fn test_add_clause_filtered() -> color_eyre::Result<()> {
+    let solver = Cadical::new();
+    let is_not_tautology = |lits: &[i32]| !lits.iter().any(|lit| lits.contains(&-lit));
+
+    assert!(solver.add_clause_filtered([1, 2], is_not_tautology));
+    assert!(!solver.add_clause_filtered([1, -1, 3], is_not_tautology));
+    assert!(solver.add_clause_filtered(vec![-2, 3], is_not_tautology));
+    assert!(!solver.add_clause_filtered(vec![2, 3, -2], is_not_tautology));
+    assert_eq!(solver.irredundant(), 2);
+
+    let response = solver.solve()?;
+    assert_eq!(response, SolveResponse::Sat);
+
+    Ok(())
+}
+
+#[test]
+fn test_sync_cadical() -> color_eyre::Result<()> {
+    use std::sync::Arc;
+    use std::thread;
+
+    let solver = Arc::new(SyncCadical::new());
+
+    // Each thread adds its own pair of clauses, (x or y) and (-x or -y), and solves:
+    let handles = (0..4)
+        .map(|i| {
+            let solver = Arc::clone(&solver);
+            thread::spawn(move || {
+                let x = 2 * i + 1;
+                let y = 2 * i + 2;
+                solver.add_clause([x, y]);
+                solver.add_clause([-x, -y]);
+                solver.solve_under_assumptions([x, y])
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        let response = handle.join().expect("thread panicked")?;
+        assert_eq!(response, SolveResponse::Unsat);
+    }
+
+    assert_eq!(solver.vars(), 8);
+    let response = solver.solve()?;
+    assert_eq!(response, SolveResponse::Sat);
+    let solver = Arc::try_unwrap(solver).expect("all threads are joined").into_inner();
+    for i in 0..4 {
+        let x = bool::from(solver.val(2 * i + 1)?);
+        let y = bool::from(solver.val(2 * i + 2)?);
+        assert!(x ^ y);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_fixed_literals() -> color_eyre::Result<()> {
+    let solver = Cadical::new();
+
+    // Units 1 and -3 imply 2 and -4:
+    solver.add_clause([1]);
+    solver.add_clause([-3]);
+    solver.add_clause([-1, 2]);
+    solver.add_clause([3, -4, 5]);
+    solver.add_clause([-4, -5]);
+    solver.add_clause([3, -2, -4]);
+    // Variable 6 is unconstrained:
+    solver.add_clause([5, 6]);
+
+    let response = solver.simplify()?;
+    assert_ne!(response, SimplifyResponse::Unsat);
+
+    let fixed = solver.fixed_literals()?;
+    println!("fixed = {:?}", fixed);
+    assert_eq!(fixed, vec![1, 2, -3, -4]);
+
+    Ok(())
+}
+
+#[test]
+fn test_check_model() -> color_eyre::Result<()> {
+    let solver = Cadical::new();
+
+    // (1 or 2) and (-1 or 3) and (-2 or -3) and (-3 or 4)
+    solver.add_clause([1, 2]);
+    solver.add_clause([-1, 3]);
+    solver.add_clause([-2, -3]);
+    solver.add_clause([-3, 4]);
+
+    let response = solver.solve()?;
+    assert_eq!(response, SolveResponse::Sat);
+    assert!(solver.check_model()?);
+
+    // Incrementally forbid the found model and check the new one:
+    let model = (1..=4).map(|v| solver.val(v)).collect::<Result<Vec<_>, _>>()?;
+    println!("model = {:?}", model);
+    solver.add_clause((1..=4).map(|v| if bool::from(model[v as usize - 1]) { -v } else { v }));
+    let response = solver.solve()?;
+    if response == SolveResponse::Sat {
+        assert!(solver.check_model()?);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_check_model_violated() -> color_eyre::Result<()> {
+    let solver = Cadical::new();
+
+    // Model is not available before `solve`
+    assert!(matches!(solver.check_model(), Err(CadicalError::InvalidState { .. })));
+
+    // (1 or 2) and (-1 or -2)
+    solver.add_clause([1, 2]);
+    solver.add_clause([-1, -2]);
+    assert_eq!(solver.solve()?, SolveResponse::Sat);
+    assert!(solver.check_model()?);
+
+    // The clause forbidding the found model is violated by it:
+    let mut blocking = Vec::new();
+    for v in 1..=2 {
+        blocking.push(if solver.val(v)? == LitValue::True { -v } else { v });
+    }
+    assert!(solver.check_model_against(&[vec![1, 2]])?);
+    assert!(!solver.check_model_against(&[vec![1, 2], blocking.clone()])?);
+
+    // Adding the clause invalidates the model:
+    solver.add_clause(blocking);
+    assert!(matches!(solver.check_model(), Err(CadicalError::InvalidState { .. })));
+
+    Ok(())
+}
+
+#[test]
+fn test_use_after_release() {
+    let mut solver = Cadical::new();
+    solver.add_clause([1, 2]);
+    solver.release();
+    // Second release is a no-op:
+    solver.release();
+
+    let res = solver.solve();
+    println!("solve after release: {:?}", res);
+    assert!(matches!(res, Err(CadicalError::Released)));
+    assert!(matches!(solver.val(1), Err(CadicalError::Released)));
+    assert!(matches!(solver.assume(1), Err(CadicalError::Released)));
+    // Note: `Drop` does not free the solver again.
+}
+
+#[test]
+#[should_panic(expected = "Solver was already released, cannot call `add`")]
+fn test_add_clause_after_release() {
+    let mut solver = Cadical::new();
+    solver.release();
+    // Methods not returning a `Result` panic, so the clause is not silently lost:
+    solver.add_clause([1, 2]);
+}
+
+#[test]
+fn test_builder() -> color_eyre::Result<()> {
+    let solver = Cadical::builder().configure("sat").option("elim", 0).option("seed", 42).build()?;
+    assert_eq!(solver.get_option("elim"), 0);
+    assert_eq!(solver.get_option("seed"), 42);
+    // "sat" configuration sets `elimreleff=10`:
+    assert_eq!(solver.get_option("elimreleff"), 10);
+
+    solver.add_clause([1, 2]);
+    solver.add_clause([-1, -2]);
+    let response = solver.solve()?;
+    assert_eq!(response, SolveResponse::Sat);
+
+    let res = Cadical::builder().option("no-such-option", 1).build();
+    assert!(matches!(res, Err(CadicalError::OptionError { name }) if name == "no-such-option"));
+    let res = Cadical::builder().configure("no-such-configuration").build();
+    assert!(matches!(res, Err(CadicalError::OptionError { .. })));
+
+    Ok(())
+}
+
+#[test]
+fn test_error_kinds() -> color_eyre::Result<()> {
+    let solver = Cadical::new();
+    solver.add_clause([1, 2]);
+    solver.add_clause([-1, -2]);
+
+    assert_eq!(solver.solve()?, SolveResponse::Sat);
+    assert!(solver.val(1).is_ok());
+
+    // Invalid literals
+    assert!(matches!(solver.val(0), Err(CadicalError::ZeroLiteral)));
+    assert!(matches!(solver.val(i32::MIN), Err(CadicalError::InvalidLiteral { lit: i32::MIN })));
+    assert!(matches!(
+        solver.assume(i32::MIN),
+        Err(CadicalError::InvalidLiteral { lit: i32::MIN })
+    ));
+
+    solver.assume(1)?;
+    solver.assume(2)?;
+    assert_eq!(solver.solve()?, SolveResponse::Unsat);
+    assert!(solver.failed(1)? || solver.failed(2)?);
+
+    // Invalid limit name
+    assert!(matches!(solver.try_limit("foo", 1), Err(CadicalError::UsageError { .. })));
+
+    Ok(())
+}
+
+#[test]
+fn test_invalid_state() -> color_eyre::Result<()> {
+    let solver = Cadical::new();
+    solver.add_clause([1, 2]);
+    solver.add_clause([-1, -2]);
+
+    // Model is not available before `solve`
+    assert!(matches!(solver.val(1), Err(CadicalError::InvalidState { .. })));
+
+    assert_eq!(solver.solve()?, SolveResponse::Sat);
+    assert!(solver.val(1).is_ok());
+
+    // Core is only available in the unsatisfiable state
+    assert!(matches!(solver.failed(1), Err(CadicalError::InvalidState { .. })));
+    assert!(matches!(solver.constraint_failed(), Err(CadicalError::InvalidState { .. })));
+
+    // Modifying the formula invalidates the model
+    solver.add_clause([1, 3]);
+    match solver.val(1) {
+        Err(e @ CadicalError::InvalidState { .. }) => {
+            assert_eq!(e.to_string(), "Invalid solver state: `val` requires the last `solve` to return SAT");
+        }
+        other => panic!("Unexpected result: {:?}", other),
+    }
+
+    // Adding assumptions invalidates the model too
+    assert_eq!(solver.solve()?, SolveResponse::Sat);
+    solver.assume(1)?;
+    assert!(matches!(solver.val(1), Err(CadicalError::InvalidState { .. })));
+
+    solver.assume(2)?;
+    assert_eq!(solver.solve()?, SolveResponse::Unsat);
+    assert!(solver.failed(1)? || solver.failed(2)?);
+    assert!(matches!(solver.val(1), Err(CadicalError::InvalidState { .. })));
+
+    Ok(())
+}
+
+#[test]
+fn test_zero_literal() -> color_eyre::Result<()> {
+    let solver = Cadical::new();
+    solver.add_clause([1, 2]);
+    assert!(matches!(solver.assume(0), Err(CadicalError::ZeroLiteral)));
+    assert!(matches!(solver.fixed(0), Err(CadicalError::ZeroLiteral)));
+    assert!(matches!(solver.freeze(0), Err(CadicalError::ZeroLiteral)));
+    assert_eq!(solver.solve()?, SolveResponse::Sat);
+    match solver.val(0) {
+        Err(e @ CadicalError::ZeroLiteral) => assert_eq!(e.to_string(), "Literal must be non-zero"),
+        other => panic!("Unexpected result: {:?}", other),
+    }
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "Literal must be non-zero")]
+fn test_zero_literal_in_clause() {
+    let solver = Cadical::new();
+    solver.add_clause([1, 0, 2]);
+}
+
+#[test]
+fn test_propcheck_full() {
+    let solver = Cadical::new();
+    // 1 -> 2, 2 -> 3, (1 and 3) -> 4, 4 -> -5
+    solver.add_clause([-1, 2]);
+    solver.add_clause([-2, 3]);
+    solver.add_clause([-1, -3, 4]);
+    solver.add_clause([-4, -5]);
+
+    // No conflict
+    let res = solver.propcheck_full(&[1]);
+    println!("propcheck_full([1]) = {:?}", res);
+    assert!(res.ok);
+    assert!(res.core.is_empty());
+    assert_eq!(res.propagated, solver.propcheck_get_propagated());
+    let (ok, num_propagated) = solver.propcheck(&[1], true, true, true);
+    assert_eq!(ok, res.ok);
+    assert_eq!(num_propagated, res.num_propagated);
+    assert_eq!(solver.propcheck_get_propagated(), res.propagated);
+
+    // Conflict
+    let res = solver.propcheck_full(&[1, 5]);
+    println!("propcheck_full([1, 5]) = {:?}", res);
+    assert!(!res.ok);
+    assert!(!res.core.is_empty());
+    assert_eq!(res.core, solver.propcheck_get_core());
+    let (ok, num_propagated) = solver.propcheck(&[1, 5], true, true, true);
+    assert_eq!(ok, res.ok);
+    assert_eq!(num_propagated, res.num_propagated);
+    assert_eq!(solver.propcheck_get_propagated(), res.propagated);
+    assert_eq!(solver.propcheck_get_core(), res.core);
+}
+
+#[test]
+fn test_propcheck_all_budgeted() {
+    use std::time::{Duration, Instant};
+
+    let solver = Cadical::new();
+    // 1 -> 2, 2 -> 3, (1 and 3) -> 4, 4 -> -5
+    solver.add_clause([-1, 2]);
+    solver.add_clause([-2, 3]);
+    solver.add_clause([-1, -3, 4]);
+    solver.add_clause([-4, -5]);
+
+    let cubes = (0..1000).map(|i| vec![if i % 2 == 0 { 1 } else { -1 }, 5]).collect_vec();
+
+    // Generous deadline: all cubes are evaluated
+    let results = solver.propcheck_all_budgeted(&cubes, Instant::now() + Duration::from_secs(60));
+    assert_eq!(results.len(), cubes.len());
+    for (cube, result) in cubes.iter().zip_eq(&results) {
+        let (ok, _) = result.expect("all cubes must be evaluated");
+        // Cube (1, 5) leads to a conflict:
+        assert_eq!(ok, cube[0] < 0);
+    }
+
+    // Deadline has already passed: no cubes are evaluated
+    let results = solver.propcheck_all_budgeted(&cubes, Instant::now());
+    assert_eq!(results.len(), cubes.len());
+    assert!(results.iter().all(|result| result.is_none()));
 }
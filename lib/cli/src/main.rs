@@ -2,7 +2,6 @@ use std::fmt::Display;
 use std::path::PathBuf;
 
 use clap::Parser;
-use color_eyre::eyre::bail;
 use elapsed::measure_time;
 use itertools::Itertools;
 use log::info;
@@ -14,8 +13,11 @@ use sat_nexus_core::solver::{SolveResponse, Solver};
 use sat_nexus_core::utils::bootstrap_solver_from_cnf;
 use sat_nexus_wrappers::cadical_dynamic::CadicalDynamicSolver;
 use sat_nexus_wrappers::dispatch::DispatchSolver;
+use sat_nexus_wrappers::ipasir::IpasirSolver;
+use sat_nexus_wrappers::kind::SolverKind;
 use sat_nexus_wrappers::kissat_dynamic::KissatDynamicSolver;
 use sat_nexus_wrappers::minisat_dynamic::MiniSatDynamicSolver;
+use sat_nexus_wrappers::simplesat::SimpleSatSolver;
 
 #[derive(Parser, Debug)]
 #[command(author, version)]
@@ -26,7 +28,7 @@ struct Cli {
 
     /// SAT solver
     #[arg(short, long, default_value = "cadical")]
-    solver: String,
+    solver: SolverKind,
 }
 
 #[allow(dead_code)]
@@ -36,18 +38,19 @@ fn get_solver1() -> DispatchSolver {
 
 #[allow(dead_code)]
 fn get_solver2(name: &str) -> color_eyre::Result<Box<dyn SimpleSolver>> {
-    let solver: Box<dyn SimpleSolver> = match name.to_ascii_lowercase().as_str() {
-        "minisat" => MiniSatDynamicSolver::new().into(),
-        "cadical" => CadicalDynamicSolver::new().into(),
-        "kissat" => KissatDynamicSolver::new().into(),
-        _ => bail!("Bad solver '{}'", name),
+    let solver: Box<dyn SimpleSolver> = match name.parse::<SolverKind>()? {
+        SolverKind::MiniSat => MiniSatDynamicSolver::new().into(),
+        SolverKind::Cadical => CadicalDynamicSolver::new().into(),
+        SolverKind::Kissat => KissatDynamicSolver::new().into(),
+        SolverKind::Glucose => IpasirSolver::new_glucose().into(),
+        SolverKind::Simple => SimpleSatSolver::new().into(),
     };
     Ok(solver)
 }
 
 #[allow(dead_code)]
-fn get_solver3(name: &str) -> DispatchSolver {
-    DispatchSolver::by_name(name)
+fn get_solver3(kind: SolverKind) -> DispatchSolver {
+    DispatchSolver::new_kind(kind)
 }
 
 fn main() -> color_eyre::Result<()> {
@@ -59,7 +62,8 @@ fn main() -> color_eyre::Result<()> {
     info!("args.cnf = {}", args.cnf.display());
     info!("args.solver = {}", args.solver);
 
-    let solver = get_solver3(&args.solver);
+    let solver = get_solver3(args.solver);
+    info!("backend = {}", solver.backend_signature());
 
     run(args, solver)
 }
@@ -0,0 +1,155 @@
+//! At-most-one encodings.
+//!
+//! - Pairwise: `n(n-1)/2` binary clauses, no auxiliary variables.
+//! - Commander [[`1`]]: the literals are split into groups, each having a _commander_ variable,
+//!   which is true iff some literal in the group is true, and at-most-one is recursively
+//!   encoded for the commanders.
+//! - Product [[`2`]]: the literals are arranged in a `p*q` grid, each literal implies its row and
+//!   column variables, and at-most-one is recursively encoded for the rows and the columns.
+//!
+//! [[`1`]] W. Klieber and G. Kwon, _"Efficient CNF encoding for selecting 1 from N objects,"_ in Constraints in Formal Verification, 2007.
+//!
+//! [[`2`]] J. Chen, _"A new SAT encoding of the at-most-one constraint,"_ in Modelling and Reformulating Constraint Satisfaction Problems, 2010.
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use itertools::Itertools;
+
+use crate::lit::Lit;
+use crate::op::encodings::Encodings;
+use crate::solver::Solver;
+
+/// Number of literals below which the recursive encodings fall back to the pairwise one.
+const PAIRWISE_THRESHOLD: usize = 6;
+
+/// Size of groups in the commander encoding.
+const COMMANDER_GROUP_SIZE: usize = 3;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub enum AmoEncoding {
+    #[default]
+    Pairwise,
+    Commander,
+    Product,
+}
+
+impl Display for AmoEncoding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AmoEncoding::Pairwise => write!(f, "pairwise"),
+            AmoEncoding::Commander => write!(f, "commander"),
+            AmoEncoding::Product => write!(f, "product"),
+        }
+    }
+}
+
+impl FromStr for AmoEncoding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pairwise" => Ok(AmoEncoding::Pairwise),
+            "commander" => Ok(AmoEncoding::Commander),
+            "product" => Ok(AmoEncoding::Product),
+            _ => Err(format!("Unknown at-most-one encoding '{}'", s)),
+        }
+    }
+}
+
+pub fn encode_pairwise<S>(solver: &mut S, lits: &[Lit])
+where
+    S: Solver,
+{
+    solver.encode_at_most_one(lits);
+}
+
+pub fn encode_commander<S>(solver: &mut S, lits: &[Lit])
+where
+    S: Solver,
+{
+    if lits.len() <= PAIRWISE_THRESHOLD {
+        return encode_pairwise(solver, lits);
+    }
+
+    let mut commanders = Vec::new();
+    for group in lits.chunks(COMMANDER_GROUP_SIZE) {
+        let c = solver.new_var();
+        encode_pairwise(solver, group);
+        // c <=> OR(group)
+        for &x in group.iter() {
+            solver.add_clause([-x, c]);
+        }
+        solver.add_clause(std::iter::once(-c).chain(group.iter().copied()));
+        commanders.push(c);
+    }
+    encode_commander(solver, &commanders);
+}
+
+pub fn encode_product<S>(solver: &mut S, lits: &[Lit])
+where
+    S: Solver,
+{
+    if lits.len() <= PAIRWISE_THRESHOLD {
+        return encode_pairwise(solver, lits);
+    }
+
+    let p = (lits.len() as f64).sqrt().ceil() as usize;
+    let q = lits.len().div_ceil(p);
+    let rows = (0..p).map(|_| solver.new_var()).collect_vec();
+    let columns = (0..q).map(|_| solver.new_var()).collect_vec();
+    for (k, &x) in lits.iter().enumerate() {
+        solver.add_clause([-x, rows[k / q]]);
+        solver.add_clause([-x, columns[k % q]]);
+    }
+    encode_product(solver, &rows);
+    encode_product(solver, &columns);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::card::Cardinality;
+    use crate::solver::ext::SolverExt;
+    use crate::solver::mock::MockSolver;
+    use crate::solver::Solver;
+
+    use super::*;
+
+    fn count(n: usize, encoding: AmoEncoding) -> (usize, usize) {
+        let mut solver = MockSolver::new();
+        let lits = solver.new_var_vec(n);
+        solver.encode_at_most_one_with(&lits, encoding);
+        (solver.num_vars() - n, solver.num_clauses())
+    }
+
+    #[test]
+    fn test_amo_sizes() {
+        // Small: all encodings coincide with the pairwise one.
+        for encoding in [AmoEncoding::Pairwise, AmoEncoding::Commander, AmoEncoding::Product] {
+            assert_eq!(count(5, encoding), (0, 10));
+        }
+
+        assert_eq!(count(12, AmoEncoding::Pairwise), (0, 66));
+        // 4 groups of 3: 3 + 3 + 1 clauses each, then 6 for commanders.
+        assert_eq!(count(12, AmoEncoding::Commander), (4, 34));
+        // 4x3 grid: 2 clauses per literal, then 6 for rows and 3 for columns.
+        assert_eq!(count(12, AmoEncoding::Product), (7, 33));
+
+        for n in [20, 50, 100] {
+            let (_, pairwise) = count(n, AmoEncoding::Pairwise);
+            let (_, commander) = count(n, AmoEncoding::Commander);
+            let (_, product) = count(n, AmoEncoding::Product);
+            assert_eq!(pairwise, n * (n - 1) / 2);
+            assert!(commander < pairwise);
+            assert!(product < commander);
+        }
+    }
+
+    #[test]
+    fn test_amo_encoding_from_str() {
+        for encoding in [AmoEncoding::Pairwise, AmoEncoding::Commander, AmoEncoding::Product] {
+            assert_eq!(encoding.to_string().parse::<AmoEncoding>(), Ok(encoding));
+        }
+        assert!("ladder".parse::<AmoEncoding>().is_err());
+    }
+}
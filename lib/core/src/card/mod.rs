@@ -1,8 +1,12 @@
+use itertools::Itertools;
+
+use amo::AmoEncoding;
 use totalizer::Totalizer;
 
 use crate::lit::Lit;
 use crate::solver::Solver;
 
+pub mod amo;
 pub mod totalizer;
 
 impl<S> Cardinality for S where S: Solver {}
@@ -11,4 +15,46 @@ pub trait Cardinality: Solver + Sized {
     fn declare_totalizer(&mut self, input_vars: &[Lit]) -> Totalizer {
         Totalizer::new(self, input_vars)
     }
+
+    /// Encode `sum(lits) <= k` using the totalizer.
+    fn encode_at_most_k(&mut self, lits: &[Lit], k: usize) {
+        if k >= lits.len() {
+            // Trivially satisfied.
+            return;
+        }
+        let mut totalizer = self.declare_totalizer(lits);
+        totalizer.declare_upper_bound_less_than_or_equal(self, k);
+    }
+
+    /// Encode `sum(lits) >= k` as `sum(~lits) <= n - k`.
+    fn encode_at_least_k(&mut self, lits: &[Lit], k: usize) {
+        assert!(k <= lits.len(), "At least {} of {} literals can never be satisfied", k, lits.len());
+        let negated = lits.iter().map(|&lit| -lit).collect_vec();
+        self.encode_at_most_k(&negated, lits.len() - k);
+    }
+
+    /// Encode `sum(lits) == k` as the conjunction of both bounds.
+    fn encode_exactly_k(&mut self, lits: &[Lit], k: usize) {
+        self.encode_at_most_k(lits, k);
+        self.encode_at_least_k(lits, k);
+    }
+
+    /// Encode `sum(lits) <= 1` using the commander encoding.
+    fn encode_at_most_one_commander(&mut self, lits: &[Lit]) {
+        amo::encode_commander(self, lits);
+    }
+
+    /// Encode `sum(lits) <= 1` using the product encoding.
+    fn encode_at_most_one_product(&mut self, lits: &[Lit]) {
+        amo::encode_product(self, lits);
+    }
+
+    /// Encode `sum(lits) <= 1` using the given `encoding`.
+    fn encode_at_most_one_with(&mut self, lits: &[Lit], encoding: AmoEncoding) {
+        match encoding {
+            AmoEncoding::Pairwise => amo::encode_pairwise(self, lits),
+            AmoEncoding::Commander => amo::encode_commander(self, lits),
+            AmoEncoding::Product => amo::encode_product(self, lits),
+        }
+    }
 }
@@ -24,6 +24,15 @@ impl Totalizer {
     {
         assert!(!input_vars.is_empty());
 
+        // Note: a single input is its own output (the loop below does not link them).
+        if input_vars.len() == 1 {
+            return Self {
+                output_vars: input_vars.to_vec(),
+                declared_lower_bound: None,
+                declared_upper_bound: None,
+            };
+        }
+
         let output_vars = (0..input_vars.len()).map(|_| solver.new_var()).collect_vec();
         let mut queue = VecDeque::from([(input_vars, output_vars.clone())]);
 
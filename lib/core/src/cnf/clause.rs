@@ -1,8 +1,9 @@
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 use std::slice::Iter;
 use std::vec::IntoIter;
 
-use itertools::Itertools;
+use itertools::{Either, Itertools};
 
 use crate::lit::Lit;
 
@@ -25,6 +26,34 @@ impl Clause {
     }
 }
 
+impl Clause {
+    /// Bring the clause to the canonical form: the literals are sorted by variable
+    /// (the negative literal goes first), and the duplicates are removed.
+    pub fn canonicalize(&mut self) {
+        self.lits.sort_unstable_by_key(|lit| (lit.var(), lit.sign()));
+        self.lits.dedup();
+    }
+
+    /// Canonical form of the clause, see [`canonicalize`][Clause::canonicalize].
+    pub fn canonical(&self) -> Clause {
+        let mut clause = self.clone();
+        clause.canonicalize();
+        clause
+    }
+}
+
+impl Clause {
+    /// Compact representation of the clause, see [`Lit::pack`].
+    pub fn pack(&self) -> Vec<u32> {
+        self.lits.iter().map(|lit| lit.pack()).collect()
+    }
+
+    /// Clause from the [packed][Clause::pack] literals.
+    pub fn from_packed(packed: &[u32]) -> Self {
+        Self::new(Lit::unpack_slice(packed).collect())
+    }
+}
+
 impl<L> FromIterator<L> for Clause
 where
     L: Into<Lit>,
@@ -65,12 +94,39 @@ impl Display for Clause {
 
 impl PartialEq for Clause {
     fn eq(&self, other: &Self) -> bool {
-        if self.lits.len() != other.lits.len() {
-            return false;
-        }
-        let lhs = self.lits.iter().copied().sorted_unstable();
-        let rhs = other.lits.iter().copied().sorted_unstable();
-        itertools::equal(lhs, rhs)
+        self.lits.len() == other.lits.len() && self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Clause {}
+
+impl PartialOrd for Clause {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Clauses are compared by their sorted literals (in the order of the canonical form, but keeping the duplicates),
+/// so the order of literals does not matter.
+impl Ord for Clause {
+    fn cmp(&self, other: &Self) -> Ordering {
+        sorted_keys(&self.lits).cmp(sorted_keys(&other.lits))
+    }
+}
+
+fn lit_key(lit: &Lit) -> (u32, i32) {
+    (lit.var(), lit.sign())
+}
+
+/// Keys of the literals in increasing order, including the duplicates.
+///
+/// Sorted literals (e.g. of a clause in the canonical form) are iterated directly,
+/// otherwise the keys are sorted in a separate buffer.
+fn sorted_keys(lits: &[Lit]) -> impl Iterator<Item = (u32, i32)> + '_ {
+    if lits.windows(2).all(|w| lit_key(&w[0]) <= lit_key(&w[1])) {
+        Either::Left(lits.iter().map(lit_key))
+    } else {
+        Either::Right(lits.iter().map(lit_key).sorted_unstable())
     }
 }
 
@@ -84,4 +140,50 @@ mod tests {
         let clause = Clause::new(lits);
         assert_eq!("[1, -2, 3]", &format!("{}", clause))
     }
+    #[test]
+    fn test_canonicalize() {
+        let mut a = Clause::from_iter([3, -1, 2]);
+        let mut b = Clause::from_iter([2, 3, -1]);
+        assert_eq!(a, b);
+        assert_eq!(a.cmp(&b), Ordering::Equal);
+
+        a.canonicalize();
+        b.canonicalize();
+        assert_eq!(a.lits, b.lits);
+        assert_eq!(a.lits, [Lit::new(-1), Lit::new(2), Lit::new(3)]);
+
+        // Negative literal goes first, duplicates are removed:
+        let mut c = Clause {
+            lits: vec![Lit::new(2), Lit::new(-2), Lit::new(2)],
+        };
+        c.canonicalize();
+        assert_eq!(c.lits, [Lit::new(-2), Lit::new(2)]);
+
+        assert!(Clause::from_iter([-1, 2]) < Clause::from_iter([1, 2]));
+        assert_ne!(Clause::from_iter([1, 2]), Clause::from_iter([1, 2, 3]));
+    }
+
+    #[test]
+    fn test_compare_with_duplicates() {
+        let clause = |lits: &[i32]| Clause {
+            lits: lits.iter().copied().map(Lit::new).collect(),
+        };
+
+        // Duplicates are kept, so the clauses are equal only as multisets:
+        assert_eq!(clause(&[2, 1, 2]), clause(&[2, 2, 1]));
+        assert_eq!(clause(&[2, 1, 2]), clause(&[1, 2, 2]));
+        assert_ne!(clause(&[1, 2, 2]), clause(&[1, 2]));
+        assert_ne!(clause(&[1, 1, 2]), clause(&[1, 2, 2]));
+        assert!(clause(&[2, 1, 1]) < clause(&[2, 1, 2]));
+        assert!(clause(&[2, 1]) < clause(&[1, 2, 2]));
+        assert!(clause(&[-1, 2, 2]) < clause(&[3, -1]));
+    }
+
+    #[test]
+    fn test_pack() {
+        let clause = Clause::from_iter([3, -1, 2]);
+        let packed = clause.pack();
+        assert_eq!(packed, [6, 3, 4]);
+        assert_eq!(Clause::from_packed(&packed).lits, clause.lits);
+    }
 }
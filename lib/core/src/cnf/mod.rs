@@ -1,12 +1,18 @@
 use log::debug;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fmt::{Debug, Display, Formatter};
 use std::path::Path;
 use std::slice::Iter;
 
+use rand::seq::SliceRandom;
+use rand::Rng;
+
 use clause::Clause;
 
 use crate::lit::Lit;
 
+pub use parsing::CnfReader;
+
 pub mod clause;
 mod parsing;
 
@@ -34,6 +40,11 @@ impl Cnf {
         debug!("Reading CNF from '{}'...", path.as_ref().display());
         parsing::parse_cnf(path)
     }
+
+    /// Reader with the configurable validation, see [`CnfReader`].
+    pub fn reader() -> CnfReader {
+        CnfReader::new()
+    }
 }
 
 impl Default for Cnf {
@@ -68,10 +79,161 @@ impl Display for Cnf {
 }
 
 impl Cnf {
+    /// Length of the longest clause, or 0 if there are no clauses.
+    pub fn max_clause_len(&self) -> usize {
+        self.iter().map(|clause| clause.lits.len()).max().unwrap_or(0)
+    }
+
+    /// All literal occurrences in the CNF, clause by clause.
+    pub fn literals(&self) -> impl Iterator<Item = Lit> + '_ {
+        self.iter().flat_map(|clause| clause.iter().copied())
+    }
+
+    /// Distinct variables occurring in the CNF, in ascending order.
+    pub fn variables(&self) -> impl Iterator<Item = usize> {
+        self.literals().map(|lit| lit.var() as usize).collect::<BTreeSet<_>>().into_iter()
+    }
+
     pub fn add(&mut self, clause: Clause) {
-        self.max_var = self.max_var.max(clause.lits.iter().map(|lit| lit.var() as usize).max().unwrap());
+        self.max_var = self
+            .max_var
+            .max(clause.lits.iter().map(|lit| lit.var() as usize).max().unwrap_or(0));
         self.clauses.push(clause);
     }
+
+    /// Remove duplicate clauses (equal up to the order of literals), keeping the first occurrences.
+    ///
+    /// Returns the number of removed clauses.
+    pub fn dedup(&mut self) -> usize {
+        let mut seen = BTreeSet::new();
+        let before = self.clauses.len();
+        self.clauses.retain(|clause| seen.insert(clause.canonical()));
+        before - self.clauses.len()
+    }
+
+    /// Add all clauses of `other` with the variables shifted by `offset`,
+    /// i.e. the variable `v` of `other` becomes `v + offset`.
+    ///
+    /// Use `offset = self.max_var` to place the variables of `other` after all the variables of `self`.
+    pub fn merge_offset(&mut self, other: &Cnf, offset: usize) {
+        let offset = offset as i32;
+        for clause in other.iter() {
+            let lits = clause.iter().map(|lit| Lit::new(lit.get() + lit.sign() * offset)).collect();
+            self.clauses.push(Clause::new(lits));
+        }
+        if other.max_var > 0 {
+            self.max_var = self.max_var.max(other.max_var + offset as usize);
+        }
+    }
+
+    /// Variable-dependency (primal) graph: variables are connected if they co-occur in some clause.
+    ///
+    /// Every variable occurring in the CNF is a key, possibly with no neighbours.
+    pub fn primal_graph(&self) -> HashMap<usize, HashSet<usize>> {
+        let mut graph: HashMap<usize, HashSet<usize>> = HashMap::new();
+        for clause in self.iter() {
+            for &a in clause.iter() {
+                let neighbours = graph.entry(a.var() as usize).or_default();
+                for &b in clause.iter() {
+                    if a.var() != b.var() {
+                        neighbours.insert(b.var() as usize);
+                    }
+                }
+            }
+        }
+        graph
+    }
+
+    /// Number of connected components of the [primal graph][Cnf::primal_graph],
+    /// i.e. the number of independent sub-formulas over disjoint sets of variables.
+    pub fn community_structure(&self) -> usize {
+        let graph = self.primal_graph();
+        let mut visited = HashSet::new();
+        let mut components = 0;
+        for &start in graph.keys() {
+            if !visited.insert(start) {
+                continue;
+            }
+            components += 1;
+            let mut stack = vec![start];
+            while let Some(v) = stack.pop() {
+                for &u in graph[&v].iter() {
+                    if visited.insert(u) {
+                        stack.push(u);
+                    }
+                }
+            }
+        }
+        components
+    }
+}
+
+/// Variable renumbering produced by [`Cnf::shuffle`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VarPermutation {
+    /// `forward[v - 1]` is the new number of the original variable `v`.
+    forward: Vec<u32>,
+    /// `backward[v - 1]` is the original number of the new variable `v`.
+    backward: Vec<u32>,
+}
+
+impl VarPermutation {
+    /// Translate the literal over the original variables to the shuffled ones.
+    pub fn apply(&self, lit: Lit) -> Lit {
+        Self::map(&self.forward, lit)
+    }
+
+    /// Translate the literal over the shuffled variables back to the original ones.
+    pub fn revert(&self, lit: Lit) -> Lit {
+        Self::map(&self.backward, lit)
+    }
+
+    /// Translate the model (as a list of literals) of the shuffled CNF back to the original variables.
+    pub fn revert_model(&self, model: &[Lit]) -> Vec<Lit> {
+        model.iter().map(|&lit| self.revert(lit)).collect()
+    }
+
+    /// Variables outside the permutation (above the maximum variable of the shuffled CNF) are kept as is.
+    fn map(mapping: &[u32], lit: Lit) -> Lit {
+        let var = mapping.get(lit.var() as usize - 1).copied().unwrap_or(lit.var()) as i32;
+        Lit::new(if lit.get() < 0 { -var } else { var })
+    }
+}
+
+impl Cnf {
+    /// Randomly permute the clauses, the literals inside each clause, and the variables.
+    ///
+    /// The result is equisatisfiable to the original CNF.
+    /// Returns the variable permutation, which can be used to translate the models back.
+    pub fn shuffle<R>(&mut self, rng: &mut R) -> VarPermutation
+    where
+        R: Rng + ?Sized,
+    {
+        // Note: `max_var` may be under-declared (e.g. by the DIMACS header), so the clauses are checked as well.
+        let max_var = self
+            .iter()
+            .flat_map(|clause| clause.iter())
+            .map(|lit| lit.var() as usize)
+            .fold(self.max_var, usize::max);
+        self.max_var = max_var;
+        let mut backward: Vec<u32> = (1..=max_var as u32).collect();
+        backward.shuffle(rng);
+        let mut forward = vec![0; backward.len()];
+        for (i, &v) in backward.iter().enumerate() {
+            forward[v as usize - 1] = i as u32 + 1;
+        }
+        let permutation = VarPermutation { forward, backward };
+
+        self.clauses.shuffle(rng);
+        for clause in self.clauses.iter_mut() {
+            for lit in clause.lits.iter_mut() {
+                *lit = permutation.apply(*lit);
+            }
+            clause.lits.shuffle(rng);
+        }
+
+        permutation
+    }
 }
 
 impl Extend<Clause> for Cnf {
@@ -95,10 +257,27 @@ impl crate::op::ops::AddClause for Cnf {
 
 #[cfg(test)]
 mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
     use crate::op::ops::Ops;
 
     use super::*;
 
+    /// Find a model (as a list of literals) by brute force.
+    fn brute_force(cnf: &Cnf) -> Option<Vec<Lit>> {
+        (0u32..1 << cnf.max_var).find_map(|bits| {
+            let model = (1..=cnf.max_var as i32)
+                .map(|v| Lit::new(if bits & (1 << (v - 1)) != 0 { v } else { -v }))
+                .collect::<Vec<_>>();
+            is_model(cnf, &model).then_some(model)
+        })
+    }
+
+    fn is_model(cnf: &Cnf, model: &[Lit]) -> bool {
+        cnf.iter().all(|clause| clause.iter().any(|lit| model.contains(lit)))
+    }
+
     #[test]
     fn test_cnf() {
         let mut cnf = Cnf::new();
@@ -108,4 +287,128 @@ mod tests {
         cnf.imply_and(a, [b, c]);
         assert_eq!(cnf.clauses, [Clause::from_iter([-1, 2]), Clause::from_iter([-1, 3])]);
     }
+
+    #[test]
+    fn test_dedup() {
+        let mut cnf = Cnf::from_iter([vec![1, -2], vec![2, 3], vec![-2, 1], vec![3, 2, 1], vec![3, 2]]);
+        assert_eq!(cnf.dedup(), 2);
+        assert_eq!(
+            cnf.clauses,
+            [Clause::from_iter([1, -2]), Clause::from_iter([2, 3]), Clause::from_iter([3, 2, 1])]
+        );
+        // Keeps the original order of literals:
+        assert_eq!(cnf.clauses[0].lits, [Lit::new(1), Lit::new(-2)]);
+    }
+
+    #[test]
+    fn test_merge_offset() {
+        let mut cnf = Cnf::from_iter([vec![1, -2], vec![2, 3]]);
+        let other = Cnf::from_iter([vec![-1, 3], vec![2]]);
+        cnf.merge_offset(&other, 3);
+        assert_eq!(cnf.max_var, 6);
+        assert_eq!(
+            cnf.clauses,
+            [
+                Clause::from_iter([1, -2]),
+                Clause::from_iter([2, 3]),
+                Clause::from_iter([-4, 6]),
+                Clause::from_iter([5]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_primal_graph() {
+        // Two groups of clauses over the disjoint sets of variables {1,2,3} and {4,5}:
+        let cnf = Cnf::from_iter([vec![1, -2], vec![2, 3], vec![-4, 5], vec![4]]);
+        let graph = cnf.primal_graph();
+        assert_eq!(graph.len(), 5);
+        assert_eq!(graph[&1], HashSet::from([2]));
+        assert_eq!(graph[&2], HashSet::from([1, 3]));
+        assert_eq!(graph[&4], HashSet::from([5]));
+        assert_eq!(cnf.community_structure(), 2);
+
+        // Connecting the groups:
+        let mut cnf = cnf;
+        cnf.add(Clause::from_iter([3, -5]));
+        assert_eq!(cnf.community_structure(), 1);
+    }
+
+    #[test]
+    fn test_max_clause_len() {
+        let dimacs = "p cnf 3 4\n1 2 0\n-1 3 0\n2 -3 0\n-2 0\n";
+        let cnf = Cnf::reader().max_clause_len(2).from_reader(dimacs.as_bytes()).unwrap();
+        assert_eq!(cnf.clauses.len(), 4);
+        assert_eq!(cnf.max_clause_len(), 2);
+        assert_eq!(Cnf::new().max_clause_len(), 0);
+
+        // Missing terminating zero after `-1 3` merges it with the next clause:
+        let dimacs = "p cnf 3 4\n1 2 0\n-1 3\n2 -3 0\n-2 0\n";
+        let cnf = Cnf::reader().from_reader(dimacs.as_bytes()).unwrap();
+        assert_eq!(cnf.max_clause_len(), 4);
+        let err = Cnf::reader().max_clause_len(3).from_reader(dimacs.as_bytes()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("Clause #2 has 4 literals"), "{}", err);
+    }
+
+    #[test]
+    fn test_literals_and_variables() {
+        let cnf = Cnf::from_iter([vec![3, -1], vec![1, 5], vec![-3, -5, 1]]);
+        assert_eq!(cnf.literals().map(|lit| lit.get()).collect::<Vec<_>>(), [3, -1, 1, 5, -3, -5, 1]);
+        assert_eq!(cnf.variables().collect::<Vec<_>>(), [1, 3, 5]);
+
+        assert_eq!(Cnf::new().literals().count(), 0);
+        assert_eq!(Cnf::new().variables().count(), 0);
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let sat = Cnf::from_iter([
+            vec![1, -2, 3],
+            vec![-1, 2],
+            vec![2, -3, 4],
+            vec![-4, 5],
+            vec![1, 3, 4],
+            vec![-5, -6, 7],
+            vec![6, -7],
+        ]);
+        let unsat = Cnf::from_iter([vec![1, 2], vec![-1, 2], vec![1, -2], vec![-1, -2, 3], vec![-3, 4], vec![-4]]);
+
+        for original in [sat, unsat] {
+            for _ in 0..10 {
+                let mut cnf = Cnf::from_iter(original.iter().map(|clause| clause.lits.clone()));
+                let permutation = cnf.shuffle(&mut rng);
+                assert_eq!(cnf.max_var, original.max_var);
+                assert_eq!(cnf.clauses.len(), original.clauses.len());
+                for v in 1..=original.max_var as i32 {
+                    let lit = Lit::new(-v);
+                    assert_eq!(permutation.revert(permutation.apply(lit)), lit);
+                }
+
+                let model = brute_force(&cnf);
+                assert_eq!(model.is_some(), brute_force(&original).is_some());
+                if let Some(model) = model {
+                    assert!(is_model(&original, &permutation.revert_model(&model)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_shuffle_under_declared() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut cnf = Cnf::from_iter([vec![1, -2], vec![2, 3], vec![-3, 4]]);
+        // E.g. the DIMACS header declares fewer variables than used:
+        cnf.max_var = 2;
+        let permutation = cnf.shuffle(&mut rng);
+        assert_eq!(cnf.max_var, 4);
+        for v in 1..=4 {
+            let lit = Lit::new(v);
+            assert!(permutation.apply(lit).var() <= 4);
+            assert_eq!(permutation.revert(permutation.apply(lit)), lit);
+        }
+        // Variables outside the permutation are kept as is:
+        assert_eq!(permutation.apply(Lit::new(-5)), Lit::new(-5));
+    }
 }
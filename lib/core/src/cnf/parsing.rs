@@ -1,4 +1,5 @@
 use std::fs::File;
+use std::io;
 use std::io::Read;
 use std::path::Path;
 
@@ -11,8 +12,12 @@ pub fn parse_cnf<P: AsRef<Path>>(path: P) -> Cnf {
     let mut cnf = String::new();
     f.read_to_string(&mut cnf).expect("Could not read CNF");
     let instance = parse_dimacs(&cnf).expect("Could not parse DIMACS");
+    instance_to_cnf(instance).expect("Bad instance")
+}
+
+fn instance_to_cnf(instance: Instance) -> Option<Cnf> {
     match instance {
-        Instance::Cnf { num_vars, clauses } => Cnf {
+        Instance::Cnf { num_vars, clauses } => Some(Cnf {
             max_var: num_vars as usize,
             clauses: clauses
                 .iter()
@@ -29,7 +34,55 @@ pub fn parse_cnf<P: AsRef<Path>>(path: P) -> Cnf {
                         .collect()
                 })
                 .collect(),
-        },
-        _ => panic!("Bad instance"),
+        }),
+        _ => None,
+    }
+}
+
+/// Reader of the [`Cnf`] in DIMACS format, with optional validation of the parsed clauses.
+#[derive(Debug, Clone, Default)]
+pub struct CnfReader {
+    max_clause_len: Option<usize>,
+}
+
+impl CnfReader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reject the CNF containing clauses longer than `max_clause_len`.
+    ///
+    /// Useful for catching malformed input, where a missing `0` terminator merges several clauses into one.
+    pub fn max_clause_len(mut self, max_clause_len: usize) -> Self {
+        self.max_clause_len = Some(max_clause_len);
+        self
+    }
+
+    pub fn from_reader<R: Read>(&self, mut reader: R) -> io::Result<Cnf> {
+        fn invalid_data(msg: String) -> io::Error {
+            io::Error::new(io::ErrorKind::InvalidData, msg)
+        }
+
+        let mut s = String::new();
+        reader.read_to_string(&mut s)?;
+        let instance = parse_dimacs(&s).map_err(|e| invalid_data(format!("Could not parse DIMACS: {:?}", e)))?;
+        let cnf = instance_to_cnf(instance).ok_or_else(|| invalid_data("Not a CNF instance".to_string()))?;
+
+        if let Some(max_clause_len) = self.max_clause_len {
+            if let Some((i, clause)) = cnf.iter().enumerate().find(|(_, clause)| clause.lits.len() > max_clause_len) {
+                return Err(invalid_data(format!(
+                    "Clause #{} has {} literals, which exceeds the limit of {} (missing terminating zero?)",
+                    i + 1,
+                    clause.lits.len(),
+                    max_clause_len
+                )));
+            }
+        }
+
+        Ok(cnf)
+    }
+
+    pub fn from_file<P: AsRef<Path>>(&self, path: P) -> io::Result<Cnf> {
+        self.from_reader(File::open(path)?)
     }
 }
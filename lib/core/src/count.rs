@@ -0,0 +1,129 @@
+use itertools::Itertools;
+use log::{debug, warn};
+use rand::Rng;
+
+use crate::lit::Lit;
+use crate::solver::Solver;
+
+/// Default limit on the number of models enumerated by [`exact_model_count`].
+pub const DEFAULT_MODEL_COUNT_LIMIT: u64 = 1 << 20;
+
+/// Exact number of models of the formula in `solver`, projected onto `vars`.
+///
+/// The models are enumerated one by one using blocking clauses, so this is only
+/// suitable for small instances (e.g. for verification).
+/// The blocking clauses are guarded by an activation literal, which is disabled afterwards.
+///
+/// **Panics** if there are more than [`DEFAULT_MODEL_COUNT_LIMIT`] models,
+/// see [`exact_model_count_with_limit`] for the non-panicking version.
+pub fn exact_model_count<S: Solver>(solver: &mut S, vars: &[i32]) -> u64 {
+    match exact_model_count_with_limit(solver, vars, DEFAULT_MODEL_COUNT_LIMIT) {
+        Some(count) => count,
+        None => panic!("Model count exceeds the limit of {}", DEFAULT_MODEL_COUNT_LIMIT),
+    }
+}
+
+/// Same as [`exact_model_count`], but stops the enumeration and returns `None`
+/// as soon as the number of models exceeds the `limit`.
+pub fn exact_model_count_with_limit<S: Solver>(solver: &mut S, vars: &[i32], limit: u64) -> Option<u64> {
+    let act = solver.new_var();
+    let count = count_models(solver, act, vars, limit);
+    // Disable the blocking clauses:
+    solver.add_clause([-act]);
+    if count.is_none() {
+        warn!("Model count exceeds the limit of {}, enumeration aborted", limit);
+    }
+    count
+}
+
+/// Approximate number of models of the formula in `solver`, projected onto `vars`.
+///
+/// This is an ApproxMC-style estimator: the model space is split into `num_cells` cells
+/// (rounded up to the power of two) using random XOR constraints as hash functions,
+/// then the models in one random cell are enumerated and the count is scaled back.
+/// The result is the median over `num_samples` independent samples.
+///
+/// The XOR constraints are Tseitin-encoded into CNF, and both the hash and the blocking
+/// clauses are guarded by an activation literal, which is disabled after each sample.
+/// Thus, the solver is left with the same set of models (over the original variables),
+/// but with auxiliary variables and clauses added.
+pub fn approx_model_count<S: Solver>(solver: &mut S, vars: &[i32], num_cells: u32, num_samples: u32) -> f64 {
+    approx_model_count_with_rng(solver, vars, num_cells, num_samples, &mut rand::thread_rng())
+}
+
+/// Same as [`approx_model_count`], with the hash functions drawn from the given `rng`.
+pub fn approx_model_count_with_rng<S: Solver>(solver: &mut S, vars: &[i32], num_cells: u32, num_samples: u32, rng: &mut impl Rng) -> f64 {
+    assert!(num_cells > 0, "number of cells must be positive");
+    assert!(num_samples > 0, "number of samples must be positive");
+
+    let num_xors = num_cells.next_power_of_two().trailing_zeros();
+    let scale = (1u64 << num_xors) as f64;
+
+    let estimates = (0..num_samples)
+        .map(|_| {
+            let act = solver.new_var();
+            for _ in 0..num_xors {
+                let lits = vars.iter().filter(|_| rng.gen_bool(0.5)).map(|&v| Lit::new(v)).collect_vec();
+                add_xor(solver, act, &lits, rng.gen_bool(0.5));
+            }
+            let count = count_models(solver, act, vars, u64::MAX).unwrap();
+            // Disable the hash and the blocking clauses:
+            solver.add_clause([-act]);
+            debug!("Cell contains {} models, estimate = {}", count, count as f64 * scale);
+            count as f64 * scale
+        })
+        .sorted_by(f64::total_cmp)
+        .collect_vec();
+
+    let mid = estimates.len() / 2;
+    if estimates.len() % 2 == 1 {
+        estimates[mid]
+    } else {
+        (estimates[mid - 1] + estimates[mid]) / 2.0
+    }
+}
+
+/// Add the constraint `xor(lits) = rhs` guarded by `act`, using the Tseitin-encoded chain of XORs.
+fn add_xor<S: Solver>(solver: &mut S, act: Lit, lits: &[Lit], rhs: bool) {
+    let Some((&first, rest)) = lits.split_first() else {
+        if rhs {
+            // Empty XOR is false, so the cell is empty:
+            solver.add_clause([-act]);
+        }
+        return;
+    };
+
+    let mut acc = first;
+    for &x in rest {
+        // t <-> acc ^ x
+        let t = solver.new_var();
+        solver.add_clause([-t, acc, x]);
+        solver.add_clause([-t, -acc, -x]);
+        solver.add_clause([t, -acc, x]);
+        solver.add_clause([t, acc, -x]);
+        acc = t;
+    }
+    solver.add_clause([-act, if rhs { acc } else { -acc }]);
+}
+
+/// Enumerate the models over `vars` under the assumption `act`, which guards the blocking clauses.
+/// Returns `None` if there are more than `limit` models.
+fn count_models<S: Solver>(solver: &mut S, act: Lit, vars: &[i32], limit: u64) -> Option<u64> {
+    let mut count = 0;
+    loop {
+        solver.assume(act);
+        if !solver.solve().is_sat() {
+            break;
+        }
+        if count == limit {
+            return None;
+        }
+        count += 1;
+        // Ban the solution:
+        let refutation = vars
+            .iter()
+            .map(|&v| if solver.value(v).bool() { Lit::new(-v) } else { Lit::new(v) });
+        solver.add_clause(std::iter::once(-act).chain(refutation).collect_vec());
+    }
+    Some(count)
+}
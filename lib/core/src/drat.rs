@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use log::{debug, warn};
+use snafu::Snafu;
+
+use crate::cnf::Cnf;
+use crate::lit::Lit;
+
+/// Single step of the DRAT proof.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ProofStep {
+    /// Addition of the lemma, which must be RUP or RAT (on its first literal).
+    Add(Vec<Lit>),
+    /// Deletion of the clause.
+    Delete(Vec<Lit>),
+}
+
+pub type Result<T, E = DratError> = std::result::Result<T, E>;
+
+#[derive(Debug, Snafu)]
+pub enum DratError {
+    #[snafu(display("Proof step #{} adds clause [{}], which is neither RUP nor RAT", step, clause.iter().join(", ")))]
+    NotImplied { step: usize, clause: Vec<Lit> },
+
+    #[snafu(display("Proof does not derive the empty clause"))]
+    NoConflict,
+
+    #[snafu(display("Bad token '{}' on line {}", token, line))]
+    Parse { line: usize, token: String },
+}
+
+/// Parse the proof in the textual DRAT format: one clause per line, terminated by `0`,
+/// with the deletions prefixed by `d`. Comment lines (starting with `c`) are skipped.
+pub fn parse_proof(s: &str) -> Result<Vec<ProofStep>> {
+    let mut steps = Vec::new();
+    for (i, line) in s.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('c') {
+            continue;
+        }
+        let (is_delete, rest) = match line.strip_prefix('d') {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+        let mut lits = Vec::new();
+        for token in rest.split_whitespace() {
+            let lit: i32 = token.parse().map_err(|_| DratError::Parse {
+                line: i + 1,
+                token: token.to_string(),
+            })?;
+            if lit == 0 {
+                break;
+            }
+            lits.push(Lit::new(lit));
+        }
+        steps.push(if is_delete { ProofStep::Delete(lits) } else { ProofStep::Add(lits) });
+    }
+    Ok(steps)
+}
+
+/// Check that the `proof` refutes the `cnf`, using the forward DRAT checking.
+///
+/// Each added lemma must be either a *reverse unit propagation* (RUP) consequence of the current
+/// clauses, or a *resolution asymmetric tautology* (RAT) on its first literal.
+/// The proof is valid if it derives the empty clause (explicitly, or by unit propagation at the end).
+///
+/// Note that the unit propagation here is naive (without watched literals),
+/// so this is only suitable for small instances, e.g. for testing.
+pub fn check(cnf: &Cnf, proof: &[ProofStep]) -> Result<()> {
+    let mut checker = Checker {
+        clauses: cnf.iter().map(|clause| clause.lits.clone()).collect(),
+    };
+
+    for (i, step) in proof.iter().enumerate() {
+        let step_index = i + 1;
+        match step {
+            ProofStep::Add(clause) => {
+                if !checker.is_rup(clause) && !checker.is_rat(clause) {
+                    return NotImpliedSnafu {
+                        step: step_index,
+                        clause: clause.clone(),
+                    }
+                    .fail();
+                }
+                if clause.is_empty() {
+                    debug!("Empty clause derived on step #{}", step_index);
+                    return Ok(());
+                }
+                checker.clauses.push(clause.clone());
+            }
+            ProofStep::Delete(clause) => {
+                let key = canonical(clause);
+                match checker.clauses.iter().position(|c| canonical(c) == key) {
+                    Some(pos) => {
+                        checker.clauses.swap_remove(pos);
+                    }
+                    None => warn!("Ignoring deletion of missing clause {:?} on step #{}", clause, step_index),
+                }
+            }
+        }
+    }
+
+    if checker.is_rup(&[]) {
+        Ok(())
+    } else {
+        NoConflictSnafu.fail()
+    }
+}
+
+/// Literals sorted by variable (negative first) without duplicates, for comparing clauses up to the order.
+fn canonical(lits: &[Lit]) -> Vec<Lit> {
+    lits.iter().copied().sorted_by_key(|lit| (lit.var(), lit.sign())).dedup().collect()
+}
+
+struct Checker {
+    clauses: Vec<Vec<Lit>>,
+}
+
+impl Checker {
+    /// Check whether assigning all literals of `lits` to false leads to a conflict by unit propagation.
+    fn is_rup(&self, lits: &[Lit]) -> bool {
+        let mut assignment: HashMap<u32, bool> = HashMap::new();
+        for &lit in lits {
+            // Assign `lit` to false:
+            let value = lit.get() < 0;
+            if *assignment.entry(lit.var()).or_insert(value) != value {
+                // Tautology
+                return true;
+            }
+        }
+        self.propagate(&mut assignment)
+    }
+
+    /// Check whether `lits` is a resolution asymmetric tautology on its first literal,
+    /// i.e. all resolvents with the clauses containing the negated pivot are RUP.
+    fn is_rat(&self, lits: &[Lit]) -> bool {
+        let Some(&pivot) = lits.first() else {
+            return false;
+        };
+        self.clauses.iter().filter(|clause| clause.contains(&-pivot)).all(|clause| {
+            let resolvent: Vec<Lit> = lits.iter().chain(clause.iter().filter(|&&lit| lit != -pivot)).copied().collect();
+            self.is_rup(&resolvent)
+        })
+    }
+
+    /// Unit propagation until the fixpoint. Returns `true` if a conflict is found.
+    fn propagate(&self, assignment: &mut HashMap<u32, bool>) -> bool {
+        let value = |assignment: &HashMap<u32, bool>, lit: Lit| assignment.get(&lit.var()).map(|&v| v == (lit.get() > 0));
+        loop {
+            let mut changed = false;
+            for clause in self.clauses.iter() {
+                let mut unassigned = None;
+                let mut num_unassigned = 0;
+                let mut satisfied = false;
+                for &lit in clause.iter() {
+                    match value(assignment, lit) {
+                        Some(true) => {
+                            satisfied = true;
+                            break;
+                        }
+                        Some(false) => {}
+                        None => {
+                            unassigned = Some(lit);
+                            num_unassigned += 1;
+                        }
+                    }
+                }
+                if satisfied {
+                    continue;
+                }
+                match (num_unassigned, unassigned) {
+                    (0, _) => return true,
+                    (1, Some(unit)) => {
+                        assignment.insert(unit.var(), unit.get() > 0);
+                        changed = true;
+                    }
+                    _ => {}
+                }
+            }
+            if !changed {
+                return false;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// All 4 clauses over 2 variables: UNSAT.
+    fn unsat_cnf() -> Cnf {
+        Cnf::from_iter([vec![1, 2], vec![-1, 2], vec![1, -2], vec![-1, -2]])
+    }
+
+    #[test]
+    fn test_valid_proof() {
+        let proof = parse_proof("c proof\n2 0\nd 1 2 0\n0\n").unwrap();
+        assert_eq!(
+            proof,
+            [
+                ProofStep::Add(vec![Lit::new(2)]),
+                ProofStep::Delete(vec![Lit::new(1), Lit::new(2)]),
+                ProofStep::Add(vec![]),
+            ]
+        );
+        check(&unsat_cnf(), &proof).unwrap();
+
+        // Without the explicit empty clause, the conflict is found by unit propagation:
+        check(&unsat_cnf(), &proof[..1]).unwrap();
+    }
+
+    #[test]
+    fn test_rat() {
+        let cnf = Cnf::from_iter([vec![1, 2], vec![-1, 2], vec![1, -2]]);
+
+        // (3 or -1) is not RUP, but it is RAT on the fresh variable 3, yet the formula is SAT:
+        let proof = [ProofStep::Add(vec![Lit::new(3), Lit::new(-1)])];
+        assert!(matches!(check(&cnf, &proof), Err(DratError::NoConflict)));
+
+        // (-1) is neither RUP nor RAT:
+        let proof = [ProofStep::Add(vec![Lit::new(-1)])];
+        assert!(matches!(check(&cnf, &proof), Err(DratError::NotImplied { step: 1, .. })));
+    }
+
+    #[test]
+    fn test_corrupted_proof() {
+        // Lemma (1) is not implied after deleting (1 or -2):
+        let proof = parse_proof("d 1 -2 0\n1 0\n0\n").unwrap();
+        match check(&unsat_cnf(), &proof) {
+            Err(DratError::NotImplied { step, clause }) => {
+                assert_eq!(step, 2);
+                assert_eq!(clause, [Lit::new(1)]);
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+
+        assert!(matches!(parse_proof("1 x 0\n"), Err(DratError::Parse { line: 1, .. })));
+    }
+}
@@ -1,4 +1,6 @@
 use std::fmt::Write as _;
+use std::io;
+use std::path::Path;
 
 use crate::lit::Lit;
 use crate::map::Map;
@@ -34,6 +36,7 @@ impl CnfEncoder {
         self.clauses.push(clause);
     }
 
+    /// Render the encoded CNF in DIMACS format.
     pub fn to_dimacs(&self) -> String {
         let mut output = String::new();
         writeln!(output, "p cnf {} {}", self.num_vars, self.clauses.len()).unwrap();
@@ -45,6 +48,11 @@ impl CnfEncoder {
         }
         output
     }
+
+    /// Write the encoded CNF in DIMACS format to the file at `path`.
+    pub fn write_dimacs<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        std::fs::write(path, self.to_dimacs())
+    }
 }
 
 // Variables
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::fmt::{Debug, Display, Formatter};
 use std::hash::Hash;
 use std::ops;
@@ -186,6 +186,60 @@ impl<T> Expr<T> {
     }
 }
 
+impl<T> Expr<T>
+where
+    T: Ord + Clone,
+{
+    /// Collects all terminals referenced in the expression.
+    pub fn vars(&self) -> BTreeSet<T> {
+        fn collect<T: Ord + Clone>(expr: &Expr<T>, vars: &mut BTreeSet<T>) {
+            match expr {
+                Expr::Const(_) => {}
+                Expr::Terminal(v) => {
+                    vars.insert(v.clone());
+                }
+                Expr::Not { arg } => collect(arg, vars),
+                Expr::And { args } | Expr::Or { args } => {
+                    for arg in args {
+                        collect(arg, vars);
+                    }
+                }
+            }
+        }
+
+        let mut vars = BTreeSet::new();
+        collect(self, &mut vars);
+        vars
+    }
+
+    /// Returns the largest terminal referenced in the expression, or `None` if there are none.
+    pub fn max_var(&self) -> Option<T> {
+        self.vars().pop_last()
+    }
+}
+
+impl<T> Expr<T>
+where
+    T: Hash + Eq + Clone,
+{
+    /// Replaces the terminals present in `map` with the corresponding expressions.
+    ///
+    /// Combined with [`fold_constants`][crate::formula::simplify::fold_constants],
+    /// substituting constants gives the cofactors of the expression.
+    pub fn substitute(&self, map: &HashMap<T, Expr<T>>) -> Expr<T> {
+        match self {
+            Expr::Const(b) => Expr::Const(*b),
+            Expr::Terminal(v) => match map.get(v) {
+                Some(e) => e.clone(),
+                None => Expr::Terminal(v.clone()),
+            },
+            Expr::Not { arg } => Expr::not(arg.substitute(map)),
+            Expr::And { args } => Expr::and(args.iter().map(|arg| arg.substitute(map))),
+            Expr::Or { args } => Expr::or(args.iter().map(|arg| arg.substitute(map))),
+        }
+    }
+}
+
 impl Expr<Var> {
     pub fn parse_flat(input: &str) -> eyre::Result<Self> {
         let parsed_expr = expr_parser::flat::parser::parse_expr(input)?;
@@ -324,4 +378,44 @@ mod tests {
         let nnf = e.to_nnf();
         println!("nnf = {:?}", nnf);
     }
+
+    #[test]
+    fn test_substitute() {
+        use crate::formula::simplify::fold_constants;
+
+        let e = Var(1) & Var(2);
+
+        // x1 := true
+        let map = HashMap::from([(Var(1), Expr::Const(true))]);
+        let cofactor = e.substitute(&map);
+        assert_eq!(cofactor, Expr::Const(true) & Var(2));
+        assert_eq!(fold_constants(cofactor), Expr::Terminal(Var(2)));
+
+        // x1 := false
+        let map = HashMap::from([(Var(1), Expr::Const(false))]);
+        assert_eq!(fold_constants(e.substitute(&map)), Expr::Const(false));
+
+        // x2 := x3 | x4
+        let map = HashMap::from([(Var(2), Var(3) | Var(4))]);
+        assert_eq!(e.substitute(&map), Var(1) & (Var(3) | Var(4)));
+    }
+
+    #[test]
+    fn test_vars() {
+        for (s, vars) in [
+            ("(x1 | ~(x2 | ~~~x4) & x3) | T & false", vec![1, 2, 3, 4]),
+            ("x1 -> ~x2 | x3 -> x3 & x4", vec![1, 2, 3, 4]),
+            ("x1 | x2 & x3 | x4", vec![1, 2, 3, 4]),
+            ("x42 & ~x7 | x42", vec![7, 42]),
+        ] {
+            let e = Expr::parse_flat(s).unwrap();
+            let expected: BTreeSet<Var> = vars.into_iter().map(Var).collect();
+            assert_eq!(e.vars(), expected);
+            assert_eq!(e.max_var(), expected.last().copied());
+        }
+
+        let e: Expr<Var> = Expr::Const(true);
+        assert!(e.vars().is_empty());
+        assert_eq!(e.max_var(), None);
+    }
 }
@@ -0,0 +1,83 @@
+use crate::cnf::Cnf;
+use crate::formula::expr::Expr;
+use crate::formula::var::Var;
+use crate::lit::Lit;
+
+/// Lifts the [`Cnf`] into a flat [`Expr`]: a conjunction of disjunctions of literals.
+///
+/// The empty clause is lifted to `false`, and the empty CNF is lifted to `true`.
+pub fn from_cnf(cnf: &Cnf) -> Expr<Var> {
+    if cnf.clauses.is_empty() {
+        return Expr::Const(true);
+    }
+    let args = cnf
+        .iter()
+        .map(|clause| {
+            if clause.lits.is_empty() {
+                Expr::Const(false)
+            } else {
+                Expr::Or {
+                    args: clause.iter().map(|&lit| lit_to_expr(lit)).collect(),
+                }
+            }
+        })
+        .collect();
+    Expr::And { args }
+}
+
+fn lit_to_expr(lit: Lit) -> Expr<Var> {
+    let var = Expr::Terminal(Var(lit.var()));
+    if lit.get() < 0 {
+        Expr::not(var)
+    } else {
+        var
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use quickcheck_macros::quickcheck;
+
+    use crate::cnf::clause::Clause;
+
+    use super::*;
+
+    fn eval_cnf(cnf: &Cnf, mapping: &HashMap<Var, bool>) -> bool {
+        cnf.iter().all(|clause| {
+            clause.iter().any(|lit| {
+                let value = mapping[&Var(lit.var())];
+                if lit.get() < 0 {
+                    !value
+                } else {
+                    value
+                }
+            })
+        })
+    }
+
+    #[test]
+    fn test_from_cnf_trivial() {
+        let cnf = Cnf::new();
+        assert_eq!(from_cnf(&cnf), Expr::Const(true));
+
+        let mut cnf = Cnf::new();
+        cnf.clauses.push(Clause { lits: vec![] });
+        assert_eq!(
+            from_cnf(&cnf),
+            Expr::And {
+                args: vec![Expr::Const(false)]
+            }
+        );
+        assert!(!from_cnf(&cnf).eval(&HashMap::new()));
+    }
+
+    #[quickcheck]
+    fn test_from_cnf_equivalence(bits: u8) -> bool {
+        let cnf = Cnf::from_iter([vec![1, -2, 3], vec![-1, 2], vec![2, -3, 4], vec![-4], vec![1, 3, 4]]);
+        let expr = from_cnf(&cnf);
+        let mapping: HashMap<Var, bool> = (1..=cnf.max_var as u32).map(|v| (Var(v), bits & (1 << v) != 0)).collect();
+        expr.eval(&mapping) == eval_cnf(&cnf, &mapping)
+    }
+}
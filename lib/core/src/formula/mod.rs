@@ -1,6 +1,9 @@
 pub mod expr;
+pub mod lift;
 pub mod nnf;
 pub mod simplify;
 pub mod var;
 
 pub mod constraint;
+
+pub use self::lift::from_cnf;
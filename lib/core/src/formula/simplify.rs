@@ -87,6 +87,65 @@ pub fn simplify<T>(mut expr: Expr<T>) -> Expr<T> {
     expr
 }
 
+/// Folds the constants in the expression bottom-up, using the identities
+/// `x & true = x`, `x & false = false`, `x | false = x`, `x | true = true`, `~true = false`,
+/// and `x & ~x = false`, `x | ~x = true`.
+/// Nested same-operator nodes are flattened along the way.
+pub fn fold_constants<T>(expr: Expr<T>) -> Expr<T>
+where
+    T: PartialEq,
+{
+    match expr {
+        Expr::Const(_) | Expr::Terminal(_) => expr,
+        Expr::Not { arg } => match fold_constants(*arg) {
+            Expr::Const(b) => Expr::Const(!b),
+            arg => Expr::not(arg),
+        },
+        Expr::And { args } => fold_junction(args, true),
+        Expr::Or { args } => fold_junction(args, false),
+    }
+}
+
+// Folds AND (when `neutral` is true) or OR (when `neutral` is false).
+// The neutral element is dropped, and the absorbing element (`!neutral`) absorbs everything.
+fn fold_junction<T>(args: Vec<Expr<T>>, neutral: bool) -> Expr<T>
+where
+    T: PartialEq,
+{
+    let mut new_args = Vec::new();
+    for arg in args {
+        let arg = fold_constants(arg);
+        let sub_args = match arg {
+            Expr::And { args } if neutral => args,
+            Expr::Or { args } if !neutral => args,
+            arg => vec![arg],
+        };
+        for sub_arg in sub_args {
+            match sub_arg {
+                Expr::Const(b) if b == neutral => {}
+                Expr::Const(_) => return Expr::Const(!neutral),
+                _ => new_args.push(sub_arg),
+            }
+        }
+    }
+
+    // Complementary pair: x & ~x |- false, x | ~x |- true
+    let has_complement = new_args.iter().any(|arg| match arg {
+        Expr::Not { arg: inner } => new_args.contains(inner),
+        _ => false,
+    });
+    if has_complement {
+        return Expr::Const(!neutral);
+    }
+
+    match new_args.len() {
+        0 => Expr::Const(neutral),
+        1 => new_args.into_iter().next().unwrap(),
+        _ if neutral => Expr::And { args: new_args },
+        _ => Expr::Or { args: new_args },
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -216,4 +275,80 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_fold_constants() {
+        // (x1 & true) | false |- x1
+        let e: Expr<i32> = Expr::Or {
+            args: vec![
+                Expr::And {
+                    args: vec![Expr::Terminal(1), Expr::Const(true)],
+                },
+                Expr::Const(false),
+            ],
+        };
+        assert_eq!(fold_constants(e), Expr::Terminal(1));
+
+        // ~true | (x1 & (x2 & x3)) |- x1 & x2 & x3
+        let e: Expr<i32> = Expr::Or {
+            args: vec![
+                Expr::Not {
+                    arg: Box::new(Expr::Const(true)),
+                },
+                Expr::And {
+                    args: vec![
+                        Expr::Terminal(1),
+                        Expr::And {
+                            args: vec![Expr::Terminal(2), Expr::Terminal(3)],
+                        },
+                    ],
+                },
+            ],
+        };
+        assert_eq!(
+            fold_constants(e),
+            Expr::And {
+                args: vec![Expr::Terminal(1), Expr::Terminal(2), Expr::Terminal(3)]
+            }
+        );
+
+        // x2 | ~false |- true
+        let e: Expr<i32> = Expr::Or {
+            args: vec![
+                Expr::Terminal(2),
+                Expr::Not {
+                    arg: Box::new(Expr::Const(false)),
+                },
+            ],
+        };
+        assert_eq!(fold_constants(e), Expr::Const(true));
+    }
+
+    #[test]
+    fn test_fold_contradiction() {
+        // x1 & x2 & ~x1 |- false
+        let e: Expr<i32> = Expr::And {
+            args: vec![
+                Expr::Terminal(1),
+                Expr::Terminal(2),
+                Expr::Not {
+                    arg: Box::new(Expr::Terminal(1)),
+                },
+            ],
+        };
+        assert_eq!(fold_constants(e), Expr::Const(false));
+
+        // (x1 | false) & (x2 & false) |- false
+        let e: Expr<i32> = Expr::And {
+            args: vec![
+                Expr::Or {
+                    args: vec![Expr::Terminal(1), Expr::Const(false)],
+                },
+                Expr::And {
+                    args: vec![Expr::Terminal(2), Expr::Const(false)],
+                },
+            ],
+        };
+        assert_eq!(fold_constants(e), Expr::Const(false));
+    }
 }
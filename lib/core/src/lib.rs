@@ -1,7 +1,9 @@
 pub mod card;
 pub mod cnf;
 pub mod context;
+pub mod count;
 pub mod domainvar;
+pub mod drat;
 pub mod encoder;
 pub mod eval;
 pub mod formula;
@@ -26,6 +26,34 @@ impl Lit {
     }
 }
 
+impl Lit {
+    /// Compact unsigned representation of the literal: the variable in the high bits,
+    /// and the sign in the lowest bit (`1` for negative literals).
+    ///
+    /// Packed literals of the same variable are adjacent, e.g. `1 -> 2`, `-1 -> 3`, `2 -> 4`,
+    /// so they can be used as indices into arrays over literals.
+    pub const fn pack(self) -> u32 {
+        debug_assert!(self.0 != 0, "cannot pack zero literal");
+        debug_assert!(self.0 != i32::MIN, "cannot pack literal i32::MIN");
+        (self.var() << 1) | (self.0 < 0) as u32
+    }
+
+    /// Inverse of [`pack`][Lit::pack].
+    pub const fn unpack(packed: u32) -> Self {
+        let var = (packed >> 1) as i32;
+        if packed & 1 == 1 {
+            Lit::new(-var)
+        } else {
+            Lit::new(var)
+        }
+    }
+
+    /// Iterator over the literals unpacked from the `packed` slice.
+    pub fn unpack_slice(packed: &[u32]) -> impl Iterator<Item = Lit> + '_ {
+        packed.iter().map(|&p| Lit::unpack(p))
+    }
+}
+
 impl Display for Lit {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.get())
@@ -77,6 +105,7 @@ mod tests {
     fn test_lit_display() {
         let lit = Lit::new(42);
         assert_eq!(format!("{}", lit), "42");
+        assert_eq!(Lit::from(-3).to_string(), "-3");
     }
 
     #[test]
@@ -98,6 +127,29 @@ mod tests {
         assert_eq!(value, 42);
     }
 
+    #[test]
+    fn test_lit_pack() {
+        for val in (-1000..=1000).filter(|&x| x != 0) {
+            let lit = Lit::new(val);
+            let packed = lit.pack();
+            assert_eq!(packed >> 1, lit.var());
+            assert_eq!(packed & 1 == 1, val < 0);
+            assert_eq!(Lit::unpack(packed), lit);
+        }
+        assert_eq!(Lit::new(1).pack(), 2);
+        assert_eq!(Lit::new(-1).pack(), 3);
+
+        // Max variable:
+        for lit in [Lit::new(i32::MAX), Lit::new(-i32::MAX)] {
+            assert_eq!(Lit::unpack(lit.pack()), lit);
+        }
+        assert_eq!(Lit::new(-i32::MAX).pack(), u32::MAX);
+
+        let lits = [Lit::new(3), Lit::new(-5), Lit::new(i32::MAX)];
+        let packed: Vec<u32> = lits.iter().map(|lit| lit.pack()).collect();
+        assert!(Lit::unpack_slice(&packed).eq(lits));
+    }
+
     #[test]
     fn test_lit_neg() {
         let lit = Lit::new(42);
@@ -1,7 +1,7 @@
 use itertools::Itertools;
 
 use crate::lit::Lit;
-use crate::solver::{SolveResponse, Solver};
+use crate::solver::Solver;
 
 impl<S> AllSat for S where S: Solver {}
 
@@ -71,7 +71,7 @@ where
             self.solver.add_clause(refutation);
         }
 
-        if matches!(self.solver.solve(), SolveResponse::Sat) {
+        if self.solver.solve().is_sat() {
             // Build the refutation
             self.refutation = Some(self.solver.build_refutation(&self.essential));
 
@@ -1,4 +1,5 @@
 pub mod allsat;
 pub mod encodings;
 pub mod ops;
+pub mod optimize;
 pub mod rel;
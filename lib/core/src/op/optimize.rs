@@ -0,0 +1,58 @@
+use itertools::Itertools;
+
+use crate::card::Cardinality;
+use crate::lit::Lit;
+use crate::solver::{SolveResponse, Solver};
+
+impl<S> Optimize for S where S: Solver {}
+
+pub trait Optimize: Solver + Sized {
+    /// Find the minimum number of true literals among `objective`.
+    ///
+    /// The sum is encoded via a totalizer, and the bound is tightened
+    /// using assumptions, so the formula itself is not constrained afterwards.
+    /// Note that the totalizer clauses (over fresh auxiliary variables) remain in the solver.
+    /// Objective variables unknown to the solver are allocated before the first `solve`.
+    ///
+    /// Returns the optimal cost together with the witnessing model
+    /// (signed literals over the variables present before the encoding),
+    /// or `None` if the formula is unsatisfiable or the first `solve` is interrupted.
+    /// If some later `solve` is interrupted, the best found solution is returned.
+    fn minimize_sum(&mut self, objective: &[Lit]) -> Option<(u64, Vec<Lit>)> {
+        let max_var = objective.iter().map(|lit| lit.var()).max().unwrap_or(0);
+        self.reserve_vars(max_var as usize);
+        if self.solve() != SolveResponse::Sat {
+            return None;
+        }
+
+        let num_vars = self.num_vars();
+        let get_model = |solver: &Self| -> Vec<Lit> {
+            (1..=num_vars as i32)
+                .map(|v| if solver.value(v).bool() { Lit::new(v) } else { Lit::new(-v) })
+                .collect_vec()
+        };
+        let get_cost = |model: &[Lit]| -> u64 { objective.iter().filter(|&&lit| model[lit.var() as usize - 1] == lit).count() as u64 };
+
+        let mut model = get_model(self);
+        let mut cost = get_cost(&model);
+        if cost == 0 {
+            return Some((cost, model));
+        }
+
+        let totalizer = self.declare_totalizer(objective);
+        while cost > 0 {
+            // Assume that at most `cost - 1` objective literals are true.
+            let bound = totalizer.output_vars[cost as usize - 1];
+            self.assume(-bound);
+            if self.solve() != SolveResponse::Sat {
+                break;
+            }
+            model = get_model(self);
+            let new_cost = get_cost(&model);
+            assert!(new_cost < cost);
+            cost = new_cost;
+        }
+
+        Some((cost, model))
+    }
+}
@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use log::warn;
+
 use crate::lit::Lit;
 
 use super::types::*;
@@ -11,12 +13,39 @@ pub trait Solver: Sized {
     /// Return the signature of the solver as a `Cow<str>`.
     fn signature(&self) -> Cow<str>;
 
-    /// Reset the solver to its initial state.
-    fn reset(&mut self);
+    /// Reset the solver to its initial state, so that it can be reused for an unrelated problem.
+    ///
+    /// By default, does nothing (with a warning), since not every solver supports it.
+    fn reset(&mut self) {
+        warn!("Solver '{}' does not support reset", self.signature());
+    }
 
     /// Release any resources held by the solver.
     fn release(&mut self);
 
+    /// Make the solver deterministic, so that repeated runs on the same input
+    /// (with the same `seed`) produce identical results, including the models.
+    ///
+    /// Implementations fix the seed of the solver's internal random generator.
+    /// This is sufficient as long as the search does not depend on timing (besides
+    /// the explicit time limits), which holds for all the wrapped backends.
+    ///
+    /// By default, does nothing (with a warning), since not every solver supports it.
+    fn set_deterministic(&mut self, seed: u64) {
+        warn!(
+            "Solver '{}' does not support deterministic mode (seed = {})",
+            self.signature(),
+            seed
+        );
+    }
+
+    /// Return the features supported by the solver.
+    ///
+    /// By default, reports no features beyond the plain (one-shot) solving.
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::NONE
+    }
+
     /// Return the number of variables in the solver.
     fn num_vars(&self) -> usize;
 
@@ -26,12 +55,33 @@ pub trait Solver: Sized {
     /// Create a new variable in the solver and return its literal representation.
     fn new_var(&mut self) -> Lit;
 
+    /// Make sure the solver has at least `num_vars` variables.
+    ///
+    /// Backends that support it allocate the variables at once, which avoids repeated
+    /// reallocations when adding a large formula. By default, calls [`new_var`][Solver::new_var] repeatedly.
+    fn reserve_vars(&mut self, num_vars: usize) {
+        while self.num_vars() < num_vars {
+            self.new_var();
+        }
+    }
+
     /// Add an assumption to the solver.
     /// The assumption is represented by the given literal.
     fn assume<L>(&mut self, lit: L)
     where
         L: Into<Lit>;
 
+    /// Add all the given literals as assumptions.
+    fn assume_all<I>(&mut self, lits: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<Lit>,
+    {
+        for lit in lits.into_iter() {
+            self.assume(lit);
+        }
+    }
+
     /// Add a clause to the solver.
     /// The clause is represented by an iterator of literals.
     fn add_clause<I>(&mut self, lits: I)
@@ -63,6 +113,12 @@ pub trait Solver: Sized {
     fn value<L>(&self, lit: L) -> LitValue
     where
         L: Into<Lit>;
+
+    /// Return `true` if the solver is a [`DelegateSolver`][crate::solver::delegate::DelegateSolver],
+    /// possibly behind other wrappers.
+    fn is_delegate(&self) -> bool {
+        false
+    }
 }
 
 impl<S> crate::op::ops::AddClause for S
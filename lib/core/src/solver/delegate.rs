@@ -1,12 +1,16 @@
 use std::borrow::Cow;
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 
 use itertools::Itertools;
+use log::{trace, warn};
 
+use crate::cnf::clause::Clause;
+use crate::cnf::Cnf;
 use crate::lit::Lit;
 use crate::solver::simple::SimpleSolver;
 use crate::solver::wrap::WrapSolver;
-use crate::solver::{LitValue, SolveResponse, Solver};
+use crate::solver::{Capabilities, LitValue, SolveResponse, Solver};
 
 #[derive(Debug)]
 pub struct DelegateSolver {
@@ -15,16 +19,24 @@ pub struct DelegateSolver {
 
 impl DelegateSolver {
     pub fn new(inner: impl SimpleSolver + 'static) -> Self {
-        Self { inner: Box::new(inner) }
+        Self::from(Box::new(inner) as Box<dyn SimpleSolver>)
     }
 
     pub fn wrap(inner: impl Solver + 'static) -> Self {
         Self::new(WrapSolver::new(inner))
     }
+
+    /// Peel one layer of delegation, returning the inner solver.
+    pub fn unwrap_delegate(self) -> Box<dyn SimpleSolver> {
+        self.inner
+    }
 }
 
 impl From<Box<dyn SimpleSolver>> for DelegateSolver {
     fn from(inner: Box<dyn SimpleSolver>) -> Self {
+        if inner.is_delegate() {
+            warn!("Wrapping the delegate solver '{}' into another delegate", inner.signature());
+        }
         DelegateSolver { inner }
     }
 }
@@ -48,6 +60,14 @@ impl Solver for DelegateSolver {
         self.inner.release()
     }
 
+    fn set_deterministic(&mut self, seed: u64) {
+        self.inner.set_deterministic(seed)
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.inner.capabilities()
+    }
+
     fn num_vars(&self) -> usize {
         self.inner.num_vars()
     }
@@ -60,6 +80,10 @@ impl Solver for DelegateSolver {
         self.inner.new_var()
     }
 
+    fn reserve_vars(&mut self, num_vars: usize) {
+        self.inner.reserve_vars(num_vars)
+    }
+
     fn assume<L>(&mut self, lit: L)
     where
         L: Into<Lit>,
@@ -93,4 +117,616 @@ impl Solver for DelegateSolver {
     {
         self.inner.value(lit.into())
     }
+
+    fn is_delegate(&self) -> bool {
+        true
+    }
+}
+
+/// Transparent wrapper logging (at `trace` level) all calls modifying the inner solver,
+/// e.g. for reproducing the issues from user programs.
+#[derive(Debug)]
+pub struct LoggingSolver<S> {
+    inner: S,
+    /// Logged calls, kept in memory if enabled by [`keep_calls`][LoggingSolver::keep_calls].
+    calls: Option<Vec<String>>,
+    /// Added clauses, kept in memory if enabled by [`dump_dimacs`][LoggingSolver::dump_dimacs].
+    clauses: Option<Vec<Vec<Lit>>>,
+}
+
+impl<S> LoggingSolver<S>
+where
+    S: Solver,
+{
+    pub const fn new(inner: S) -> Self {
+        Self {
+            inner,
+            calls: None,
+            clauses: None,
+        }
+    }
+
+    /// Additionally keep the logged calls in memory, see [`calls`][LoggingSolver::calls].
+    pub fn keep_calls(mut self) -> Self {
+        self.calls = Some(Vec::new());
+        self
+    }
+
+    /// Additionally log the full formula in DIMACS format on each `solve`.
+    pub fn dump_dimacs(mut self) -> Self {
+        self.clauses = Some(Vec::new());
+        self
+    }
+
+    /// Logged calls, or an empty slice if they are not [kept][LoggingSolver::keep_calls].
+    pub fn calls(&self) -> &[String] {
+        self.calls.as_deref().unwrap_or_default()
+    }
+
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    fn log(&mut self, call: String) {
+        trace!("{}", call);
+        if let Some(calls) = &mut self.calls {
+            calls.push(call);
+        }
+    }
+}
+
+impl<S> Display for LoggingSolver<S>
+where
+    S: Solver + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", tynm::type_name::<Self>(), self.inner)
+    }
+}
+
+impl<S> Solver for LoggingSolver<S>
+where
+    S: Solver,
+{
+    fn signature(&self) -> Cow<str> {
+        self.inner.signature()
+    }
+
+    fn reset(&mut self) {
+        self.log("reset()".to_string());
+        if let Some(clauses) = &mut self.clauses {
+            clauses.clear();
+        }
+        self.inner.reset()
+    }
+
+    fn release(&mut self) {
+        self.log("release()".to_string());
+        self.inner.release()
+    }
+
+    fn set_deterministic(&mut self, seed: u64) {
+        self.log(format!("set_deterministic({})", seed));
+        self.inner.set_deterministic(seed)
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.inner.capabilities()
+    }
+
+    fn num_vars(&self) -> usize {
+        self.inner.num_vars()
+    }
+
+    fn num_clauses(&self) -> usize {
+        self.inner.num_clauses()
+    }
+
+    fn new_var(&mut self) -> Lit {
+        let lit = self.inner.new_var();
+        self.log(format!("new_var() -> {}", lit));
+        lit
+    }
+
+    fn reserve_vars(&mut self, num_vars: usize) {
+        self.log(format!("reserve_vars({})", num_vars));
+        self.inner.reserve_vars(num_vars)
+    }
+
+    fn assume<L>(&mut self, lit: L)
+    where
+        L: Into<Lit>,
+    {
+        let lit = lit.into();
+        self.log(format!("assume({})", lit));
+        self.inner.assume(lit)
+    }
+
+    fn add_clause<I>(&mut self, lits: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<Lit>,
+    {
+        let lits = lits.into_iter().map_into::<Lit>().collect_vec();
+        self.log(format!("add_clause([{}])", lits.iter().join(", ")));
+        self.inner.add_clause(&lits);
+        if let Some(clauses) = &mut self.clauses {
+            clauses.push(lits);
+        }
+    }
+
+    fn solve(&mut self) -> SolveResponse {
+        if let Some(clauses) = &self.clauses {
+            let max_var = clauses.iter().flatten().map(|lit| lit.var()).max().unwrap_or(0);
+            let mut dimacs = format!("p cnf {} {}\n", max_var, clauses.len());
+            for clause in clauses.iter() {
+                dimacs += &format!("{} 0\n", clause.iter().join(" "));
+            }
+            trace!("Solving the formula:\n{}", dimacs);
+        }
+        let response = self.inner.solve();
+        self.log(format!("solve() -> {:?}", response));
+        response
+    }
+
+    fn value<L>(&self, lit: L) -> LitValue
+    where
+        L: Into<Lit>,
+    {
+        self.inner.value(lit)
+    }
+
+    fn is_delegate(&self) -> bool {
+        self.inner.is_delegate()
+    }
+}
+
+/// Transparent wrapper recording all added clauses and assumptions,
+/// so the exact instance can be dumped (e.g. after hitting a bug) via [`recorded_cnf`][RecordingSolver::recorded_cnf].
+#[derive(Debug)]
+pub struct RecordingSolver<S> {
+    inner: S,
+    cnf: Cnf,
+    /// Assumptions for the next `solve`.
+    assumptions: Vec<Lit>,
+    /// Assumptions used in the last `solve`.
+    last_assumptions: Vec<Lit>,
+}
+
+impl<S> RecordingSolver<S>
+where
+    S: Solver,
+{
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            cnf: Cnf::new(),
+            assumptions: Vec::new(),
+            last_assumptions: Vec::new(),
+        }
+    }
+
+    /// All clauses added so far.
+    pub fn recorded_cnf(&self) -> &Cnf {
+        &self.cnf
+    }
+
+    /// Assumptions used in the last `solve`.
+    pub fn recorded_assumptions(&self) -> &[Lit] {
+        &self.last_assumptions
+    }
+
+    /// Recorded clauses in DIMACS format, with the empty clause written as a bare `0` line.
+    pub fn to_dimacs(&self) -> String {
+        let mut dimacs = format!("p cnf {} {}\n", self.cnf.max_var, self.cnf.clauses.len());
+        for clause in self.cnf.iter() {
+            for lit in clause.iter() {
+                dimacs += &format!("{} ", lit);
+            }
+            dimacs += "0\n";
+        }
+        dimacs
+    }
+
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    fn record_vars(&mut self) {
+        self.cnf.max_var = self.cnf.max_var.max(self.inner.num_vars());
+    }
+}
+
+impl<S> Display for RecordingSolver<S>
+where
+    S: Solver + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", tynm::type_name::<Self>(), self.inner)
+    }
+}
+
+impl<S> Solver for RecordingSolver<S>
+where
+    S: Solver,
+{
+    fn signature(&self) -> Cow<str> {
+        self.inner.signature()
+    }
+
+    fn reset(&mut self) {
+        self.cnf = Cnf::new();
+        self.assumptions.clear();
+        self.last_assumptions.clear();
+        self.inner.reset()
+    }
+
+    fn release(&mut self) {
+        self.inner.release()
+    }
+
+    fn set_deterministic(&mut self, seed: u64) {
+        self.inner.set_deterministic(seed)
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.inner.capabilities()
+    }
+
+    fn num_vars(&self) -> usize {
+        self.inner.num_vars()
+    }
+
+    fn num_clauses(&self) -> usize {
+        self.inner.num_clauses()
+    }
+
+    fn new_var(&mut self) -> Lit {
+        let lit = self.inner.new_var();
+        self.record_vars();
+        lit
+    }
+
+    fn reserve_vars(&mut self, num_vars: usize) {
+        self.inner.reserve_vars(num_vars);
+        self.record_vars();
+    }
+
+    fn assume<L>(&mut self, lit: L)
+    where
+        L: Into<Lit>,
+    {
+        let lit = lit.into();
+        self.assumptions.push(lit);
+        self.inner.assume(lit)
+    }
+
+    fn add_clause<I>(&mut self, lits: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<Lit>,
+    {
+        let lits = lits.into_iter().map_into::<Lit>().collect_vec();
+        self.inner.add_clause(&lits);
+        // Note: `Clause::new` rejects the empty clause, but it must be recorded as well,
+        //  since it makes the formula trivially UNSAT.
+        self.cnf.add(Clause { lits });
+    }
+
+    fn solve(&mut self) -> SolveResponse {
+        self.last_assumptions = std::mem::take(&mut self.assumptions);
+        self.inner.solve()
+    }
+
+    fn value<L>(&self, lit: L) -> LitValue
+    where
+        L: Into<Lit>,
+    {
+        self.inner.value(lit)
+    }
+
+    fn is_delegate(&self) -> bool {
+        self.inner.is_delegate()
+    }
+}
+
+/// Transparent wrapper caching the solve results (response and model) by the set of assumptions.
+///
+/// The clauses are assumed to be fixed between solves, so the cache is invalidated on every added clause.
+/// `Unknown` responses are not cached.
+/// Note that the model is copied out of the inner solver after each uncached SAT solve.
+#[derive(Debug)]
+pub struct CachingSolver<S> {
+    inner: S,
+    /// Assumptions for the next `solve`, passed to the inner solver only on a cache miss.
+    assumptions: Vec<Lit>,
+    /// Cached responses and models (values of all variables), keyed by the sorted assumptions.
+    cache: HashMap<Vec<Lit>, (SolveResponse, Vec<LitValue>)>,
+    /// Model of the last `solve`, if it was served from the cache.
+    cached_model: Option<Vec<LitValue>>,
+    hits: usize,
+    misses: usize,
+}
+
+impl<S> CachingSolver<S>
+where
+    S: Solver,
+{
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            assumptions: Vec::new(),
+            cache: HashMap::new(),
+            cached_model: None,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Number of solves served from the cache.
+    pub fn cache_hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Number of solves passed to the inner solver.
+    pub fn cache_misses(&self) -> usize {
+        self.misses
+    }
+
+    pub fn clear_cache(&mut self) {
+        self.cache.clear();
+        self.cached_model = None;
+    }
+
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S> Display for CachingSolver<S>
+where
+    S: Solver + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", tynm::type_name::<Self>(), self.inner)
+    }
+}
+
+impl<S> Solver for CachingSolver<S>
+where
+    S: Solver,
+{
+    fn signature(&self) -> Cow<str> {
+        self.inner.signature()
+    }
+
+    fn reset(&mut self) {
+        self.assumptions.clear();
+        self.clear_cache();
+        self.inner.reset()
+    }
+
+    fn release(&mut self) {
+        self.inner.release()
+    }
+
+    fn set_deterministic(&mut self, seed: u64) {
+        self.inner.set_deterministic(seed)
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.inner.capabilities()
+    }
+
+    fn num_vars(&self) -> usize {
+        self.inner.num_vars()
+    }
+
+    fn num_clauses(&self) -> usize {
+        self.inner.num_clauses()
+    }
+
+    fn new_var(&mut self) -> Lit {
+        self.inner.new_var()
+    }
+
+    fn reserve_vars(&mut self, num_vars: usize) {
+        self.inner.reserve_vars(num_vars)
+    }
+
+    fn assume<L>(&mut self, lit: L)
+    where
+        L: Into<Lit>,
+    {
+        self.assumptions.push(lit.into());
+    }
+
+    fn add_clause<I>(&mut self, lits: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<Lit>,
+    {
+        self.clear_cache();
+        self.inner.add_clause(lits)
+    }
+
+    fn solve(&mut self) -> SolveResponse {
+        let mut key = std::mem::take(&mut self.assumptions);
+        key.sort_unstable();
+        key.dedup();
+
+        if let Some((response, model)) = self.cache.get(&key) {
+            trace!("Cache hit for assumptions {:?}", key);
+            self.hits += 1;
+            self.cached_model = Some(model.clone());
+            return *response;
+        }
+
+        self.misses += 1;
+        self.cached_model = None;
+        for &lit in key.iter() {
+            self.inner.assume(lit);
+        }
+        let response = self.inner.solve();
+        match response {
+            SolveResponse::Sat => {
+                let model = (1..=self.inner.num_vars() as i32).map(|v| self.inner.value(v)).collect();
+                self.cache.insert(key, (response, model));
+            }
+            SolveResponse::Unsat => {
+                self.cache.insert(key, (response, Vec::new()));
+            }
+            SolveResponse::Unknown => {}
+        }
+        response
+    }
+
+    fn value<L>(&self, lit: L) -> LitValue
+    where
+        L: Into<Lit>,
+    {
+        let lit = lit.into();
+        match &self.cached_model {
+            Some(model) => match model.get(lit.var() as usize - 1) {
+                Some(LitValue::True) if lit.get() < 0 => LitValue::False,
+                Some(LitValue::False) if lit.get() < 0 => LitValue::True,
+                Some(&value) => value,
+                // Variable added after the cached solve is unconstrained:
+                None => LitValue::DontCare,
+            },
+            None => self.inner.value(lit),
+        }
+    }
+
+    fn is_delegate(&self) -> bool {
+        self.inner.is_delegate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solver::mock::MockSolver;
+
+    use super::*;
+
+    #[test]
+    fn test_is_delegate() {
+        assert!(!MockSolver::new().is_delegate());
+
+        let solver = DelegateSolver::wrap(MockSolver::new());
+        assert!(solver.is_delegate());
+        assert!(!solver.unwrap_delegate().is_delegate());
+
+        // Double wrapping (warns):
+        let mut solver = DelegateSolver::wrap(DelegateSolver::wrap(MockSolver::new()));
+        assert!(solver.is_delegate());
+        solver.add_clause([1, 2]);
+        assert_eq!(solver.num_clauses(), 1);
+        let inner = solver.unwrap_delegate();
+        assert!(inner.is_delegate());
+        assert_eq!(inner.num_clauses(), 1);
+    }
+
+    #[test]
+    fn test_logging_solver() {
+        let mut solver = LoggingSolver::new(MockSolver::new()).keep_calls().dump_dimacs();
+        let a = solver.new_var();
+        let b = solver.new_var();
+        solver.add_clause([a, b]);
+        solver.add_clause_(&[-a]);
+        solver.assume(b);
+        assert_eq!(solver.solve(), SolveResponse::Sat);
+        assert_eq!(
+            solver.calls(),
+            [
+                "new_var() -> 1",
+                "new_var() -> 2",
+                "add_clause([1, 2])",
+                "add_clause([-1])",
+                "assume(2)",
+                "solve() -> Sat"
+            ]
+        );
+
+        // Calls are forwarded to the inner solver:
+        let inner = solver.into_inner();
+        assert_eq!(inner.num_vars(), 2);
+        assert_eq!(inner.num_clauses(), 2);
+
+        // Calls are not kept by default:
+        let mut solver = LoggingSolver::new(MockSolver::new());
+        solver.add_clause([1, 2]);
+        assert!(solver.calls().is_empty());
+    }
+
+    #[test]
+    fn test_recording_solver() {
+        let mut solver = RecordingSolver::new(MockSolver::new());
+        let a = solver.new_var();
+        let b = solver.new_var();
+        let _c = solver.new_var();
+        solver.add_clause([a, b]);
+        solver.add_unit(-a);
+        solver.assume(b);
+        assert!(solver.recorded_assumptions().is_empty());
+        solver.solve();
+
+        let cnf = solver.recorded_cnf();
+        assert_eq!(cnf.max_var, 3);
+        assert_eq!(cnf.clauses, [Clause::from_iter([1, 2]), Clause::from_iter([-1])]);
+        assert_eq!(solver.recorded_assumptions(), [b]);
+        assert_eq!(solver.to_dimacs(), "p cnf 3 2\n1 2 0\n-1 0\n");
+
+        // The empty clause is recorded as well:
+        solver.add_clause(Vec::<Lit>::new());
+        assert_eq!(solver.recorded_cnf().clauses.len(), 3);
+        assert!(solver.recorded_cnf().clauses[2].lits.is_empty());
+        assert_eq!(solver.to_dimacs(), "p cnf 3 3\n1 2 0\n-1 0\n0\n");
+        assert_eq!(solver.into_inner().num_clauses(), 3);
+    }
+
+    #[test]
+    fn test_caching_solver() {
+        let mut solver = CachingSolver::new(MockSolver::new());
+        let a = solver.new_var();
+        let b = solver.new_var();
+        solver.add_clause([a, b]);
+
+        solver.assume(a);
+        solver.assume(-b);
+        assert_eq!(solver.solve(), SolveResponse::Sat);
+        assert_eq!((solver.cache_hits(), solver.cache_misses()), (0, 1));
+
+        // The order and duplicates of assumptions do not matter:
+        solver.assume(-b);
+        solver.assume(a);
+        solver.assume(a);
+        assert_eq!(solver.solve(), SolveResponse::Sat);
+        assert_eq!((solver.cache_hits(), solver.cache_misses()), (1, 1));
+        assert_eq!(solver.value(a), LitValue::True);
+        assert_eq!(solver.value(b), LitValue::False);
+
+        // Different assumptions are a miss, and the model follows them:
+        solver.assume(-a);
+        solver.assume(b);
+        assert_eq!(solver.solve(), SolveResponse::Sat);
+        assert_eq!((solver.cache_hits(), solver.cache_misses()), (1, 2));
+        assert_eq!(solver.value(a), LitValue::False);
+        assert_eq!(solver.value(b), LitValue::True);
+
+        // ...while the previous assumptions still hit their own entry:
+        solver.assume(a);
+        solver.assume(-b);
+        assert_eq!(solver.solve(), SolveResponse::Sat);
+        assert_eq!((solver.cache_hits(), solver.cache_misses()), (2, 2));
+        assert_eq!(solver.value(a), LitValue::True);
+        assert_eq!(solver.value(b), LitValue::False);
+
+        // New clause invalidates the cache:
+        solver.add_clause([-a, b]);
+        solver.assume(a);
+        solver.assume(-b);
+        solver.solve();
+        assert_eq!((solver.cache_hits(), solver.cache_misses()), (2, 3));
+    }
 }
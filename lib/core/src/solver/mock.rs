@@ -15,6 +15,9 @@ pub struct MockSolver {
     nvars: usize,
     nclauses: usize,
     clauses: Vec<Vec<Lit>>,
+    assumptions: Vec<Lit>,
+    /// Model of the last `solve`: assumed literals are true, all other variables are false.
+    model: Vec<bool>,
 }
 
 impl MockSolver {
@@ -24,6 +27,8 @@ impl MockSolver {
             nvars: 0,
             nclauses: 0,
             clauses: Vec::new(),
+            assumptions: Vec::new(),
+            model: Vec::new(),
         }
     }
 }
@@ -64,11 +69,11 @@ impl Solver for MockSolver {
         Lit::new(self.nvars as i32)
     }
 
-    fn assume<L>(&mut self, _lit: L)
+    fn assume<L>(&mut self, lit: L)
     where
         L: Into<Lit>,
     {
-        // TODO
+        self.assumptions.push(lit.into());
     }
 
     fn add_clause<I>(&mut self, lits: I)
@@ -82,16 +87,25 @@ impl Solver for MockSolver {
     }
 
     fn solve(&mut self) -> SolveResponse {
-        // TODO
+        // TODO: clauses are not checked, the model only satisfies the assumptions
+        self.model = vec![false; self.nvars];
+        for lit in self.assumptions.drain(..) {
+            self.model[lit.var() as usize - 1] = lit.get() > 0;
+        }
         SolveResponse::Sat
     }
 
-    fn value<L>(&self, _lit: L) -> LitValue
+    fn value<L>(&self, lit: L) -> LitValue
     where
         L: Into<Lit>,
     {
-        // TODO
-        LitValue::False
+        let lit = lit.into();
+        let value = self.model.get(lit.var() as usize - 1).copied().unwrap_or(false);
+        if value == (lit.get() > 0) {
+            LitValue::True
+        } else {
+            LitValue::False
+        }
     }
 }
 
@@ -118,6 +132,20 @@ mod tests {
         solver.add_unit(-c);
         assert_eq!(solver.nclauses, 6);
 
+        // The model follows the assumptions, other variables are false:
+        solver.assume(-a);
+        solver.assume(b);
+        assert_eq!(solver.solve(), SolveResponse::Sat);
+        assert_eq!(solver.value(a), LitValue::False);
+        assert_eq!(solver.value(-a), LitValue::True);
+        assert_eq!(solver.value(b), LitValue::True);
+        assert_eq!(solver.value(c), LitValue::False);
+        assert_eq!(solver.value(-c), LitValue::True);
+
+        // Assumptions are dropped after `solve`:
+        solver.solve();
+        assert_eq!(solver.value(b), LitValue::False);
+
         Ok(())
     }
 }
@@ -7,5 +7,6 @@ mod types;
 pub mod delegate;
 pub mod ext;
 pub mod mock;
+pub mod prelude;
 pub mod simple;
 pub mod wrap;
@@ -0,0 +1,28 @@
+//! Commonly used solver traits and types, importable with a single `use`:
+//!
+//! ```
+//! use sat_nexus_core::solver::prelude::*;
+//! # use sat_nexus_core::solver::mock::MockSolver;
+//!
+//! fn exactly_one_of_two<S: Solver>(solver: &mut S) -> SolveResponse {
+//!     let a = solver.new_var();
+//!     let b = solver.new_var();
+//!     solver.add_clause([a, b]);
+//!     solver.add_clause([-a, -b]);
+//!     solver.solve()
+//! }
+//!
+//! let mut solver = MockSolver::new();
+//! exactly_one_of_two(&mut solver);
+//! assert_eq!(solver.num_clauses(), 2);
+//! ```
+//!
+//! The actual backends (and `DispatchSolver`) live in the `sat-nexus-wrappers` crate,
+//! whose `prelude` re-exports this one.
+
+pub use crate::lit::Lit;
+pub use crate::solver::delegate::DelegateSolver;
+pub use crate::solver::ext::SolverExt;
+pub use crate::solver::simple::SimpleSolver;
+pub use crate::solver::wrap::WrapSolver;
+pub use crate::solver::{LitValue, SolveResponse, Solver};
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
 
 use easy_ext::ext;
+use log::warn;
 
 use crate::lit::Lit;
 
@@ -13,19 +14,49 @@ const _: Option<&dyn SimpleSolver> = None;
 pub trait SimpleSolver {
     fn signature(&self) -> Cow<str>;
 
-    fn reset(&mut self);
+    fn reset(&mut self) {
+        warn!("Solver '{}' does not support reset", self.signature());
+    }
     fn release(&mut self);
+    fn set_deterministic(&mut self, seed: u64) {
+        warn!(
+            "Solver '{}' does not support deterministic mode (seed = {})",
+            self.signature(),
+            seed
+        );
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::NONE
+    }
 
     fn num_vars(&self) -> usize;
     fn num_clauses(&self) -> usize;
 
     fn new_var(&mut self) -> Lit;
+    fn reserve_vars(&mut self, num_vars: usize) {
+        while self.num_vars() < num_vars {
+            self.new_var();
+        }
+    }
     fn assume(&mut self, lit: Lit);
     fn add_clause(&mut self, lits: &[Lit]);
     fn add_clause__(&mut self, lits: &mut dyn Iterator<Item = Lit>);
 
     fn solve(&mut self) -> SolveResponse;
     fn value(&self, lit: Lit) -> LitValue;
+
+    fn is_delegate(&self) -> bool {
+        false
+    }
+
+    /// Convert the solver into a trait object.
+    fn boxed(self) -> Box<dyn SimpleSolver>
+    where
+        Self: Sized + 'static,
+    {
+        Box::new(self)
+    }
 }
 
 impl Debug for dyn SimpleSolver {
@@ -7,6 +7,31 @@ pub enum SolveResponse {
     Unknown,
 }
 
+impl SolveResponse {
+    pub const fn is_sat(&self) -> bool {
+        matches!(self, SolveResponse::Sat)
+    }
+
+    pub const fn is_unsat(&self) -> bool {
+        matches!(self, SolveResponse::Unsat)
+    }
+
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self, SolveResponse::Unknown)
+    }
+
+    /// Single-character status, as used in the compact solver logs of SAT competitions:
+    /// `'S'` for SAT, `'U'` for UNSAT, and `'?'` for UNKNOWN.
+    pub const fn to_competition_char(&self) -> char {
+        use SolveResponse::*;
+        match self {
+            Sat => 'S',
+            Unsat => 'U',
+            Unknown => '?',
+        }
+    }
+}
+
 impl Display for SolveResponse {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         use SolveResponse::*;
@@ -18,6 +43,37 @@ impl Display for SolveResponse {
     }
 }
 
+/// Features supported by the solver, see [`Solver::capabilities`][crate::solver::Solver::capabilities].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub struct Capabilities {
+    /// Solving under assumptions via [`assume`][crate::solver::Solver::assume].
+    pub assumptions: bool,
+    /// Adding clauses and solving again after [`solve`][crate::solver::Solver::solve].
+    pub incremental: bool,
+    /// Tracing the proof of unsatisfiability.
+    pub proofs: bool,
+    /// Enumerating models, e.g. via [`AllSat`][crate::op::allsat::AllSat], which requires incremental solving.
+    pub model_enumeration: bool,
+}
+
+impl Capabilities {
+    /// No features beyond the plain (one-shot) solving.
+    pub const NONE: Capabilities = Capabilities {
+        assumptions: false,
+        incremental: false,
+        proofs: false,
+        model_enumeration: false,
+    };
+
+    /// Features of a typical incremental solver: assumptions, incremental solving and model enumeration.
+    pub const INCREMENTAL: Capabilities = Capabilities {
+        assumptions: true,
+        incremental: true,
+        proofs: false,
+        model_enumeration: true,
+    };
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum LitValue {
     True,
@@ -46,3 +102,19 @@ impl Display for LitValue {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_response_helpers() {
+        use SolveResponse::*;
+        assert!(Sat.is_sat() && !Sat.is_unsat() && !Sat.is_unknown());
+        assert!(!Unsat.is_sat() && Unsat.is_unsat() && !Unsat.is_unknown());
+        assert!(!Unknown.is_sat() && !Unknown.is_unsat() && Unknown.is_unknown());
+        assert_eq!(Sat.to_competition_char(), 'S');
+        assert_eq!(Unsat.to_competition_char(), 'U');
+        assert_eq!(Unknown.to_competition_char(), '?');
+    }
+}
@@ -3,7 +3,7 @@ use std::fmt::{Debug, Display, Formatter};
 
 use crate::lit::Lit;
 use crate::solver::simple::SimpleSolver;
-use crate::solver::{LitValue, SolveResponse, Solver};
+use crate::solver::{Capabilities, LitValue, SolveResponse, Solver};
 
 /// Implementation of [SimpleSolver] that wraps the [Solver] instance.
 #[derive(Debug)]
@@ -48,6 +48,14 @@ where
         self.inner.release()
     }
 
+    fn set_deterministic(&mut self, seed: u64) {
+        self.inner.set_deterministic(seed)
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.inner.capabilities()
+    }
+
     fn num_vars(&self) -> usize {
         self.inner.num_vars()
     }
@@ -60,6 +68,10 @@ where
         self.inner.new_var()
     }
 
+    fn reserve_vars(&mut self, num_vars: usize) {
+        self.inner.reserve_vars(num_vars)
+    }
+
     fn assume(&mut self, lit: Lit) {
         self.inner.assume(lit)
     }
@@ -79,6 +91,10 @@ where
     fn value(&self, lit: Lit) -> LitValue {
         self.inner.value(lit)
     }
+
+    fn is_delegate(&self) -> bool {
+        self.inner.is_delegate()
+    }
 }
 
 impl<S> From<S> for Box<dyn SimpleSolver>
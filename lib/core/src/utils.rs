@@ -1,14 +1,18 @@
+use std::io;
+use std::io::BufRead;
+
 use log::debug;
+use rand::Rng;
 
+use crate::cnf::clause::Clause;
 use crate::cnf::Cnf;
+use crate::lit::Lit;
 use crate::solver::Solver;
 
 pub fn bootstrap_solver_from_cnf(solver: &mut impl Solver, cnf: &Cnf) {
     if cnf.max_var > solver.num_vars() {
         debug!("Adding {} variables...", cnf.max_var - solver.num_vars());
-        for _ in solver.num_vars()..cnf.max_var {
-            solver.new_var();
-        }
+        solver.reserve_vars(cnf.max_var);
     }
 
     debug!("Adding {} clauses...", cnf.clauses.len());
@@ -17,6 +21,129 @@ pub fn bootstrap_solver_from_cnf(solver: &mut impl Solver, cnf: &Cnf) {
     }
 }
 
+/// Clause-to-variable ratio of the satisfiability threshold of random 3-SAT:
+/// instances below it are mostly satisfiable, above it mostly unsatisfiable,
+/// and the hardest ones are near it.
+pub const RANDOM_3SAT_THRESHOLD: f64 = 4.26;
+
+/// Uniform random 3-SAT instance with `num_vars` variables and `num_clauses` clauses,
+/// each consisting of three literals over distinct variables.
+pub fn random_3sat(num_vars: usize, num_clauses: usize, rng: &mut impl Rng) -> Cnf {
+    assert!(num_vars >= 3, "random 3-SAT requires at least 3 variables");
+    let mut cnf = Cnf::new();
+    for _ in 0..num_clauses {
+        let mut lits: Vec<Lit> = Vec::with_capacity(3);
+        while lits.len() < 3 {
+            let var = rng.gen_range(1..=num_vars as i32);
+            if lits.iter().all(|lit| lit.var() != var as u32) {
+                lits.push(Lit::new(if rng.gen_bool(0.5) { var } else { -var }));
+            }
+        }
+        cnf.add(Clause::new(lits));
+    }
+    cnf
+}
+
+/// Same as [`random_3sat`], with the number of clauses given by the clause-to-variable `ratio`
+/// (see [`RANDOM_3SAT_THRESHOLD`]).
+pub fn random_3sat_with_ratio(num_vars: usize, ratio: f64, rng: &mut impl Rng) -> Cnf {
+    let num_clauses = (ratio * num_vars as f64).round() as usize;
+    random_3sat(num_vars, num_clauses, rng)
+}
+
+/// Pigeonhole principle: `pigeons` pigeons in `holes` holes, each hole holding at most one pigeon.
+///
+/// Unsatisfiable when `pigeons > holes`, and notoriously hard for CDCL solvers as it grows.
+/// Variable `i * holes + j + 1` means "pigeon `i` sits in hole `j`".
+pub fn pigeonhole(pigeons: usize, holes: usize) -> Cnf {
+    let p = |i: usize, j: usize| (i * holes + j + 1) as i32;
+    let mut clauses = Vec::new();
+    for i in 0..pigeons {
+        clauses.push((0..holes).map(|j| p(i, j)).collect::<Vec<_>>());
+    }
+    for j in 0..holes {
+        for i1 in 0..pigeons {
+            for i2 in (i1 + 1)..pigeons {
+                clauses.push(vec![-p(i1, j), -p(i2, j)]);
+            }
+        }
+    }
+    Cnf::from_iter(clauses)
+}
+
+/// Parse the DIMACS CNF from `reader` and feed the clauses into the `solver` one by one,
+/// without building the whole [`Cnf`] in memory.
+///
+/// Variables are allocated in the solver on demand.
+/// Returns the maximum variable, which is the largest of the one declared in
+/// the `p cnf` header and the ones actually used in the clauses.
+pub fn feed_dimacs_stream<R, S>(reader: R, solver: &mut S) -> io::Result<usize>
+where
+    R: BufRead,
+    S: Solver,
+{
+    fn invalid_data(msg: String) -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, msg)
+    }
+
+    let mut max_var = 0;
+    let mut num_clauses = 0;
+    let mut clause: Vec<Lit> = Vec::new();
+
+    for line in reader.lines() {
+        let line = line?;
+        let line = line.trim();
+        if line.starts_with('c') || line.is_empty() {
+            continue;
+        }
+        if line.starts_with('%') {
+            // End-of-formula marker used in some benchmark sets
+            break;
+        }
+        if let Some(header) = line.strip_prefix('p') {
+            let mut tokens = header.split_whitespace();
+            if tokens.next() != Some("cnf") {
+                return Err(invalid_data(format!("Bad header: '{}'", line)));
+            }
+            let num_vars: usize = tokens
+                .next()
+                .and_then(|s| s.parse().ok())
+                .ok_or_else(|| invalid_data(format!("Bad header: '{}'", line)))?;
+            max_var = max_var.max(num_vars);
+            continue;
+        }
+        for token in line.split_whitespace() {
+            let lit: i32 = token.parse().map_err(|e| invalid_data(format!("Bad literal '{}': {}", token, e)))?;
+            if lit == 0 {
+                add_clause_with_vars(solver, &clause);
+                num_clauses += 1;
+                clause.clear();
+            } else {
+                max_var = max_var.max(lit.unsigned_abs() as usize);
+                clause.push(Lit::new(lit));
+            }
+        }
+    }
+
+    // The last clause may miss the terminating zero
+    if !clause.is_empty() {
+        add_clause_with_vars(solver, &clause);
+        num_clauses += 1;
+    }
+
+    debug!("Fed {} clauses over {} variables", num_clauses, max_var);
+    Ok(max_var)
+}
+
+fn add_clause_with_vars(solver: &mut impl Solver, clause: &[Lit]) {
+    if let Some(max) = clause.iter().map(|lit| lit.var() as usize).max() {
+        while solver.num_vars() < max {
+            solver.new_var();
+        }
+    }
+    solver.add_clause(clause);
+}
+
 pub trait TypeName {
     fn type_name(&self) -> String;
 }
@@ -26,3 +153,73 @@ impl<T: ?Sized> TypeName for T {
         tynm::type_name::<T>()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::solver::mock::MockSolver;
+
+    use super::*;
+
+    #[test]
+    fn test_feed_dimacs_stream() -> color_eyre::Result<()> {
+        let dimacs = "\
+c Example CNF
+p cnf 5 4
+1 -2 0
+2 3
+-4 0
+c comment in the middle
+-1 -3 0 3
+4 0
+";
+        let mut solver = MockSolver::new();
+        let max_var = feed_dimacs_stream(dimacs.as_bytes(), &mut solver)?;
+        assert_eq!(max_var, 5);
+        assert_eq!(solver.num_clauses(), 4);
+        assert_eq!(solver.num_vars(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bootstrap_solver_from_cnf() {
+        let cnf = Cnf::from_iter([vec![1, -2], vec![2, 5], vec![-3]]);
+        let mut solver = MockSolver::new();
+        solver.new_var();
+        bootstrap_solver_from_cnf(&mut solver, &cnf);
+        assert_eq!(solver.num_vars(), 5);
+        assert_eq!(solver.num_clauses(), 3);
+
+        // Reserving fewer variables than already allocated does nothing:
+        solver.reserve_vars(2);
+        assert_eq!(solver.num_vars(), 5);
+    }
+
+    #[test]
+    fn test_random_3sat() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let cnf = random_3sat(20, 85, &mut rng);
+        assert_eq!(cnf.clauses.len(), 85);
+        assert!(cnf.max_var <= 20);
+        for clause in cnf.iter() {
+            assert_eq!(clause.lits.len(), 3);
+            let vars = clause.lits.iter().map(|lit| lit.var()).collect::<HashSet<_>>();
+            assert_eq!(vars.len(), 3, "Clause {} has repeated variables", clause);
+            assert!(vars.iter().all(|&v| (1..=20).contains(&v)));
+        }
+
+        let cnf = random_3sat_with_ratio(50, RANDOM_3SAT_THRESHOLD, &mut rng);
+        assert_eq!(cnf.clauses.len(), 213);
+    }
+
+    #[test]
+    fn test_feed_dimacs_stream_bad_literal() {
+        let mut solver = MockSolver::new();
+        let res = feed_dimacs_stream("p cnf 2 1\n1 x2 0\n".as_bytes(), &mut solver);
+        assert_eq!(res.unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -71,6 +71,84 @@ impl Display for Expr {
     }
 }
 
+/// Displays an [`Expr`] with minimal parentheses, see [`Expr::display_minimal`].
+pub struct DisplayMinimal<'a>(&'a Expr);
+
+impl Display for DisplayMinimal<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt_with_precedence(f, 0)
+    }
+}
+
+impl Expr {
+    /// Returns a printable form of the expression that omits redundant parentheses,
+    /// based on the operator precedence (`~` > `&` > `|` > `->` > `<->`)
+    /// and the right-associativity of `->`.
+    pub fn display_minimal(&self) -> DisplayMinimal<'_> {
+        DisplayMinimal(self)
+    }
+
+    /// Precedence of the top-level operator. Atoms bind the tightest.
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::Const(_) | Expr::Var(_) | Expr::Negation { .. } => 5,
+            Expr::BinOp { op, .. } => op.precedence(),
+        }
+    }
+
+    /// Writes the expression, wrapping it in parentheses if it binds looser than `min_precedence`.
+    fn fmt_with_precedence(&self, f: &mut Formatter<'_>, min_precedence: u8) -> std::fmt::Result {
+        let braced = self.precedence() < min_precedence;
+        if braced {
+            write!(f, "(")?;
+        }
+        match self {
+            Expr::Const(b) => {
+                if *b {
+                    write!(f, "T")?;
+                } else {
+                    write!(f, "F")?;
+                }
+            }
+            Expr::Var(v) => {
+                write!(f, "x{v}")?;
+            }
+            Expr::Negation { arg } => {
+                write!(f, "~")?;
+                arg.fmt_with_precedence(f, 5)?;
+            }
+            Expr::BinOp { op, lhs, rhs } => {
+                let p = op.precedence();
+                // Operand on the associative side may have the same precedence
+                let (lhs_min, rhs_min) = if op.is_right_assoc() { (p + 1, p) } else { (p, p + 1) };
+                lhs.fmt_with_precedence(f, lhs_min)?;
+                write!(f, " {op} ")?;
+                rhs.fmt_with_precedence(f, rhs_min)?;
+            }
+        }
+        if braced {
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+impl BinOp {
+    /// Binding strength: `&` > `|` > `->` > `<->`.
+    pub const fn precedence(self) -> u8 {
+        match self {
+            BinOp::And => 4,
+            BinOp::Or => 3,
+            BinOp::Imply => 2,
+            BinOp::Iff => 1,
+        }
+    }
+
+    pub const fn is_right_assoc(self) -> bool {
+        matches!(self, BinOp::Imply)
+    }
+}
+
 impl Display for BinOp {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
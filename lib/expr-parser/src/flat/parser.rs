@@ -235,4 +235,32 @@ mod tests {
         assert!(expr.is_ok());
         assert_eq!(expr.unwrap().to_string(), "(1 <=> (~2 -> (3 & 4)))");
     }
+
+    #[test]
+    fn test_minimal_display_precedence() {
+        let expr = X1 & X2 | X3;
+        assert_eq!(format!("{}", expr), "((1 & 2) | 3)");
+        assert_eq!(format!("{:#}", expr), "((x1 and x2) or x3)");
+        assert_eq!(expr.display_minimal().to_string(), "x1 & x2 | x3");
+        assert_eq!(parse_expr(&expr.display_minimal().to_string()), Ok(expr.clone()));
+        assert_eq!(parse_expr("((x1 & x2) | x3)"), Ok(expr));
+    }
+
+    #[test]
+    fn test_minimal_display_roundtrip() {
+        for s in [
+            "x1 & (x2 | x3)",
+            "(x1 | x2) & ~(x3 & x1)",
+            "x1 -> x2 -> x3",
+            "(x1 -> x2) -> x3",
+            "x1 | x2 <=> x3 & T",
+            "x1 <=> (x2 <=> x3)",
+            "~~(x1 | F) -> x2 | x3 & x4",
+        ] {
+            let expr = parse_expr(s).unwrap();
+            let printed = expr.display_minimal().to_string();
+            assert_eq!(printed, s);
+            assert_eq!(parse_expr(&printed), Ok(expr));
+        }
+    }
 }
@@ -1,7 +1,9 @@
+use std::cell::Cell;
 use std::convert::TryInto;
 use std::fmt::{Debug, Display, Formatter};
 
 use itertools::Itertools;
+use log::warn;
 
 use crate::ffi::*;
 use crate::types::*;
@@ -9,6 +11,10 @@ use crate::types::*;
 pub struct Ipasir {
     ffi: &'static IpasirFFI,
     ptr: IpasirPtr,
+    /// Result of the last `solve`, reset on any modification (`add`, `assume`).
+    last_response: Cell<Option<SolveResponse>>,
+    /// Number of assumptions added since the last `solve`.
+    pending_assumptions: Cell<usize>,
 }
 
 // TODO: maybe make it public?
@@ -20,10 +26,44 @@ macro_rules! ipasir_instance {
     }};
 }
 
+/// Fallible initialization of the solver.
+pub trait IpasirFFIExt {
+    /// Initialize the solver, returning an error instead of a null pointer.
+    fn try_init(&self) -> Result<IpasirPtr>;
+}
+
+impl IpasirFFIExt for IpasirFFI {
+    fn try_init(&self) -> Result<IpasirPtr> {
+        check_init(self.init(), self.signature())
+    }
+}
+
+pub(crate) fn check_init(ptr: IpasirPtr, signature: &'static str) -> Result<IpasirPtr> {
+    if ptr.is_null() {
+        Err(IpasirError::InitFailed { signature })
+    } else {
+        Ok(ptr)
+    }
+}
+
 impl Ipasir {
+    /// Create a new solver.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the solver could not be initialized, see [`try_new`][Ipasir::try_new].
     pub fn new(ffi: &'static IpasirFFI) -> Self {
-        let ptr = ffi.init();
-        Self { ffi, ptr }
+        Self::try_new(ffi).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_new(ffi: &'static IpasirFFI) -> Result<Self> {
+        let ptr = ffi.try_init()?;
+        Ok(Self {
+            ffi,
+            ptr,
+            last_response: Cell::new(None),
+            pending_assumptions: Cell::new(0),
+        })
     }
 
     pub fn new_cadical() -> Self {
@@ -61,14 +101,92 @@ impl Display for Ipasir {
     }
 }
 
+/// Names of the solvers known to implement the IPASIR interface.
+pub const KNOWN_SOLVERS: &[&str] = &["cadical", "minisat", "glucose", "kissat", "lingeling", "picosat", "cryptominisat"];
+
+/// Parse the solver signature into the name and the version (if any).
+///
+/// The version starts at the first digit, e.g. `"cadical-1.9.5"` is parsed into
+/// `("cadical", Some("1.9.5"))`, and `"minisat220"` into `("minisat", Some("220"))`.
+pub fn parse_signature(signature: &str) -> (&str, Option<&str>) {
+    let signature = signature.trim();
+    match signature.find(|c: char| c.is_ascii_digit()) {
+        Some(i) => {
+            let name = signature[..i].trim_end_matches(['-', '_', ' ', 'v']);
+            (name, Some(&signature[i..]))
+        }
+        None => (signature, None),
+    }
+}
+
+// Backend information
+impl Ipasir {
+    /// Name of the loaded solver, parsed from its [signature][Ipasir::signature].
+    pub fn backend_name(&self) -> &'static str {
+        parse_signature(self.signature()).0
+    }
+
+    /// Version of the loaded solver, parsed from its [signature][Ipasir::signature].
+    pub fn backend_version(&self) -> Option<&'static str> {
+        parse_signature(self.signature()).1
+    }
+
+    /// Whether the loaded solver is one of the [`KNOWN_SOLVERS`].
+    pub fn is_known_backend(&self) -> bool {
+        let name = self.backend_name().to_ascii_lowercase();
+        KNOWN_SOLVERS.contains(&name.as_str())
+    }
+}
+
+impl Ipasir {
+    /// Ensure that the last `solve` returned `expected`, and the solver was not modified since then.
+    ///
+    /// IPASIR assumptions are cleared after each `solve`, and the model (or the failed assumptions)
+    /// is only available until the next `add`/`assume`, so querying it in any other state is a misuse.
+    fn ensure_state(&self, expected: SolveResponse, method: &str) -> Result<()> {
+        if self.last_response.get() != Some(expected) {
+            let message = match self.last_response.get() {
+                None if self.pending_assumptions.get() > 0 => format!(
+                    "`{}` requires the last `solve` to return {:?}, but `assume` was called after it without re-solving",
+                    method, expected
+                ),
+                None => format!(
+                    "`{}` requires the last `solve` to return {:?}, but the solver was modified since then",
+                    method, expected
+                ),
+                Some(actual) => format!(
+                    "`{}` requires the last `solve` to return {:?}, but it returned {:?}",
+                    method, expected, actual
+                ),
+            };
+            warn!("{}", message);
+            return Err(IpasirError::InvalidState { message });
+        }
+        Ok(())
+    }
+
+    /// Warn about the assumptions that were added but never used by `solve`.
+    fn check_pending_assumptions(&self) {
+        let pending = self.pending_assumptions.replace(0);
+        if pending > 0 {
+            warn!(
+                "{} assumption(s) were added after the last `solve` and are discarded without re-solving",
+                pending
+            );
+        }
+    }
+}
+
 // IPASIR interface
 impl Ipasir {
     pub fn reset(&mut self) {
         self.release();
-        self.ptr = self.ffi.init();
+        self.ptr = self.ffi.try_init().unwrap_or_else(|e| panic!("{}", e));
+        self.last_response.set(None);
     }
 
     pub fn release(&mut self) {
+        self.check_pending_assumptions();
         if !self.ptr.is_null() {
             unsafe { self.ffi.ipasir_release(self.ptr) }
             self.ptr = std::ptr::null_mut();
@@ -80,23 +198,30 @@ impl Ipasir {
     }
 
     pub fn add(&self, lit_or_zero: i32) {
+        self.last_response.set(None);
         unsafe { self.ffi.ipasir_add(self.ptr, lit_or_zero) }
     }
 
     pub fn assume(&self, lit: Lit) {
+        self.last_response.set(None);
+        self.pending_assumptions.set(self.pending_assumptions.get() + 1);
         unsafe { self.ffi.ipasir_assume(self.ptr, lit.into()) }
     }
 
     pub fn solve(&self) -> Result<SolveResponse> {
-        match unsafe { self.ffi.ipasir_solve(self.ptr) } {
-            0 => Ok(SolveResponse::Interrupted),
-            10 => Ok(SolveResponse::Sat),
-            20 => Ok(SolveResponse::Unsat),
-            invalid => Err(IpasirError::InvalidResponseSolve { value: invalid }),
-        }
+        let response = match unsafe { self.ffi.ipasir_solve(self.ptr) } {
+            0 => SolveResponse::Interrupted,
+            10 => SolveResponse::Sat,
+            20 => SolveResponse::Unsat,
+            invalid => return Err(IpasirError::InvalidResponseSolve { value: invalid }),
+        };
+        self.pending_assumptions.set(0);
+        self.last_response.set(Some(response));
+        Ok(response)
     }
 
     pub fn val(&self, lit: Lit) -> Result<LitValue> {
+        self.ensure_state(SolveResponse::Sat, "val")?;
         match unsafe { self.ffi.ipasir_val(self.ptr, lit.into()) } {
             0 => Ok(LitValue::DontCare),
             p if p == lit.get() => Ok(LitValue::True),
@@ -106,6 +231,7 @@ impl Ipasir {
     }
 
     pub fn failed(&self, lit: Lit) -> Result<bool> {
+        self.ensure_state(SolveResponse::Unsat, "failed")?;
         match unsafe { self.ffi.ipasir_failed(self.ptr, lit.into()) } {
             0 => Ok(false),
             1 => Ok(true),
@@ -30,3 +30,76 @@ fn test_ipasir_solver() -> color_eyre::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_lit_display() {
+    let lit = Lit::try_from(-3).unwrap();
+    assert_eq!(lit.to_string(), "-3");
+    assert_eq!(lit.var().to_string(), "3");
+}
+
+#[test]
+fn test_try_init() {
+    // `new` goes through `try_new`, which succeeds for a real backend:
+    let solver = Ipasir::new_cadical();
+    assert!(solver.signature().starts_with("cadical"));
+
+    // A null pointer from `init()` is reported as an error, not dereferenced later:
+    let err = crate::ipasir::check_init(std::ptr::null_mut(), "stub").unwrap_err();
+    assert!(matches!(err, IpasirError::InitFailed { signature: "stub" }));
+    assert_eq!(err.to_string(), "Could not initialize solver 'stub': `init()` returned null");
+}
+
+#[test]
+fn test_parse_signature() {
+    assert_eq!(parse_signature("cadical-1.9.5"), ("cadical", Some("1.9.5")));
+    assert_eq!(parse_signature("minisat220"), ("minisat", Some("220")));
+    assert_eq!(parse_signature("glucose 4.2.1"), ("glucose", Some("4.2.1")));
+    assert_eq!(parse_signature("picosat-v965"), ("picosat", Some("965")));
+    assert_eq!(parse_signature("lingeling-bbc"), ("lingeling-bbc", None));
+
+    let solver = Ipasir::new_cadical();
+    assert!(solver.backend_name().starts_with("cadical"));
+    assert!(solver.backend_version().is_some());
+    assert!(solver.is_known_backend());
+}
+
+#[test]
+fn test_state_tracking() -> color_eyre::Result<()> {
+    let solver = Ipasir::new_cadical();
+    let a: Lit = 1.try_into()?;
+    let b: Lit = 2.try_into()?;
+    solver.try_add_clause([1, 2])?;
+    solver.try_add_clause([-1, -2])?;
+
+    // Correct usage: query after `solve`.
+    assert_eq!(solver.solve()?, SolveResponse::Sat);
+    assert!(solver.val(a).is_ok());
+    assert!(solver.val(b).is_ok());
+    solver.assume(a);
+    solver.assume(b);
+    assert_eq!(solver.solve()?, SolveResponse::Unsat);
+    assert!(solver.failed(a).is_ok());
+
+    // `failed` after UNSAT, but `val` is not:
+    assert!(matches!(solver.val(a), Err(IpasirError::InvalidState { .. })));
+
+    // `assume` after `solve` without re-solving invalidates its result:
+    solver.assume(a);
+    match solver.failed(a) {
+        Err(IpasirError::InvalidState { message }) => assert!(message.contains("`assume` was called after it"), "{}", message),
+        res => panic!("Expected InvalidState, got {:?}", res),
+    }
+    assert_eq!(solver.solve()?, SolveResponse::Sat);
+    assert!(matches!(solver.failed(a), Err(IpasirError::InvalidState { .. })));
+
+    // `add` invalidates the model:
+    assert!(solver.val(a).is_ok());
+    solver.try_add_clause([1, -2])?;
+    match solver.val(a) {
+        Err(IpasirError::InvalidState { message }) => assert!(message.contains("modified"), "{}", message),
+        res => panic!("Expected InvalidState, got {:?}", res),
+    }
+
+    Ok(())
+}
@@ -15,4 +15,10 @@ pub enum IpasirError {
 
     #[snafu(display("Invalid response from `failed({})`: {}", lit, value))]
     InvalidResponseFailed { lit: Lit, value: i32 },
+
+    #[snafu(display("Invalid solver state: {}", message))]
+    InvalidState { message: String },
+
+    #[snafu(display("Could not initialize solver '{}': `init()` returned null", signature))]
+    InitFailed { signature: &'static str },
 }
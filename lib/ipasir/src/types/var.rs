@@ -1,3 +1,5 @@
+use std::fmt::{Display, Formatter};
+
 use super::Lit;
 
 /// A variable of the IPASIR implementing solver.
@@ -16,3 +18,9 @@ impl From<Lit> for Var {
         lit.var()
     }
 }
+
+impl Display for Var {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -1,3 +1,17 @@
+use snafu::Snafu;
+
+pub type Result<T, E = KissatError> = std::result::Result<T, E>;
+
+#[derive(Debug, Snafu)]
+#[snafu(visibility(pub(crate)))]
+pub enum KissatError {
+    #[snafu(display("Kissat was already solved, it does not support incremental solving: create a new instance instead"))]
+    AlreadySolved,
+
+    #[snafu(display("Invalid solver state: {}", message))]
+    InvalidState { message: String },
+}
+
 /// Possible responses from a call to `Kissat::solve`.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum SolveResponse {
@@ -1,6 +1,8 @@
+use std::cell::Cell;
 use std::fmt::{Debug, Display, Formatter};
 
 use ffi_utils::cstr2str;
+use snafu::ensure;
 
 pub use crate::common::*;
 
@@ -10,6 +12,8 @@ use super::ffi::*;
 pub struct Kissat {
     ffi: &'static KissatFFI,
     ptr: KissatPtr,
+    /// Response of `solve`, or `None` if it was not called yet. Kissat is not incremental.
+    last_response: Cell<Option<SolveResponse>>,
 }
 
 impl Kissat {
@@ -21,6 +25,7 @@ impl Kissat {
         Kissat {
             ffi,
             ptr: unsafe { ffi.kissat_init() },
+            last_response: Cell::new(None),
         }
     }
 }
@@ -63,34 +68,83 @@ impl Kissat {
     }
 
     pub fn add(&self, lit_or_zero: i32) {
+        assert_ne!(lit_or_zero, i32::MIN, "Invalid literal: {}", lit_or_zero);
         unsafe { self.ffi.kissat_add(self.ptr, lit_or_zero) }
     }
 
-    pub fn solve(&self) -> SolveResponse {
-        match unsafe { self.ffi.kissat_solve(self.ptr) } {
+    /// Solve the formula.
+    ///
+    /// Kissat is single-shot, so this method can only be called once per instance,
+    /// subsequent calls return [`KissatError::AlreadySolved`].
+    pub fn solve(&self) -> Result<SolveResponse> {
+        ensure!(self.last_response.get().is_none(), AlreadySolvedSnafu);
+        let response = match unsafe { self.ffi.kissat_solve(self.ptr) } {
             0 => SolveResponse::Interrupted,
             10 => SolveResponse::Sat,
             20 => SolveResponse::Unsat,
             invalid => panic!("Invalid response from 'kissat_solve': {}", invalid),
-        }
+        };
+        self.last_response.set(Some(response));
+        Ok(response)
     }
 
-    pub fn value(&self, lit: i32) -> LitValue {
-        debug_assert_ne!(lit, 0, "Literal must be non-zero");
+    /// Value of the literal in the model.
+    ///
+    /// Returns [`KissatError::InvalidState`] unless the last `solve` returned SAT.
+    pub fn value(&self, lit: i32) -> Result<LitValue> {
+        assert_ne!(lit, 0, "Literal must be non-zero");
+        assert_ne!(lit, i32::MIN, "Invalid literal: {}", lit);
+        ensure!(
+            self.last_response.get() == Some(SolveResponse::Sat),
+            InvalidStateSnafu {
+                message: "`value` requires the last `solve` to return SAT",
+            }
+        );
         match unsafe { self.ffi.kissat_value(self.ptr, lit) } {
-            0 => LitValue::Any,
-            p if p == lit => LitValue::True,
-            n if n == -lit => LitValue::False,
+            0 => Ok(LitValue::Any),
+            p if p == lit => Ok(LitValue::True),
+            n if n == -lit => Ok(LitValue::False),
             invalid => panic!("Invalid response from 'kissat_value(lit = {})': {}", lit, invalid),
         }
     }
 }
 
+/// Kissat additional API.
+impl Kissat {
+    pub fn reserve(&self, max_var: i32) {
+        unsafe { self.ffi.kissat_reserve(self.ptr, max_var) }
+    }
+
+    pub fn set_option(&self, name: &str, value: i32) -> i32 {
+        let name = std::ffi::CString::new(name).unwrap();
+        unsafe { self.ffi.kissat_set_option(self.ptr, name.as_ptr(), value) }
+    }
+
+    /// Limit the number of conflicts in the subsequent `solve`.
+    /// When the limit is reached, `solve` returns [`SolveResponse::Interrupted`].
+    ///
+    /// Note: Kissat limits are 32-bit, so larger values are saturated.
+    pub fn set_conflict_limit(&self, limit: u64) {
+        let limit = limit.min(u32::MAX as u64) as u32;
+        unsafe { self.ffi.kissat_set_conflict_limit(self.ptr, limit) }
+    }
+
+    /// Limit the number of decisions in the subsequent `solve`.
+    /// When the limit is reached, `solve` returns [`SolveResponse::Interrupted`].
+    ///
+    /// Note: Kissat limits are 32-bit, so larger values are saturated.
+    pub fn set_decision_limit(&self, limit: u64) {
+        let limit = limit.min(u32::MAX as u64) as u32;
+        unsafe { self.ffi.kissat_set_decision_limit(self.ptr, limit) }
+    }
+}
+
 /// Additional methods
 impl Kissat {
     pub fn reset(&mut self) {
         self.release();
         self.ptr = unsafe { self.ffi.kissat_init() };
+        self.last_response.set(None);
     }
 
     pub fn add_clause<I>(&self, lits: I)
@@ -99,7 +153,9 @@ impl Kissat {
         I::Item: Into<i32>,
     {
         for lit in lits.into_iter() {
-            self.add(lit.into());
+            let lit = lit.into();
+            assert_ne!(lit, 0, "Literal must be non-zero, clause is terminated automatically");
+            self.add(lit);
         }
         self.add(0);
     }
@@ -1,7 +1,7 @@
 use super::*;
 
 #[test]
-fn test_kissat_solver() {
+fn test_kissat_solver() -> color_eyre::Result<()> {
     let solver = Kissat::new();
     println!("solver = {}", solver);
     assert!(solver.signature().contains("kissat"));
@@ -14,14 +14,101 @@ fn test_kissat_solver() {
     solver.add_clause(vec![-3, -4]);
 
     // Problem is satisfiable
-    let response = solver.solve();
+    let response = solver.solve().unwrap();
     assert_eq!(response, SolveResponse::Sat);
 
-    let val1 = solver.value(1);
-    let val2 = solver.value(2);
-    let val3 = solver.value(3);
-    let val4 = solver.value(4);
+    let val1 = solver.value(1)?;
+    let val2 = solver.value(2)?;
+    let val3 = solver.value(3)?;
+    let val4 = solver.value(4)?;
     println!("values: {:?}", vec![val1, val2, val3, val4]);
     assert!(bool::from(val1) ^ bool::from(val2));
     assert!(bool::from(val3) ^ bool::from(val4));
+
+    Ok(())
+}
+
+#[test]
+fn test_already_solved() {
+    let solver = Kissat::new();
+    solver.add_clause([1, 2]);
+    solver.add_clause([-1]);
+
+    let response = solver.solve().unwrap();
+    assert_eq!(response, SolveResponse::Sat);
+    assert_eq!(solver.value(2).unwrap(), LitValue::True);
+
+    // Kissat is single-shot, second call must fail gracefully
+    let res = solver.solve();
+    assert!(matches!(res, Err(KissatError::AlreadySolved)));
+}
+
+/// Pigeonhole principle: `n+1` pigeons into `n` holes (UNSAT, hard for CDCL).
+fn add_pigeonhole(solver: &Kissat, n: i32) {
+    let var = |pigeon: i32, hole: i32| pigeon * n + hole + 1;
+    for p in 0..=n {
+        solver.add_clause((0..n).map(|h| var(p, h)));
+    }
+    for h in 0..n {
+        for p1 in 0..=n {
+            for p2 in (p1 + 1)..=n {
+                solver.add_clause([-var(p1, h), -var(p2, h)]);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_conflict_limit() {
+    let solver = Kissat::new();
+    add_pigeonhole(&solver, 10);
+    solver.set_conflict_limit(10);
+    let response = solver.solve().unwrap();
+    assert_eq!(response, SolveResponse::Interrupted);
+}
+
+#[test]
+fn test_decision_limit() {
+    let solver = Kissat::new();
+    add_pigeonhole(&solver, 10);
+    solver.set_decision_limit(10);
+    let response = solver.solve().unwrap();
+    assert_eq!(response, SolveResponse::Interrupted);
+}
+
+#[test]
+fn test_value_before_solve() {
+    let solver = Kissat::new();
+    solver.add_clause([1, 2]);
+    solver.add_clause([-1]);
+
+    // Model is not available before `solve`:
+    assert!(matches!(solver.value(1), Err(KissatError::InvalidState { .. })));
+}
+
+#[test]
+fn test_value_after_unsat() {
+    let solver = Kissat::new();
+    solver.add_clause([1]);
+    solver.add_clause([-1]);
+    assert_eq!(solver.solve().unwrap(), SolveResponse::Unsat);
+
+    // Model is not available after UNSAT:
+    assert!(matches!(solver.value(1), Err(KissatError::InvalidState { .. })));
+}
+
+#[test]
+#[should_panic(expected = "Literal must be non-zero")]
+fn test_zero_literal_value() {
+    let solver = Kissat::new();
+    solver.add_clause([1, 2]);
+    solver.solve().unwrap();
+    let _ = solver.value(0);
+}
+
+#[test]
+#[should_panic(expected = "Literal must be non-zero")]
+fn test_zero_literal_in_clause() {
+    let solver = Kissat::new();
+    solver.add_clause([1, 0, 2]);
 }
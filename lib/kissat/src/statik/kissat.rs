@@ -1,6 +1,8 @@
+use std::cell::Cell;
 use std::fmt::{Debug, Display, Formatter};
 
 use ffi_utils::cstr2str;
+use snafu::ensure;
 
 pub use crate::common::*;
 
@@ -9,12 +11,17 @@ use super::ffi::*;
 /// Kissat solver.
 pub struct Kissat {
     ptr: KissatPtr,
+    /// Response of `solve`, or `None` if it was not called yet. Kissat is not incremental.
+    last_response: Cell<Option<SolveResponse>>,
 }
 
 impl Kissat {
     pub fn new() -> Self {
         let ptr = unsafe { kissat_init() };
-        Self { ptr }
+        Self {
+            ptr,
+            last_response: Cell::new(None),
+        }
     }
 }
 
@@ -56,24 +63,42 @@ impl Kissat {
     }
 
     pub fn add(&self, lit_or_zero: i32) {
+        assert_ne!(lit_or_zero, i32::MIN, "Invalid literal: {}", lit_or_zero);
         unsafe { kissat_add(self.ptr, lit_or_zero) }
     }
 
-    pub fn solve(&self) -> SolveResponse {
-        match unsafe { kissat_solve(self.ptr) } {
+    /// Solve the formula.
+    ///
+    /// Kissat is single-shot, so this method can only be called once per instance,
+    /// subsequent calls return [`KissatError::AlreadySolved`].
+    pub fn solve(&self) -> Result<SolveResponse> {
+        ensure!(self.last_response.get().is_none(), AlreadySolvedSnafu);
+        let response = match unsafe { kissat_solve(self.ptr) } {
             0 => SolveResponse::Interrupted,
             10 => SolveResponse::Sat,
             20 => SolveResponse::Unsat,
             invalid => panic!("Invalid response from 'kissat_solve': {}", invalid),
-        }
-    }
-
-    pub fn value(&self, lit: i32) -> LitValue {
-        debug_assert_ne!(lit, 0, "Literal must be non-zero");
+        };
+        self.last_response.set(Some(response));
+        Ok(response)
+    }
+
+    /// Value of the literal in the model.
+    ///
+    /// Returns [`KissatError::InvalidState`] unless the last `solve` returned SAT.
+    pub fn value(&self, lit: i32) -> Result<LitValue> {
+        assert_ne!(lit, 0, "Literal must be non-zero");
+        assert_ne!(lit, i32::MIN, "Invalid literal: {}", lit);
+        ensure!(
+            self.last_response.get() == Some(SolveResponse::Sat),
+            InvalidStateSnafu {
+                message: "`value` requires the last `solve` to return SAT",
+            }
+        );
         match unsafe { kissat_value(self.ptr, lit) } {
-            0 => LitValue::Any,
-            p if p == lit => LitValue::True,
-            n if n == -lit => LitValue::False,
+            0 => Ok(LitValue::Any),
+            p if p == lit => Ok(LitValue::True),
+            n if n == -lit => Ok(LitValue::False),
             invalid => panic!("Invalid response from 'kissat_value(lit = {})': {}", lit, invalid),
         }
     }
@@ -111,11 +136,21 @@ impl Kissat {
         unsafe { kissat_set_configuration(self.ptr, name.as_ptr()) != 0 }
     }
 
-    pub fn set_conflict_limit(&self, limit: u32) {
+    /// Limit the number of conflicts in the subsequent `solve`.
+    /// When the limit is reached, `solve` returns [`SolveResponse::Interrupted`].
+    ///
+    /// Note: Kissat limits are 32-bit, so larger values are saturated.
+    pub fn set_conflict_limit(&self, limit: u64) {
+        let limit = limit.min(u32::MAX as u64) as u32;
         unsafe { kissat_set_conflict_limit(self.ptr, limit) }
     }
 
-    pub fn set_decision_limit(&self, limit: u32) {
+    /// Limit the number of decisions in the subsequent `solve`.
+    /// When the limit is reached, `solve` returns [`SolveResponse::Interrupted`].
+    ///
+    /// Note: Kissat limits are 32-bit, so larger values are saturated.
+    pub fn set_decision_limit(&self, limit: u64) {
+        let limit = limit.min(u32::MAX as u64) as u32;
         unsafe { kissat_set_decision_limit(self.ptr, limit) }
     }
 
@@ -144,6 +179,7 @@ impl Kissat {
     pub fn reset(&mut self) {
         self.release();
         self.ptr = unsafe { kissat_init() };
+        self.last_response.set(None);
     }
 
     pub fn add_clause<I>(&self, lits: I)
@@ -152,7 +188,9 @@ impl Kissat {
         I::Item: Into<i32>,
     {
         for lit in lits.into_iter() {
-            self.add(lit.into());
+            let lit = lit.into();
+            assert_ne!(lit, 0, "Literal must be non-zero, clause is terminated automatically");
+            self.add(lit);
         }
         self.add(0);
     }
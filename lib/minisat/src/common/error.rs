@@ -0,0 +1,10 @@
+use snafu::Snafu;
+
+pub type Result<T, E = MiniSatError> = std::result::Result<T, E>;
+
+#[derive(Debug, Snafu)]
+#[snafu(visibility(pub(crate)))]
+pub enum MiniSatError {
+    #[snafu(display("Invalid solver state: {}", message))]
+    InvalidState { message: String },
+}
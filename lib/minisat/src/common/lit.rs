@@ -37,6 +37,27 @@ impl Lit {
     pub const fn negate(self) -> Self {
         Self::new(self.0 ^ 1)
     }
+
+    /// Returns the DIMACS representation: 1-based variable index, negative if the literal is negated.
+    pub const fn to_external(self) -> i32 {
+        let x = self.var() as i32 + 1;
+        if self.sign() == 1 {
+            -x
+        } else {
+            x
+        }
+    }
+
+    /// Creates the literal from the DIMACS representation.
+    ///
+    /// Panics if `lit` is zero (clause terminator) or `i32::MIN`.
+    pub const fn from_external(lit: i32) -> Self {
+        assert!(lit != 0, "Literal must be non-zero");
+        assert!(lit != i32::MIN, "Invalid literal: i32::MIN");
+        let var = lit.unsigned_abs() - 1; // 0-based variable index
+        let sign = if lit > 0 { 0 } else { 1 };
+        Self::mk(var, sign)
+    }
 }
 
 impl From<Var> for Lit {
@@ -45,9 +66,10 @@ impl From<Var> for Lit {
     }
 }
 
+/// Displays the literal in DIMACS convention, see [`Lit::to_external`].
 impl Display for Lit {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.to_external())
     }
 }
 
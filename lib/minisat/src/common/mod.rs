@@ -1,7 +1,11 @@
+pub use self::error::*;
 pub use self::lbool::*;
 pub use self::lit::*;
+pub use self::response::*;
 pub use self::var::*;
 
+mod error;
 mod lbool;
 mod lit;
+mod response;
 mod var;
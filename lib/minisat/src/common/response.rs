@@ -0,0 +1,22 @@
+use std::fmt::{Display, Formatter};
+
+/// Possible responses from a call to `MiniSat::solve` and its variants.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SolveResponse {
+    /// The solver found the input to be satisfiable.
+    Sat,
+    /// The solver found the input to be unsatisfiable.
+    Unsat,
+    /// The solver was interrupted or ran out of budget.
+    Interrupted,
+}
+
+impl Display for SolveResponse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveResponse::Sat => write!(f, "SAT"),
+            SolveResponse::Unsat => write!(f, "UNSAT"),
+            SolveResponse::Interrupted => write!(f, "INDET"),
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::fmt::{Display, Formatter};
+
 /// MiniSat variable.
 ///
 /// **Note**: variables are 0-based integers, internally used as indices.
@@ -13,3 +15,10 @@ impl Var {
         self.0
     }
 }
+
+/// Displays the 1-based variable index, as in DIMACS.
+impl Display for Var {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0 + 1)
+    }
+}
@@ -1,3 +1,5 @@
+pub use crate::common::{MiniSatError, SolveResponse};
+
 pub use self::lbool::*;
 pub use self::lit::*;
 pub use self::minisat::*;
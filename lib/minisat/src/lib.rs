@@ -5,3 +5,6 @@ pub mod dynamic;
 pub mod statik;
 
 mod common;
+
+#[cfg(all(test, feature = "static", feature = "dynamic"))]
+mod parity_tests;
@@ -0,0 +1,138 @@
+//! Parity tests between the [`statik`][crate::statik] and [`dynamic`][crate::dynamic] wrappers.
+//!
+//! Both wrappers are driven by the same sequence of clauses and solve calls,
+//! and must produce the same responses and compatible models.
+
+use crate::common::{LBool, Lit};
+
+/// Clause over 0-based variables, as a list of `(var, negated)` pairs.
+type Clause = Vec<(u32, bool)>;
+
+/// Step of the scenario.
+#[derive(Debug, Clone)]
+enum Step {
+    AddClause(Clause),
+    Solve(Vec<(u32, bool)>),
+}
+
+/// Outcome of a single `Solve` step: the response and the model (if SAT).
+type Outcome = (bool, Option<Vec<LBool>>);
+
+macro_rules! run_scenario {
+    ($module:ident, $num_vars:expr, $steps:expr) => {{
+        use crate::$module::MiniSat;
+
+        let solver = MiniSat::new();
+        let lits: Vec<Lit> = (0..$num_vars).map(|_| solver.new_lit()).collect();
+        let to_lit = |&(v, neg): &(u32, bool)| if neg { -lits[v as usize] } else { lits[v as usize] };
+        let mut outcomes: Vec<Outcome> = Vec::new();
+        for step in $steps.iter() {
+            match step {
+                Step::AddClause(clause) => {
+                    solver.add_clause(clause.iter().map(to_lit));
+                }
+                Step::Solve(assumptions) => {
+                    let res = solver.solve_under_assumptions(assumptions.iter().map(to_lit));
+                    let model = if res { Some(solver.model().unwrap()) } else { None };
+                    outcomes.push((res, model));
+                }
+            }
+        }
+        outcomes
+    }};
+}
+
+/// Simple deterministic generator, so the tests do not depend on `rand`.
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self) -> u32 {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (self.0 >> 33) as u32
+    }
+}
+
+fn random_3sat(num_vars: u32, num_clauses: usize, seed: u64) -> Vec<Clause> {
+    let mut rng = Lcg(seed);
+    (0..num_clauses)
+        .map(|_| (0..3).map(|_| (rng.next() % num_vars, rng.next() % 2 == 1)).collect())
+        .collect()
+}
+
+fn is_satisfied(clause: &[(u32, bool)], model: &[LBool]) -> bool {
+    clause.iter().any(|&(v, neg)| match model[v as usize] {
+        LBool::True => !neg,
+        LBool::False => neg,
+        LBool::Undef => false,
+    })
+}
+
+fn check_parity(num_vars: u32, steps: Vec<Step>) {
+    let outcomes_static = run_scenario!(statik, num_vars, steps);
+    let outcomes_dynamic = run_scenario!(dynamic, num_vars, steps);
+    assert_eq!(outcomes_static.len(), outcomes_dynamic.len());
+
+    let mut clauses: Vec<&Clause> = Vec::new();
+    let mut outcomes = outcomes_static.iter().zip(outcomes_dynamic.iter());
+    for step in steps.iter() {
+        match step {
+            Step::AddClause(clause) => clauses.push(clause),
+            Step::Solve(assumptions) => {
+                let ((res_static, model_static), (res_dynamic, model_dynamic)) = outcomes.next().unwrap();
+                assert_eq!(res_static, res_dynamic, "Responses differ on {:?}", step);
+                if let (Some(model_static), Some(model_dynamic)) = (model_static, model_dynamic) {
+                    assert_eq!(model_static.len(), model_dynamic.len());
+                    // Both models must satisfy all clauses and assumptions...
+                    for model in [model_static, model_dynamic] {
+                        for &clause in clauses.iter() {
+                            assert!(is_satisfied(clause, model), "Clause {:?} is not satisfied", clause);
+                        }
+                        for &a in assumptions.iter() {
+                            assert!(is_satisfied(&[a], model), "Assumption {:?} is not satisfied", a);
+                        }
+                    }
+                    // ...and agree on all assigned (not don't-care) variables.
+                    for (v, (&a, &b)) in model_static.iter().zip(model_dynamic.iter()).enumerate() {
+                        if a != LBool::Undef && b != LBool::Undef {
+                            assert_eq!(a, b, "Models differ on variable {}", v);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_parity_simple() {
+    let steps = vec![
+        Step::AddClause(vec![(0, false), (1, false)]),
+        Step::AddClause(vec![(2, false), (3, false)]),
+        Step::AddClause(vec![(0, true), (1, true)]),
+        Step::AddClause(vec![(2, true), (3, true)]),
+        Step::Solve(vec![]),
+        Step::Solve(vec![(0, false), (1, false)]),
+        Step::Solve(vec![(0, false), (2, true)]),
+        Step::AddClause(vec![(0, true)]),
+        Step::Solve(vec![]),
+        Step::AddClause(vec![(1, true)]),
+        Step::Solve(vec![]),
+    ];
+    check_parity(4, steps);
+}
+
+#[test]
+fn test_parity_random_incremental() {
+    let num_vars = 50;
+    for seed in 0..10 {
+        let clauses = random_3sat(num_vars, 250, seed);
+        let mut steps = Vec::new();
+        // Add clauses in batches, solving after each batch under some assumptions
+        for (i, batch) in clauses.chunks(25).enumerate() {
+            steps.extend(batch.iter().cloned().map(Step::AddClause));
+            steps.push(Step::Solve(vec![]));
+            steps.push(Step::Solve(vec![(i as u32, false), (i as u32 + 1, true)]));
+        }
+        check_parity(num_vars, steps);
+    }
+}
@@ -1,6 +1,10 @@
+use std::cell::Cell;
 use std::fmt::{Debug, Display, Formatter};
 
 use itertools::{process_results, Itertools};
+use snafu::ensure;
+
+use crate::common::{InvalidStateSnafu, Result, SolveResponse};
 
 use super::ffi::*;
 use super::lbool::*;
@@ -28,14 +32,16 @@ use super::var::*;
 /// // Solve the SAT problem
 /// assert!(solver.solve());
 /// // Query the result
-/// assert_eq!(solver.model_value_lit(a), LBool::False);
-/// assert_eq!(solver.model_value_lit(b), LBool::True);
-/// assert_eq!(solver.model_value_lit(c), LBool::False);
+/// assert_eq!(solver.model_value_lit(a)?, LBool::False);
+/// assert_eq!(solver.model_value_lit(b)?, LBool::True);
+/// assert_eq!(solver.model_value_lit(c)?, LBool::False);
 /// # Ok(())
 /// # }
 /// ```
 pub struct MiniSat {
     ptr: *mut CMiniSat,
+    /// Response of the last `solve`, reset when clauses or assumptions are added.
+    last_response: Cell<Option<SolveResponse>>,
 }
 
 impl MiniSat {
@@ -44,7 +50,10 @@ impl MiniSat {
         unsafe {
             minisat_eliminate(ptr, true);
         }
-        MiniSat { ptr }
+        MiniSat {
+            ptr,
+            last_response: Cell::new(None),
+        }
     }
 }
 
@@ -131,6 +140,7 @@ impl MiniSat {
     // Add clause
 
     pub fn add_clause_begin(&self) {
+        self.last_response.set(None);
         unsafe { minisat_addClause_begin(self.ptr) }
     }
     pub fn add_clause_add_lit(&self, lit: Lit) {
@@ -176,25 +186,40 @@ impl MiniSat {
     // Solve
 
     pub fn solve_begin(&self) {
+        self.last_response.set(None);
         unsafe { minisat_solve_begin(self.ptr) }
     }
     pub fn solve_add_lit(&self, lit: Lit) {
         unsafe { minisat_solve_addLit(self.ptr, lit_to_c(lit)) }
     }
     pub fn solve_commit(&self) -> bool {
-        unsafe { minisat_solve_commit(self.ptr) }
+        let res = unsafe { minisat_solve_commit(self.ptr) };
+        self.last_response
+            .set(Some(if res { SolveResponse::Sat } else { SolveResponse::Unsat }));
+        res
     }
     pub fn solve_limited_commit(&self) -> LBool {
-        unsafe { lbool_from_c(minisat_limited_solve_commit(self.ptr)) }
+        let res = unsafe { lbool_from_c(minisat_limited_solve_commit(self.ptr)) };
+        self.last_response.set(Some(match res {
+            LBool::True => SolveResponse::Sat,
+            LBool::False => SolveResponse::Unsat,
+            LBool::Undef => SolveResponse::Interrupted,
+        }));
+        res
     }
 
     // Model
 
-    pub fn model_value_var(&self, var: Var) -> LBool {
-        unsafe { lbool_from_c(minisat_modelValue_Var(self.ptr, var_to_c(var))) }
+    // Note: the model is only available if the last `solve` returned SAT,
+    //  otherwise these methods return `MiniSatError::InvalidState`.
+
+    pub fn model_value_var(&self, var: Var) -> Result<LBool> {
+        self.ensure_state(SolveResponse::Sat, "model_value_var")?;
+        Ok(unsafe { lbool_from_c(minisat_modelValue_Var(self.ptr, var_to_c(var))) })
     }
-    pub fn model_value_lit(&self, lit: Lit) -> LBool {
-        unsafe { lbool_from_c(minisat_modelValue_Lit(self.ptr, lit_to_c(lit))) }
+    pub fn model_value_lit(&self, lit: Lit) -> Result<LBool> {
+        self.ensure_state(SolveResponse::Sat, "model_value_lit")?;
+        Ok(unsafe { lbool_from_c(minisat_modelValue_Lit(self.ptr, lit_to_c(lit))) })
     }
 
     // Statistics
@@ -233,6 +258,7 @@ impl MiniSat {
     pub fn reset(&mut self) {
         self.release();
         self.ptr = unsafe { minisat_init() };
+        self.last_response.set(None);
     }
 
     pub fn set_polarity_lit(&self, lit: Lit, pol: LBool) {
@@ -298,4 +324,47 @@ impl MiniSat {
     pub fn solve(&self) -> bool {
         self.solve_under_assumptions(std::iter::empty::<Lit>())
     }
+
+    /// Solve under assumptions within the budgets set by [`set_conf_budget`][Self::set_conf_budget]
+    /// and [`set_prop_budget`][Self::set_prop_budget].
+    ///
+    /// Returns [`LBool::Undef`] if a budget is exhausted.
+    /// The budgets are reset after the solve, so they must be set again before the next limited solve.
+    pub fn solve_limited_under_assumptions<I>(&self, lits: I) -> LBool
+    where
+        I: IntoIterator,
+        I::Item: Into<Lit>,
+    {
+        self.solve_begin();
+        for lit in lits.into_iter() {
+            self.solve_add_lit(lit.into());
+        }
+        let res = self.solve_limited_commit();
+        self.no_budget();
+        res
+    }
+
+    pub fn solve_limited(&self) -> LBool {
+        self.solve_limited_under_assumptions(std::iter::empty::<Lit>())
+    }
+
+    /// Returns the values of all variables in the model, indexed by variable.
+    ///
+    /// Returns [`MiniSatError::InvalidState`][super::MiniSatError::InvalidState]
+    /// unless the last `solve` returned SAT.
+    pub fn model(&self) -> Result<Vec<LBool>> {
+        (0..self.num_vars() as u32).map(|v| self.model_value_var(Var::new(v))).collect()
+    }
+
+    /// Ensure that the last `solve` returned `expected`,
+    /// and no clauses or assumptions were added since then.
+    fn ensure_state(&self, expected: SolveResponse, method: &str) -> Result<()> {
+        ensure!(
+            self.last_response.get() == Some(expected),
+            InvalidStateSnafu {
+                message: format!("`{}` requires the last `solve` to return {}", method, expected),
+            }
+        );
+        Ok(())
+    }
 }
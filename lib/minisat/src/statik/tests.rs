@@ -35,10 +35,10 @@ fn test_minisat_solver_static() -> color_eyre::Result<()> {
     let res = solver.solve();
     assert!(res);
 
-    let val1 = solver.model_value_lit(lit1);
-    let val2 = solver.model_value_lit(lit2);
-    let val3 = solver.model_value_lit(lit3);
-    let val4 = solver.model_value_lit(lit4);
+    let val1 = solver.model_value_lit(lit1)?;
+    let val2 = solver.model_value_lit(lit2)?;
+    let val3 = solver.model_value_lit(lit3)?;
+    let val4 = solver.model_value_lit(lit4)?;
     println!("values: {:?}", [val1, val2, val3, val4]);
     assert!(val1.bool() ^ val2.bool());
     assert!(val3.bool() ^ val4.bool());
@@ -57,3 +57,122 @@ fn test_minisat_solver_static() -> color_eyre::Result<()> {
     println!("{}", "=".repeat(42));
     Ok(())
 }
+
+#[test]
+fn test_model() {
+    let solver = MiniSat::new();
+    let lits: Vec<Lit> = (0..5).map(|_| solver.new_lit()).collect();
+    let clauses = vec![
+        vec![lits[0], lits[1]],
+        vec![-lits[0], lits[2]],
+        vec![-lits[1], -lits[2], lits[3]],
+        vec![-lits[3], lits[4]],
+        vec![-lits[4], -lits[0]],
+    ];
+    for clause in clauses.iter() {
+        solver.add_clause(clause.iter().copied());
+    }
+    assert!(solver.solve());
+
+    let model = solver.model().unwrap();
+    assert_eq!(model.len(), 5);
+    for clause in clauses.iter() {
+        let satisfied = clause.iter().any(|lit| {
+            let value = model[lit.var() as usize];
+            value != LBool::Undef && value.bool() == (lit.sign() == 0)
+        });
+        assert!(satisfied, "Clause {:?} is not satisfied by the model {:?}", clause, model);
+    }
+}
+
+#[test]
+fn test_model_requires_sat() -> color_eyre::Result<()> {
+    let solver = MiniSat::new();
+    let a = solver.new_lit();
+    let b = solver.new_lit();
+    solver.add_clause([a, b]);
+
+    // Model is not available before `solve`
+    assert!(matches!(solver.model_value_lit(a), Err(MiniSatError::InvalidState { .. })));
+
+    assert!(solver.solve());
+    assert!(solver.model_value_lit(a).is_ok());
+    assert_eq!(solver.model()?.len(), 2);
+
+    // Adding a clause invalidates the model
+    solver.add_clause([-a]);
+    match solver.model_value_var(Var::new(0)) {
+        Err(e @ MiniSatError::InvalidState { .. }) => {
+            assert_eq!(
+                e.to_string(),
+                "Invalid solver state: `model_value_var` requires the last `solve` to return SAT"
+            );
+        }
+        other => panic!("Unexpected result: {:?}", other),
+    }
+
+    // Model is not available after UNSAT
+    assert!(!solver.solve_under_assumptions([-b]));
+    assert!(matches!(solver.model(), Err(MiniSatError::InvalidState { .. })));
+
+    assert!(solver.solve());
+    assert_eq!(solver.model_value_lit(b)?, LBool::True);
+
+    Ok(())
+}
+
+#[test]
+fn test_solve_limited() {
+    let solver = MiniSat::new();
+    // Pigeonhole principle: 8 pigeons do not fit into 7 holes.
+    let (pigeons, holes) = (8, 7);
+    let x: Vec<Vec<Lit>> = (0..pigeons).map(|_| (0..holes).map(|_| solver.new_lit()).collect()).collect();
+    for row in x.iter() {
+        solver.add_clause(row.iter().copied());
+    }
+    for h in 0..holes {
+        for p1 in 0..pigeons {
+            for p2 in (p1 + 1)..pigeons {
+                solver.add_clause([-x[p1][h], -x[p2][h]]);
+            }
+        }
+    }
+
+    solver.set_conf_budget(10);
+    assert_eq!(solver.solve_limited(), LBool::Undef);
+    solver.set_prop_budget(10);
+    assert_eq!(solver.solve_limited(), LBool::Undef);
+
+    // Budgets are reset after the limited solve:
+    assert_eq!(solver.solve_limited(), LBool::False);
+}
+
+#[test]
+fn test_lit_external() {
+    let solver = MiniSat::new();
+    let a = solver.new_lit();
+    let b = solver.new_lit();
+    assert_eq!(a.to_external(), 1);
+    assert_eq!((-b).to_external(), -2);
+    assert_eq!(Lit::from_external(1), a);
+    assert_eq!(Lit::from_external(-2), -b);
+}
+
+#[test]
+fn test_lit_display() {
+    assert_eq!(Lit::from_external(-3).to_string(), "-3");
+    assert_eq!(Lit::from_external(5).to_string(), "5");
+    assert_eq!(Var::new(Lit::from_external(-3).var()).to_string(), "3");
+}
+
+#[test]
+#[should_panic(expected = "Literal must be non-zero")]
+fn test_lit_external_zero() {
+    let _ = Lit::from_external(0);
+}
+
+#[test]
+#[should_panic(expected = "Invalid literal")]
+fn test_lit_external_min() {
+    let _ = Lit::from_external(i32::MIN);
+}
@@ -47,6 +47,10 @@ impl<T> Arena<T> {
         self.items.is_empty()
     }
 
+    pub fn capacity(&self) -> usize {
+        self.items.capacity()
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &T> {
         self.items.iter()
     }
@@ -32,6 +32,17 @@ impl Assignment {
         }
     }
 
+    /// Reserve capacity for `num_vars` variables.
+    pub fn reserve(&mut self, num_vars: usize) {
+        self.assignment.reserve_len(num_vars);
+        self.var_data.reserve_len(num_vars);
+        self.trail.reserve(num_vars.saturating_sub(self.trail.len()));
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.assignment.capacity().min(self.var_data.capacity()).min(self.trail.capacity())
+    }
+
     pub fn value(&self, lit: Lit) -> LBool {
         self.assignment[lit.var()] ^ lit.negated()
     }
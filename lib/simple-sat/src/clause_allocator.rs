@@ -7,11 +7,16 @@ use crate::cref::ClauseRef;
 pub struct ClauseAllocator {
     /// All clauses.
     arena: Vec<Clause>,
+    /// Number of freed clauses.
+    num_deleted: usize,
 }
 
 impl ClauseAllocator {
     pub const fn new() -> Self {
-        Self { arena: Vec::new() }
+        Self {
+            arena: Vec::new(),
+            num_deleted: 0,
+        }
     }
 }
 
@@ -55,5 +60,54 @@ impl ClauseAllocator {
         let clause = self.clause_mut(cref);
         assert!(!clause.is_deleted());
         clause.mark_deleted();
+        self.num_deleted += 1;
+    }
+
+    /// Number of live (not freed) clauses.
+    pub fn len(&self) -> usize {
+        self.arena.len() - self.num_deleted
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Total number of allocated clause slots, including the freed ones.
+    ///
+    /// Freed slots are never reused, so `capacity() - len()` is the amount of garbage.
+    pub fn capacity(&self) -> usize {
+        self.arena.len()
+    }
+
+    /// Iterate over live (not freed) clauses.
+    pub fn iter(&self) -> impl Iterator<Item = (ClauseRef, &Clause)> {
+        self.arena
+            .iter()
+            .enumerate()
+            .filter(|(_, clause)| !clause.is_deleted())
+            .map(|(i, clause)| (ClauseRef(i), clause))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iter_live_clauses() {
+        let mut ca = ClauseAllocator::new();
+        let c1 = ca.alloc(Clause::from_external_lits(&[1, 2]));
+        let c2 = ca.alloc(Clause::from_external_lits(&[-1, 3]));
+        let c3 = ca.alloc(Clause::from_external_lits(&[2, -3, 4]));
+        assert_eq!(ca.len(), 3);
+        assert_eq!(ca.capacity(), 3);
+
+        ca.free(c2);
+        assert_eq!(ca.len(), 2);
+        assert_eq!(ca.capacity(), 3);
+
+        let crefs: Vec<ClauseRef> = ca.iter().map(|(cref, _)| cref).collect();
+        assert_eq!(crefs, vec![c1, c3]);
+        assert!(ca.iter().all(|(_, clause)| !clause.is_deleted()));
     }
 }
@@ -11,6 +11,18 @@ use crate::lbool::LBool;
 use crate::lit::Lit;
 use crate::utils::cmp_f64;
 
+/// Statistics of a single learnt clause database reduction.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct ReduceStats {
+    /// Number of learnt clauses removed by the reduction.
+    pub removed: usize,
+    /// Number of learnt clauses kept after the reduction.
+    pub kept: usize,
+    /// Activity of the median learnt clause.
+    /// Less active clauses (except binary and locked ones) are removed.
+    pub activity_threshold: f64,
+}
+
 #[derive(Debug)]
 pub struct ClauseDatabase {
     /// Original clauses.
@@ -72,6 +84,15 @@ impl ClauseDatabase {
         cref
     }
 
+    /// Remove all original clauses.
+    pub fn clear_clauses(&mut self, ca: &mut ClauseAllocator) {
+        for cref in self.clauses.drain(..) {
+            if !ca.clause(cref).is_deleted() {
+                ca.free(cref);
+            }
+        }
+    }
+
     pub fn cla_decay_activity(&mut self) {
         self.cla_inc *= 1.0 / self.cla_decay;
     }
@@ -98,17 +119,22 @@ impl ClauseDatabase {
         }
     }
 
-    pub fn simplify(&mut self, assignment: &Assignment, ca: &mut ClauseAllocator) {
+    /// Remove the clauses satisfied at the root level.
+    ///
+    /// Returns the number of removed clauses.
+    pub fn simplify(&mut self, assignment: &Assignment, ca: &mut ClauseAllocator) -> usize {
+        let mut removed = 0;
         let all_clauses = self.clauses.iter().chain(self.learnts.iter());
         for &cref in all_clauses {
-            let clause = ca.clause_mut(cref);
+            let clause = ca.clause(cref);
             if clause.is_deleted() {
                 continue;
             }
             match clause.contains_fixed_literal(assignment) {
                 LBool::True => {
                     trace!("{} contains satisfied literal => deleting", clause);
-                    clause.mark_deleted();
+                    ca.free(cref);
+                    removed += 1;
                 }
                 LBool::False => {
                     // TODO: fix the situation when the clause becomes unit/empty after shrinking!
@@ -120,9 +146,10 @@ impl ClauseDatabase {
                 }
             }
         }
+        removed
     }
 
-    pub fn reduce(&mut self, assignment: &Assignment, ca: &mut ClauseAllocator) {
+    pub fn reduce(&mut self, assignment: &Assignment, ca: &mut ClauseAllocator) -> ReduceStats {
         self.learnts.sort_by(|&a, &b| {
             let x = ca.clause(a);
             let y = ca.clause(b);
@@ -142,7 +169,10 @@ impl ClauseDatabase {
         let index_lim = self.num_learnts() / 2;
         let extra_lim = cla_inc / self.num_learnts() as f64; // Remove any clause below this activity
 
+        let activity_threshold = self.learnts.get(index_lim).map_or(0.0, |&cref| ca.clause(cref).activity());
+
         let learnts_before_remove = self.learnts.len();
+        let mut removed = 0;
         let mut i = 0;
         self.learnts.retain(|&cref| {
             let c = ca.clause(cref);
@@ -155,13 +185,23 @@ impl ClauseDatabase {
             i += 1;
             if remove {
                 ca.free(cref);
+                removed += 1;
                 false
             } else {
                 true
             }
         });
 
-        let removed = learnts_before_remove - self.learnts.len();
-        trace!("Removed {} clauses of {}", removed, learnts_before_remove);
+        trace!(
+            "Removed {} clauses of {}",
+            learnts_before_remove - self.learnts.len(),
+            learnts_before_remove
+        );
+
+        ReduceStats {
+            removed,
+            kept: self.learnts.len(),
+            activity_threshold,
+        }
     }
 }
@@ -44,6 +44,16 @@ impl<K: Idx> IdxHeap<K> {
         self.index.clear();
     }
 
+    /// Reserve capacity for keys with `idx < len`.
+    pub fn reserve_len(&mut self, len: usize) {
+        self.heap.reserve(len.saturating_sub(self.heap.len()));
+        self.index.reserve_len(len);
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.heap.capacity().min(self.index.capacity())
+    }
+
     /// Peek the top item in the heap.
     pub fn peek(&self) -> Option<&K> {
         self.heap.first()
@@ -87,6 +97,15 @@ impl<K: Idx + Ord> IdxHeap<K> {
     pub fn update(&mut self, key: K) {
         self.update_by(key, Self::ord_cmp)
     }
+    pub fn decrease(&mut self, key: K) {
+        self.decrease_by(key, Self::ord_cmp)
+    }
+    pub fn increase(&mut self, key: K) {
+        self.increase_by(key, Self::ord_cmp)
+    }
+    pub fn check_heap_property(&self) {
+        self.check_heap_property_by(Self::ord_cmp)
+    }
 
     pub fn sorted_iter(&mut self) -> IdxHeapSortedIter<K, fn(&K, &K) -> bool> {
         self.sorted_iter_by(Self::ord_cmp)
@@ -170,6 +189,9 @@ impl<K: Idx> IdxHeap<K> {
             heap: from,
             index: IdxVec::new(),
         };
+        for key in this.heap.iter() {
+            this.index.init_by(key, || usize::MAX);
+        }
         for i in (0..this.len()).rev() {
             this.sift_down_by(i, &cmp);
         }
@@ -229,28 +251,70 @@ impl<K: Idx> IdxHeap<K> {
     where
         F: Fn(&K, &K) -> bool,
     {
-        let i = self.index[&key];
-        if i == usize::MAX {
-            panic!("The key (key.idx() = {}) is missing from the heap", key.idx());
-        }
+        let i = self.position(&key);
         self.sift_down_by(i, &cmp);
         self.sift_up_by(i, cmp);
     }
+
+    /// Move the value (`key`) towards the top of the heap, after it became "better"
+    /// with respect to the comparator (`cmp`), e.g. after bumping its activity in a max-heap.
+    ///
+    /// Panics if `key` is not present in the heap.
     pub fn decrease_by<F>(&mut self, key: K, cmp: F)
     where
         F: Fn(&K, &K) -> bool,
     {
-        let i = self.index[key];
+        let i = self.position(&key);
         self.sift_up_by(i, cmp);
     }
+
+    /// Move the value (`key`) towards the bottom of the heap, after it became "worse"
+    /// with respect to the comparator (`cmp`).
+    ///
+    /// Panics if `key` is not present in the heap.
     pub fn increase_by<F>(&mut self, key: K, cmp: F)
     where
         F: Fn(&K, &K) -> bool,
     {
-        let i = self.index[key];
+        let i = self.position(&key);
         self.sift_down_by(i, cmp);
     }
 
+    fn position(&self, key: &K) -> usize {
+        if !self.contains(key) {
+            panic!("The key (key.idx() = {}) is missing from the heap", key.idx());
+        }
+        self.index[key]
+    }
+
+    /// Validate the heap: each item is not "better" than its parent with respect
+    /// to the comparator (`cmp`), and the positions index is consistent with the heap.
+    ///
+    /// Takes linear time, so it is meant for tests and debugging.
+    ///
+    /// Panics if the heap is inconsistent.
+    pub fn check_heap_property_by<F>(&self, cmp: F)
+    where
+        F: Fn(&K, &K) -> bool,
+    {
+        for (i, key) in self.heap.iter().enumerate() {
+            assert_eq!(self.index[key], i, "Wrong position of key (key.idx() = {})", key.idx());
+            if i > 0 {
+                let p = self.parent(i);
+                assert!(
+                    !cmp(key, &self.heap[p]),
+                    "Heap property violated at {} (key.idx() = {}) with parent at {} (key.idx() = {})",
+                    i,
+                    key.idx(),
+                    p,
+                    self.heap[p].idx()
+                );
+            }
+        }
+        let num_present = self.index.iter().filter(|&&i| i != usize::MAX).count();
+        assert_eq!(num_present, self.heap.len(), "Index contains stale positions");
+    }
+
     pub fn sorted_iter_by<F>(&mut self, cmp: F) -> IdxHeapSortedIter<K, F>
     where
         F: Fn(&K, &K) -> bool,
@@ -391,6 +455,60 @@ mod tests {
         assert_eq!(sorted, vec![5, 4, 3, 2, 1]);
     }
 
+    #[test]
+    fn test_heapify_by() {
+        let cmp = |&a: &u32, &b: &u32| a > b;
+        let heap = IdxHeap::heapify_by(vec![3, 1, 5, 2, 4], cmp);
+        heap.check_heap_property_by(cmp);
+        assert_eq!(heap.into_sorted_vec_by(cmp), vec![5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_bump_activity() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let n = 50;
+        let mut activity = vec![0.0; n];
+        let mut heap = IdxHeap::<u32>::new();
+        for key in 0..n as u32 {
+            heap.insert_by(key, |&a, &b| activity[a as usize] > activity[b as usize]);
+        }
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let key = rng.gen_range(0..n as u32);
+            activity[key as usize] += rng.gen_range(0.0..10.0);
+            let cmp = |&a: &u32, &b: &u32| activity[a as usize] > activity[b as usize];
+            heap.decrease_by(key, cmp);
+            heap.check_heap_property_by(cmp);
+
+            // Occasionally, decay the activity, moving the key down:
+            if rng.gen_bool(0.1) {
+                let key = rng.gen_range(0..n as u32);
+                activity[key as usize] /= 2.0;
+                let cmp = |&a: &u32, &b: &u32| activity[a as usize] > activity[b as usize];
+                heap.increase_by(key, cmp);
+                heap.check_heap_property_by(cmp);
+            }
+        }
+
+        let cmp = |&a: &u32, &b: &u32| activity[a as usize] > activity[b as usize];
+        let order = heap.into_sorted_vec_by(cmp);
+        assert_eq!(order.len(), n);
+        assert!(order.windows(2).all(|w| activity[w[0] as usize] >= activity[w[1] as usize]));
+    }
+
+    #[test]
+    #[should_panic(expected = "missing from the heap")]
+    fn test_decrease_missing() {
+        let mut heap = IdxHeap::<u32>::new();
+        heap.insert(1);
+        heap.insert(2);
+        heap.pop();
+        heap.decrease(2);
+    }
+
     #[test]
     fn test_heap_insert() {
         let mut heap = IdxHeap::<u32>::new();
@@ -39,10 +39,24 @@ impl<K: Idx, V> IdxMap<K, V> {
         self.map.insert(k.borrow().idx(), v)
     }
 
+    /// Reserve capacity for keys with `idx < len`.
+    pub fn reserve_len(&mut self, len: usize) {
+        self.map.reserve_len(len);
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+
     pub fn remove(&mut self, k: impl Borrow<K>) -> Option<V> {
         self.map.remove(k.borrow().idx())
     }
 
+    pub fn contains_key(&self, k: impl Borrow<K>) -> bool {
+        self.map.contains_key(k.borrow().idx())
+    }
+
+    /// Checked access, returning `None` for missing keys.
     pub fn get(&self, k: impl Borrow<K>) -> Option<&V> {
         self.map.get(k.borrow().idx())
     }
@@ -56,6 +70,11 @@ impl<K: Idx, V> IdxMap<K, V> {
     pub fn iter_mut(&mut self) -> vec_map::IterMut<V> {
         self.map.iter_mut()
     }
+
+    #[inline]
+    fn debug_check_key(&self, k: &K) {
+        debug_assert!(self.map.contains_key(k.idx()), "IdxMap: key {} is not present", k.idx());
+    }
 }
 
 // map[key]
@@ -63,6 +82,7 @@ impl<K: Idx, V> Index<K> for IdxMap<K, V> {
     type Output = V;
 
     fn index(&self, k: K) -> &Self::Output {
+        self.debug_check_key(&k);
         self.map.index(k.idx())
     }
 }
@@ -72,6 +92,7 @@ impl<K: Idx, V> Index<&K> for IdxMap<K, V> {
     type Output = V;
 
     fn index(&self, k: &K) -> &Self::Output {
+        self.debug_check_key(k);
         self.map.index(k.idx())
     }
 }
@@ -79,6 +100,7 @@ impl<K: Idx, V> Index<&K> for IdxMap<K, V> {
 // &mut map[key]
 impl<K: Idx, V> IndexMut<K> for IdxMap<K, V> {
     fn index_mut(&mut self, k: K) -> &mut Self::Output {
+        self.debug_check_key(&k);
         self.map.index_mut(k.idx())
     }
 }
@@ -86,6 +108,35 @@ impl<K: Idx, V> IndexMut<K> for IdxMap<K, V> {
 // &mut map[&key]
 impl<K: Idx, V> IndexMut<&K> for IdxMap<K, V> {
     fn index_mut(&mut self, k: &K) -> &mut Self::Output {
+        self.debug_check_key(k);
         self.map.index_mut(k.idx())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::var::Var;
+
+    use super::*;
+
+    #[test]
+    fn test_checked_access() {
+        let mut map: IdxMap<Var, u32> = IdxMap::new();
+        map.insert(Var::new(1), 10);
+        map.insert(Var::new(5), 50);
+        assert!(map.contains_key(Var::new(5)));
+        assert!(!map.contains_key(Var::new(2)));
+        assert_eq!(map.get(Var::new(5)), Some(&50));
+        assert_eq!(map.get(Var::new(2)), None);
+        assert_eq!(map.get(Var::new(100)), None);
+        assert_eq!(map.get_mut(Var::new(100)), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_missing_key() {
+        let mut map: IdxMap<Var, u32> = IdxMap::new();
+        map.insert(Var::new(1), 10);
+        let _ = map[Var::new(2)];
+    }
+}
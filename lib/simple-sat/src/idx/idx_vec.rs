@@ -62,10 +62,28 @@ impl<K: Idx, V> IdxVec<K, V> {
         k.idx() < self.vec.len()
     }
 
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
     pub fn clear(&mut self) {
         self.vec.clear();
     }
 
+    /// Reserve capacity for keys with `idx < len`.
+    pub fn reserve_len(&mut self, len: usize) {
+        self.vec.reserve(len.saturating_sub(self.vec.len()));
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    /// Bounds-checked access, returning `None` for keys outside the vector.
     pub fn get(&self, k: &K) -> Option<&V> {
         self.vec.get(k.idx())
     }
@@ -83,6 +101,16 @@ impl<K: Idx, V> IdxVec<K, V> {
     pub fn iter_mut(&mut self) -> slice::IterMut<V> {
         self.vec.iter_mut()
     }
+
+    #[inline]
+    fn debug_check_key(&self, k: &K) {
+        debug_assert!(
+            self.contains_key(k),
+            "IdxVec: index {} is out of bounds (len = {})",
+            k.idx(),
+            self.vec.len()
+        );
+    }
 }
 
 // vec[key]
@@ -90,6 +118,7 @@ impl<K: Idx, V> Index<K> for IdxVec<K, V> {
     type Output = V;
 
     fn index(&self, k: K) -> &Self::Output {
+        self.debug_check_key(&k);
         self.vec.index(k.idx())
     }
 }
@@ -99,6 +128,7 @@ impl<K: Idx, V> Index<&K> for IdxVec<K, V> {
     type Output = V;
 
     fn index(&self, k: &K) -> &Self::Output {
+        self.debug_check_key(k);
         self.vec.index(k.idx())
     }
 }
@@ -106,6 +136,7 @@ impl<K: Idx, V> Index<&K> for IdxVec<K, V> {
 // &mut vec[key]
 impl<K: Idx, V> IndexMut<K> for IdxVec<K, V> {
     fn index_mut(&mut self, k: K) -> &mut Self::Output {
+        self.debug_check_key(&k);
         self.vec.index_mut(k.idx())
     }
 }
@@ -113,6 +144,36 @@ impl<K: Idx, V> IndexMut<K> for IdxVec<K, V> {
 // &mut vec[&key]
 impl<K: Idx, V> IndexMut<&K> for IdxVec<K, V> {
     fn index_mut(&mut self, k: &K) -> &mut Self::Output {
+        self.debug_check_key(k);
         self.vec.index_mut(k.idx())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::var::Var;
+
+    use super::*;
+
+    #[test]
+    fn test_checked_access() {
+        let mut vec: IdxVec<Var, u32> = IdxVec::from(vec![10, 20, 30]);
+        assert_eq!(vec.len(), 3);
+        assert_eq!(vec.get(&Var::new(2)), Some(&30));
+        assert_eq!(vec.get(&Var::new(3)), None);
+        assert_eq!(vec.get_mut(&Var::new(3)), None);
+
+        // Growing makes the key accessible:
+        vec.init(&Var::new(3));
+        assert_eq!(vec.get(&Var::new(3)), Some(&0));
+        *vec.get_mut(&Var::new(3)).unwrap() = 40;
+        assert_eq!(vec[Var::new(3)], 40);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let vec: IdxVec<Var, u32> = IdxVec::from(vec![10, 20, 30]);
+        let _ = vec[Var::new(3)];
+    }
+}
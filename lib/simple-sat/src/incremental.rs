@@ -0,0 +1,150 @@
+//! Incremental solving driven by a file of instructions.
+//!
+//! The format is line-based, similar to DIMACS:
+//!
+//! * `c ...` is a comment, `p ...` is a header, both are skipped,
+//! * `1 -2 3 0` adds a clause,
+//! * `a 1 -2 0` adds assumptions for the next solve,
+//! * `s` solves under the assumptions added since the previous solve.
+
+use std::io::BufRead;
+use std::path::Path;
+
+use tracing::{debug, trace};
+
+use crate::lit::Lit;
+use crate::solver::{SolveResult, Solver};
+use crate::utils::{parse_dimacs_clause, read_maybe_gzip};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    AddClause(Vec<Lit>),
+    Assume(Vec<Lit>),
+    Solve,
+}
+
+pub fn parse_commands<R>(reader: R) -> impl Iterator<Item = Command>
+where
+    R: BufRead,
+{
+    reader.lines().map_while(Result::ok).filter_map(|line| {
+        let line = line.trim();
+        if line.is_empty() {
+            trace!("Skipping empty line");
+            None
+        } else if line.starts_with('c') || line.starts_with('p') {
+            trace!("Skipping line '{}'", line);
+            None
+        } else if let Some(lits) = line.strip_prefix('a') {
+            Some(Command::Assume(parse_dimacs_clause(lits)))
+        } else if line == "s" {
+            Some(Command::Solve)
+        } else {
+            Some(Command::AddClause(parse_dimacs_clause(line)))
+        }
+    })
+}
+
+pub fn parse_commands_from_file<P>(path: P) -> impl Iterator<Item = Command>
+where
+    P: AsRef<Path>,
+{
+    parse_commands(read_maybe_gzip(path).unwrap())
+}
+
+/// Execute the commands one by one, calling `on_solve` with the result of each solve.
+pub fn run_commands<I, F>(solver: &mut Solver, commands: I, mut on_solve: F)
+where
+    I: IntoIterator<Item = Command>,
+    F: FnMut(&Solver, SolveResult),
+{
+    let mut assumptions = Vec::new();
+    for command in commands {
+        match command {
+            Command::AddClause(lits) => {
+                solver.add_clause(&lits);
+            }
+            Command::Assume(lits) => {
+                // Auto-create missing variables, as `add_clause` does.
+                if let Some(max_var) = lits.iter().map(|lit| lit.var().to_external() as usize).max() {
+                    while solver.num_vars() < max_var {
+                        solver.new_var();
+                    }
+                }
+                assumptions.extend(lits);
+            }
+            Command::Solve => {
+                debug!("Solving under {} assumptions", assumptions.len());
+                let result = solver.solve_under_assumptions(&assumptions);
+                assumptions.clear();
+                on_solve(solver, result);
+            }
+        }
+    }
+}
+
+/// Execute the commands, returning the results of all solves, in order.
+pub fn run_commands_collect<I>(solver: &mut Solver, commands: I) -> Vec<SolveResult>
+where
+    I: IntoIterator<Item = Command>,
+{
+    let mut results = Vec::new();
+    run_commands(solver, commands, |_, result| results.push(result));
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_commands() {
+        let input = "c example\np inccnf\n1 2 0\na -1 0\ns\n";
+        let commands = parse_commands(input.as_bytes()).collect_vec();
+        assert_eq!(
+            commands,
+            vec![
+                Command::AddClause(vec![Lit::from_external(1), Lit::from_external(2)]),
+                Command::Assume(vec![Lit::from_external(-1)]),
+                Command::Solve,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_run_commands() {
+        let input = "\
+p inccnf
+1 2 0
+-1 2 0
+s
+a -2 0
+s
+a 1 0
+a 3 0
+s
+1 -2 0
+a 1 0
+s
+-1 -2 0
+s
+s
+";
+        let mut solver = Solver::default();
+        let results = run_commands_collect(&mut solver, parse_commands(input.as_bytes()));
+        assert_eq!(
+            results,
+            vec![
+                SolveResult::Sat,
+                SolveResult::Unsat,
+                SolveResult::Sat,
+                SolveResult::Sat,
+                SolveResult::Unsat,
+                SolveResult::Unsat,
+            ]
+        );
+        assert_eq!(solver.num_vars(), 3);
+    }
+}
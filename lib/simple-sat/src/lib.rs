@@ -1,20 +1,27 @@
 pub mod solver;
 
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
 pub mod assignment;
 pub mod clause;
 pub mod clause_allocator;
 pub mod clause_database;
 pub mod cref;
 pub mod idx;
+pub mod incremental;
 pub mod lbool;
 pub mod learning;
 pub mod lit;
 pub mod options;
+pub mod preprocess;
+pub mod reconstruction;
 pub mod restart;
+pub mod statistics;
 pub mod utils;
 pub mod var;
 pub mod var_order;
 pub mod watch;
+pub mod xor;
 
 pub mod arena;
 pub mod trie;
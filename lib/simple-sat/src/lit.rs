@@ -82,3 +82,16 @@ impl Display for Lit {
         write!(f, "{}", self.to_external())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lit_display() {
+        let lit = Lit::from_external(-3);
+        assert_eq!(lit.to_string(), "-3");
+        assert_eq!(Lit::from_external(5).to_string(), "5");
+        assert_eq!(lit.var().to_string(), "3");
+    }
+}
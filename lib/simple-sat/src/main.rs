@@ -8,19 +8,29 @@ use serde::Serialize;
 use serde_with::serde_as;
 use serde_with::DurationSecondsWithFrac;
 
+use simple_sat::incremental::{parse_commands_from_file, run_commands};
 use simple_sat::options::Options;
 use simple_sat::options::DEFAULT_OPTIONS;
 use simple_sat::solver::{SolveResult, Solver};
+use simple_sat::statistics::Statistics;
+use simple_sat::var_order::HeuristicKind;
+use simple_sat::watch::Propagation;
 
 const HEADING_RESTART: &str = "RESTART OPTIONS";
 const HEADING_REDUCE_DB: &str = "REDUCE-DB OPTIONS";
+const HEADING_MINIMIZE: &str = "MINIMIZATION OPTIONS";
 
 #[derive(Debug, Parser)]
 #[command(author, version)]
 struct Cli {
     /// Path to input CNF.
     #[arg(value_name = "PATH")]
-    input: PathBuf,
+    #[arg(required_unless_present = "incremental", conflicts_with = "incremental")]
+    input: Option<PathBuf>,
+
+    /// Path to a file with incremental instructions (clauses, `a <lits> 0` assumptions, `s` solves).
+    #[arg(long, value_name = "PATH")]
+    incremental: Option<PathBuf>,
 
     /// Path to output results.
     #[arg(short, long, value_name = "PATH")]
@@ -46,6 +56,11 @@ struct Cli {
     #[arg(default_value_t = DEFAULT_OPTIONS.restart_inc)]
     restart_inc: f64,
 
+    /// Block restarts when the trail is larger than the given factor times the average trail size.
+    #[arg(help_heading = HEADING_RESTART)]
+    #[arg(long, value_name = "NUM")]
+    restart_blocking: Option<f64>,
+
     #[arg(help_heading = HEADING_REDUCE_DB)]
     #[arg(long, value_name = "NUM")]
     #[arg(default_value_t = DEFAULT_OPTIONS.min_learnts_limit)]
@@ -70,6 +85,33 @@ struct Cli {
     #[arg(long, value_name = "NUM")]
     #[arg(default_value_t = DEFAULT_OPTIONS.learntsize_adjust_inc)]
     learntsize_adjust_inc: f64,
+
+    /// Conflict clause minimization mode (0 = none, 1 = basic, 2 = deep).
+    #[arg(help_heading = HEADING_MINIMIZE)]
+    #[arg(long, value_name = "INT")]
+    #[arg(value_parser = clap::value_parser!(u32).range(0..=2))]
+    #[arg(default_value_t = DEFAULT_OPTIONS.ccmin_mode)]
+    ccmin_mode: u32,
+
+    /// Decision heuristic (vsids, random).
+    #[arg(long, value_name = "NAME")]
+    #[arg(default_value_t = DEFAULT_OPTIONS.heuristic)]
+    heuristic: HeuristicKind,
+
+    /// Unit propagation scheme (two-watched, counting).
+    #[arg(long, value_name = "NAME")]
+    #[arg(default_value_t = DEFAULT_OPTIONS.propagation)]
+    propagation: Propagation,
+
+    /// Run bounded variable elimination before the search.
+    ///
+    /// Not supported in the incremental mode, since later clauses and assumptions may mention eliminated variables.
+    #[arg(long, conflicts_with = "incremental")]
+    preprocess: bool,
+
+    /// Check the solver invariants after each propagation and backtrack (only in debug builds).
+    #[arg(long)]
+    check_invariants: bool,
 }
 
 #[serde_as]
@@ -79,18 +121,8 @@ struct TheResult {
     result: SolveResult,
     #[serde_as(as = "DurationSecondsWithFrac<f64>")]
     time_total: Duration,
-    #[serde_as(as = "DurationSecondsWithFrac<f64>")]
-    time_search: Duration,
-    #[serde_as(as = "DurationSecondsWithFrac<f64>")]
-    time_propagate: Duration,
-    num_vars: usize,
-    num_clauses: usize,
-    num_learnts: usize,
-    num_decisions: usize,
-    num_propagations: usize,
-    num_conflicts: usize,
-    num_restarts: usize,
-    num_reduces: usize,
+    #[serde(flatten)]
+    statistics: Statistics,
 }
 
 fn main() -> color_eyre::Result<()> {
@@ -104,35 +136,45 @@ fn main() -> color_eyre::Result<()> {
         is_luby: cli.luby,
         restart_init: cli.restart_init,
         restart_inc: cli.restart_inc,
+        restart_blocking: cli.restart_blocking,
         min_learnts_limit: cli.min_learnts_limit,
         learntsize_factor: cli.learntsize_factor,
         learntsize_inc: cli.learntsize_inc,
         learntsize_adjust_start: cli.learntsize_adjust_start,
         learntsize_adjust_inc: cli.learntsize_adjust_inc,
+        ccmin_mode: cli.ccmin_mode,
+        heuristic: cli.heuristic,
+        propagation: cli.propagation,
+        preprocess: cli.preprocess,
+        check_invariants: cli.check_invariants,
         // ..DEFAULT_OPTIONS
     };
     let mut solver = Solver::new(options);
-    solver.init_from_file(&cli.input);
-    let time_create = time_start.elapsed();
-
-    // Solve:
-    let result = solver.solve();
+    let input = cli.input.as_ref().or(cli.incremental.as_ref()).unwrap();
+    let (result, time_create) = if let Some(path) = &cli.incremental {
+        // Solve incrementally:
+        let mut result = SolveResult::Unknown;
+        let mut num_solves = 0;
+        run_commands(&mut solver, parse_commands_from_file(path), |_, res| {
+            num_solves += 1;
+            println!("Solve #{}: {}", num_solves, res);
+            result = res;
+        });
+        (result, time_start.elapsed())
+    } else {
+        solver.init_from_file(input);
+        let time_create = time_start.elapsed();
+
+        // Solve:
+        (solver.solve(), time_create)
+    };
     let time_total = time_start.elapsed();
 
     let res = TheResult {
-        name: cli.input.file_name().unwrap().to_str().unwrap().to_string(),
+        name: input.file_name().unwrap().to_str().unwrap().to_string(),
         result,
         time_total,
-        time_search: solver.time_search,
-        time_propagate: solver.time_propagate,
-        num_vars: solver.num_vars(),
-        num_clauses: solver.num_clauses(),
-        num_learnts: solver.num_learnts(),
-        num_decisions: solver.num_decisions(),
-        num_propagations: solver.num_propagations(),
-        num_conflicts: solver.num_conflicts(),
-        num_restarts: solver.num_restarts(),
-        num_reduces: solver.num_reduces(),
+        statistics: solver.statistics(),
     };
     // println!("{:#?}", result);
 
@@ -153,6 +195,9 @@ fn main() -> color_eyre::Result<()> {
     println!("propagations: {}", solver.num_propagations().to_formatted_string(format));
     println!("conflicts:    {}", solver.num_conflicts().to_formatted_string(format));
     println!("restarts:     {}", solver.num_restarts().to_formatted_string(format));
+    if solver.restart_blocker.is_some() {
+        println!("blocked:      {}", solver.num_blocked_restarts().to_formatted_string(format));
+    }
     println!("simplifies:   {}", solver.num_simplifies().to_formatted_string(format));
     println!("reduces:      {}", solver.num_reduces().to_formatted_string(format));
     println!("time total:      {:?}", time_total);
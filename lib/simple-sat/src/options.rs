@@ -1,15 +1,32 @@
+use crate::var_order::HeuristicKind;
+use crate::watch::Propagation;
+
 #[derive(Debug, Clone)]
 pub struct Options {
     // Restart:
     pub is_luby: bool,
     pub restart_init: usize,
     pub restart_inc: f64,
+    pub restart_blocking: Option<f64>,
     // ReduceDB:
     pub min_learnts_limit: usize,
     pub learntsize_factor: f64,
     pub learntsize_inc: f64,
     pub learntsize_adjust_start: f64,
     pub learntsize_adjust_inc: f64,
+    // Conflict clause minimization mode:
+    //  - 0 = none,
+    //  - 1 = basic (local),
+    //  - 2 = deep (recursive).
+    pub ccmin_mode: u32,
+    // Decision heuristic:
+    pub heuristic: HeuristicKind,
+    // Unit propagation scheme:
+    pub propagation: Propagation,
+    // Run bounded variable elimination before the first search:
+    pub preprocess: bool,
+    // Check the solver invariants after each propagation and backtrack (only in debug builds):
+    pub check_invariants: bool,
 }
 
 pub const DEFAULT_OPTIONS: Options = Options {
@@ -17,12 +34,23 @@ pub const DEFAULT_OPTIONS: Options = Options {
     is_luby: true,
     restart_init: 100,
     restart_inc: 2.0,
+    restart_blocking: None,
     // ReduceDB:
     min_learnts_limit: 1000,
     learntsize_factor: 1.0 / 3.0,
     learntsize_inc: 1.1,
     learntsize_adjust_start: 100.0,
     learntsize_adjust_inc: 1.5,
+    // Minimization:
+    ccmin_mode: 1,
+    // Decisions:
+    heuristic: HeuristicKind::Vsids,
+    // Propagation:
+    propagation: Propagation::TwoWatched,
+    // Preprocessing:
+    preprocess: false,
+    // Debug:
+    check_invariants: false,
 };
 
 impl Default for Options {
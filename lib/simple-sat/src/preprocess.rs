@@ -0,0 +1,267 @@
+//! Bounded variable elimination (BVE).
+//!
+//! A variable `x` is eliminated by replacing all the clauses containing `x` or `~x`
+//! with all their non-tautological resolvents on `x`, as long as the number of
+//! clauses does not grow by more than the given bound.
+//! The resulting formula is equisatisfiable to the original one, and any model of it
+//! can be extended to a model of the original formula via [`Reconstruction`].
+//!
+//! Duplicate clauses are dropped, and so are the resolvents subsumed by some clause,
+//! both detected via [`ClauseTrie`]. Note that the clauses removed by the elimination
+//! stay in the trie, which is fine, since they contain the eliminated variables,
+//! and thus can never subsume a later resolvent.
+//!
+//! [`Reconstruction`]: crate::reconstruction::Reconstruction
+
+use itertools::Itertools;
+use tracing::debug;
+
+use crate::idx::VarVec;
+use crate::lit::Lit;
+use crate::trie::ClauseTrie;
+use crate::var::Var;
+
+/// Maximum number of occurrences of a variable (for each polarity) to try eliminating it.
+const MAX_OCCURRENCES: usize = 16;
+
+/// Maximum length of a resolvent.
+const MAX_RESOLVENT_LEN: usize = 20;
+
+/// Eliminated variable together with the removed clauses containing it.
+#[derive(Debug, Clone)]
+pub struct Elimination {
+    pub var: Var,
+    pub clauses: Vec<Vec<Lit>>,
+}
+
+/// Eliminate variables from `clauses` (over `num_vars` variables).
+///
+/// Variables in `frozen` are never eliminated.
+/// A variable is eliminated only when the number of its non-tautological resolvents
+/// does not exceed the number of clauses containing it by more than `grow`.
+///
+/// Returns the resulting clauses and the performed eliminations, in order.
+pub fn eliminate_variables(clauses: &[Vec<Lit>], num_vars: usize, frozen: &[Var], grow: usize) -> (Vec<Vec<Lit>>, Vec<Elimination>) {
+    let mut db: Vec<Option<Vec<Lit>>> = Vec::with_capacity(clauses.len());
+    let mut occurs: VarVec<Vec<usize>> = VarVec::from(vec![Vec::new(); num_vars]);
+    let mut trie = ClauseTrie::new();
+    for clause in clauses.iter() {
+        if let Some(clause) = normalize(clause.iter().copied()) {
+            if !trie.insert(&clause) {
+                // Duplicate clause.
+                continue;
+            }
+            for lit in clause.iter() {
+                occurs[lit.var()].push(db.len());
+            }
+            db.push(Some(clause));
+        }
+    }
+
+    let mut is_frozen = VarVec::from(vec![false; num_vars]);
+    for &var in frozen.iter() {
+        is_frozen[var] = true;
+    }
+
+    // Try variables with fewer occurrences first:
+    let candidates = (0..num_vars)
+        .map(|i| Var::new(i as u32))
+        .filter(|&var| !is_frozen[var] && !occurs[var].is_empty())
+        .sorted_by_key(|&var| occurs[var].len())
+        .collect_vec();
+
+    let mut eliminations = Vec::new();
+    for var in candidates {
+        let (pos, neg): (Vec<usize>, Vec<usize>) = occurs[var]
+            .iter()
+            .copied()
+            .filter(|&i| db[i].is_some())
+            .unique()
+            .partition(|&i| db[i].as_ref().unwrap().contains(&Lit::positive(var)));
+        if pos.len() > MAX_OCCURRENCES || neg.len() > MAX_OCCURRENCES {
+            continue;
+        }
+
+        let limit = pos.len() + neg.len() + grow;
+        let mut resolvents = Vec::new();
+        let mut too_many = false;
+        'outer: for &i in pos.iter() {
+            for &j in neg.iter() {
+                let a = db[i].as_ref().unwrap();
+                let b = db[j].as_ref().unwrap();
+                let lits = a.iter().chain(b.iter()).copied().filter(|lit| lit.var() != var);
+                if let Some(resolvent) = normalize(lits) {
+                    if trie.contains_subset(&resolvent) {
+                        // Subsumed resolvent.
+                        continue;
+                    }
+                    if resolvent.len() > MAX_RESOLVENT_LEN || resolvents.len() >= limit {
+                        too_many = true;
+                        break 'outer;
+                    }
+                    resolvents.push(resolvent);
+                }
+            }
+        }
+        if too_many {
+            continue;
+        }
+
+        debug!(
+            "Eliminating {} ({}+{} clauses -> {} resolvents)",
+            var,
+            pos.len(),
+            neg.len(),
+            resolvents.len()
+        );
+        let removed = pos.iter().chain(neg.iter()).map(|&i| db[i].take().unwrap()).collect_vec();
+        eliminations.push(Elimination { var, clauses: removed });
+        for resolvent in resolvents {
+            if !trie.insert(&resolvent) {
+                // Duplicate resolvent.
+                continue;
+            }
+            for lit in resolvent.iter() {
+                occurs[lit.var()].push(db.len());
+            }
+            db.push(Some(resolvent));
+        }
+    }
+
+    (db.into_iter().flatten().collect(), eliminations)
+}
+
+/// Sort and deduplicate the literals, returning `None` for tautologies.
+fn normalize<I>(lits: I) -> Option<Vec<Lit>>
+where
+    I: IntoIterator<Item = Lit>,
+{
+    let lits = lits.into_iter().sorted_by_key(|lit| lit.inner()).dedup().collect_vec();
+    if lits.windows(2).any(|w| w[0].var() == w[1].var()) {
+        None
+    } else {
+        Some(lits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::idx::VarMap;
+    use crate::lbool::LBool;
+    use crate::reconstruction::Reconstruction;
+    use crate::solver::{SolveResult, Solver};
+
+    use super::*;
+
+    fn random_3sat(rng: &mut StdRng, num_vars: usize, num_clauses: usize) -> Vec<Vec<Lit>> {
+        sat_nexus_core::utils::random_3sat(num_vars, num_clauses, rng)
+            .iter()
+            .map(|clause| clause.iter().map(|lit| Lit::from_external(lit.get())).collect_vec())
+            .collect_vec()
+    }
+
+    fn solve(num_vars: usize, clauses: &[Vec<Lit>]) -> Option<VarMap<LBool>> {
+        let mut solver = Solver::default();
+        for _ in 0..num_vars {
+            solver.new_var();
+        }
+        for clause in clauses.iter() {
+            solver.add_clause(clause);
+        }
+        match solver.solve() {
+            SolveResult::Sat => {
+                let mut model = VarMap::new();
+                for i in 0..num_vars {
+                    let var = Var::new(i as u32);
+                    model.insert(var, solver.value_var(var));
+                }
+                Some(model)
+            }
+            SolveResult::Unsat => None,
+            SolveResult::Unknown => unreachable!(),
+        }
+    }
+
+    fn is_satisfied(clauses: &[Vec<Lit>], model: &VarMap<LBool>) -> bool {
+        clauses.iter().all(|clause| {
+            clause
+                .iter()
+                .any(|&lit| model.get(lit.var()).is_some_and(|&v| v ^ lit.negated() == LBool::True))
+        })
+    }
+
+    fn reconstruction(eliminations: Vec<Elimination>) -> Reconstruction {
+        let mut reconstruction = Reconstruction::new();
+        for elimination in eliminations {
+            reconstruction.push(elimination);
+        }
+        reconstruction
+    }
+
+    #[test]
+    fn test_eliminate_simple() {
+        // (x1 or x2) and (~x1 or x3) => (x2 or x3)
+        let clauses = vec![
+            vec![Lit::from_external(1), Lit::from_external(2)],
+            vec![Lit::from_external(-1), Lit::from_external(3)],
+        ];
+        let (result, eliminations) = eliminate_variables(&clauses, 3, &[Var::from_external(2), Var::from_external(3)], 0);
+        assert_eq!(result, vec![vec![Lit::from_external(2), Lit::from_external(3)]]);
+        assert_eq!(eliminations.len(), 1);
+        assert_eq!(eliminations[0].var, Var::from_external(1));
+        assert_eq!(eliminations[0].clauses.len(), 2);
+
+        // x2 = false, x3 = true => x1 must be true
+        let mut model = VarMap::new();
+        model.insert(Var::from_external(2), LBool::False);
+        model.insert(Var::from_external(3), LBool::True);
+        reconstruction(eliminations).extend_model(&mut model);
+        assert_eq!(model[Var::from_external(1)], LBool::True);
+        assert!(is_satisfied(&clauses, &model));
+    }
+
+    #[test]
+    fn test_eliminate_subsumed() {
+        // (x1 or x2) and (~x1 or x3) => (x2 or x3), which is subsumed by (x2)
+        let clauses = vec![
+            vec![Lit::from_external(1), Lit::from_external(2)],
+            vec![Lit::from_external(-1), Lit::from_external(3)],
+            vec![Lit::from_external(2)],
+            vec![Lit::from_external(2)],
+        ];
+        let (result, eliminations) = eliminate_variables(&clauses, 3, &[Var::from_external(2), Var::from_external(3)], 0);
+        assert_eq!(result, vec![vec![Lit::from_external(2)]]);
+        assert_eq!(eliminations.len(), 1);
+        assert_eq!(eliminations[0].var, Var::from_external(1));
+    }
+
+    #[test]
+    fn test_eliminate_equisatisfiable() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let num_vars = 30;
+        let mut num_eliminated = 0;
+        for num_clauses in [60, 90, 120, 130, 140, 150, 160, 200] {
+            let clauses = random_3sat(&mut rng, num_vars, num_clauses);
+            let (result, eliminations) = eliminate_variables(&clauses, num_vars, &[], 0);
+            assert!(result.len() <= clauses.len());
+            num_eliminated += eliminations.len();
+
+            let original = solve(num_vars, &clauses);
+            let preprocessed = solve(num_vars, &result);
+            assert_eq!(original.is_some(), preprocessed.is_some());
+
+            if let Some(mut model) = preprocessed {
+                assert!(is_satisfied(&result, &model));
+                for elimination in eliminations.iter() {
+                    model.remove(elimination.var);
+                }
+                reconstruction(eliminations).extend_model(&mut model);
+                assert!(is_satisfied(&clauses, &model));
+            }
+        }
+        assert!(num_eliminated > 0);
+    }
+}
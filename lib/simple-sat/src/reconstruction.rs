@@ -0,0 +1,57 @@
+use crate::idx::VarMap;
+use crate::lbool::LBool;
+use crate::lit::Lit;
+use crate::preprocess::Elimination;
+use crate::var::Var;
+
+/// Stack of elimination steps, used to extend the models of the simplified formula
+/// to the models of the original one.
+#[derive(Debug, Default)]
+pub struct Reconstruction {
+    stack: Vec<Elimination>,
+}
+
+impl Reconstruction {
+    pub const fn new() -> Self {
+        Self { stack: Vec::new() }
+    }
+
+    pub fn push(&mut self, elimination: Elimination) {
+        self.stack.push(elimination);
+    }
+
+    /// Remove the last elimination step, e.g. to re-introduce its variable.
+    pub fn pop(&mut self) -> Option<Elimination> {
+        self.stack.pop()
+    }
+
+    /// Elimination steps, in the order they were performed.
+    pub fn steps(&self) -> &[Elimination] {
+        &self.stack
+    }
+
+    pub fn len(&self) -> usize {
+        self.stack.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    /// Extend the `partial` model to the eliminated variables, replaying the steps in reverse order.
+    ///
+    /// The `partial` model must assign all the variables which were not eliminated.
+    /// Values of the eliminated variables already present in `partial` are overwritten.
+    pub fn extend_model(&self, partial: &mut VarMap<LBool>) {
+        let value = |partial: &VarMap<LBool>, lit: Lit| partial.get(lit.var()).map_or(LBool::Undef, |&v| v ^ lit.negated());
+        for Elimination { var, clauses } in self.stack.iter().rev() {
+            let var: Var = *var;
+            let pos = Lit::positive(var);
+            // Set `var` to true iff some clause containing it is not satisfied by the other literals:
+            let is_true = clauses
+                .iter()
+                .any(|clause| clause.contains(&pos) && !clause.iter().any(|&lit| lit.var() != var && value(partial, lit) == LBool::True));
+            partial.insert(var, LBool::from(is_true));
+        }
+    }
+}
@@ -18,3 +18,73 @@ impl RestartStrategy {
         (restart_base * self.restart_init as f64) as usize
     }
 }
+
+/// Glucose-style restart blocking.
+///
+/// Tracks the moving average of the trail size at conflicts, and blocks
+/// a scheduled restart when the current trail is much larger than the average,
+/// i.e. when the solver is (probably) approaching a satisfying assignment.
+#[derive(Debug)]
+pub struct RestartBlocker {
+    /// Restart is blocked when `trail > factor * average`.
+    pub factor: f64,
+    /// Size of the window for the moving average.
+    pub window: usize,
+    num_samples: usize,
+    trail_avg: f64,
+    last_trail: usize,
+}
+
+impl RestartBlocker {
+    pub const DEFAULT_WINDOW: usize = 5000;
+
+    pub fn new(factor: f64) -> Self {
+        Self {
+            factor,
+            window: Self::DEFAULT_WINDOW,
+            num_samples: 0,
+            trail_avg: 0.0,
+            last_trail: 0,
+        }
+    }
+
+    /// Average trail size at conflicts.
+    pub fn trail_avg(&self) -> f64 {
+        self.trail_avg
+    }
+
+    /// Record the trail size at conflict.
+    pub fn on_conflict(&mut self, trail_len: usize) {
+        // Exact mean for the first `window` samples, exponential moving average afterwards.
+        if self.num_samples < self.window {
+            self.num_samples += 1;
+        }
+        self.trail_avg += (trail_len as f64 - self.trail_avg) / self.num_samples as f64;
+        self.last_trail = trail_len;
+    }
+
+    /// Check whether the scheduled restart should be blocked.
+    pub fn should_block(&self) -> bool {
+        self.num_samples > 0 && self.last_trail as f64 > self.factor * self.trail_avg
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restart_blocker() {
+        let mut blocker = RestartBlocker::new(1.4);
+        assert!(!blocker.should_block());
+        for _ in 0..10 {
+            blocker.on_conflict(100);
+        }
+        assert_eq!(blocker.trail_avg(), 100.0);
+        assert!(!blocker.should_block());
+        blocker.on_conflict(200);
+        assert!(blocker.should_block());
+        blocker.on_conflict(50);
+        assert!(!blocker.should_block());
+    }
+}
@@ -1,7 +1,9 @@
 use std::collections::HashSet;
-use std::fmt::{Display, Formatter};
+use std::fmt::{Debug, Display, Formatter};
 use std::mem;
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use itertools::{zip_eq, Itertools};
@@ -13,21 +15,27 @@ use tracing::{debug, info, trace, warn};
 use crate::assignment::{Assignment, VarData};
 use crate::clause::Clause;
 use crate::clause_allocator::ClauseAllocator;
-use crate::clause_database::ClauseDatabase;
+use crate::clause_database::{ClauseDatabase, ReduceStats};
 use crate::cref::ClauseRef;
-use crate::idx::VarVec;
+use crate::idx::{VarMap, VarVec};
 use crate::lbool::LBool;
 use crate::learning::{LearningGuard, LearningStrategy};
 use crate::lit::Lit;
 use crate::options::Options;
 use crate::options::DEFAULT_OPTIONS;
-use crate::restart::RestartStrategy;
+use crate::preprocess::{eliminate_variables, Elimination};
+use crate::reconstruction::Reconstruction;
+use crate::restart::{RestartBlocker, RestartStrategy};
+use crate::statistics::Statistics;
 use crate::trie::Trie;
 use crate::utils::parse_dimacs;
+use crate::utils::parse_dimacs_with_comments;
+use crate::utils::DimacsLine;
 use crate::utils::DisplaySlice;
 use crate::var::Var;
-use crate::var_order::VarOrder;
-use crate::watch::{WatchList, Watcher};
+use crate::var_order::DecisionHeuristic;
+use crate::watch::{OccurList, Propagation, WatchList, Watcher};
+use crate::xor::XorSystem;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, SerializeDisplay)]
 pub enum SolveResult {
@@ -56,6 +64,7 @@ enum SearchResult {
     Unsat,
     Restart,
     AssumptionsConflict(Vec<Lit>),
+    Interrupted,
 }
 
 /// CDCL SAT solver.
@@ -65,8 +74,9 @@ enum SearchResult {
 /// * `ca`: The clause allocator.
 /// * `db`: The clause database.
 /// * `watchlist`: A list of clauses that are watched by a variable.
+/// * `occurs`: A list of clauses containing a literal (only for counting propagation).
 /// * `assignment`: The current assignment of the solver.
-/// * `var_order`: The variable order heuristic.
+/// * `var_order`: The decision heuristic.
 /// * `polarity`: The phase saving heuristic.
 /// * `ok`: This is a boolean that indicates whether the solver is in a state where it can continue solving.
 /// * `next_var`: The next variable to be assigned.
@@ -85,22 +95,40 @@ pub struct Solver {
     ca: ClauseAllocator,
     db: ClauseDatabase,
     watchlist: WatchList,
+    occurs: OccurList,
+    xors: XorSystem,
+    /// Row of the XOR system implying the variable (on levels above 0), see [`Solver::explain`].
+    xor_reasons: VarVec<Option<usize>>,
+    /// Falsified clauses of the XOR conflicts, freed on backtracking.
+    xor_conflicts: Vec<ClauseRef>,
     assignment: Assignment,
-    var_order: VarOrder,
+    var_order: Box<dyn DecisionHeuristic>,
     polarity: VarVec<bool>, // `pol=true` => negated lit; `false` => positive
     // seen: Vec<bool>,
     pub restart_strategy: RestartStrategy,
+    pub restart_blocker: Option<RestartBlocker>,
     pub learning_guard: LearningGuard,
     ok: bool,
     next_var: u32,
+    num_dec_vars: usize,
+    comments: Vec<String>,
+    preprocessed: bool,
+    eliminated: VarVec<bool>,
+    reconstruction: Reconstruction,
+    interrupt: Option<Arc<AtomicBool>>,
+    progress_callback: Option<ProgressCallback>,
     // rng: StdRng,
     // Statistics:
     decisions: usize,
     propagations: usize,
     conflicts: usize,
     restarts: usize,
+    blocked_restarts: usize,
     simplifies: usize,
+    simplified_clauses: usize,
     reduces: usize,
+    reduced_learnts: usize,
+    last_reduce: ReduceStats,
     // Timings:
     pub time_search: Duration,
     pub time_propagate: Duration,
@@ -110,6 +138,7 @@ pub struct Solver {
     pub time_restart: Duration,
     pub time_simplify: Duration,
     pub time_reduce: Duration,
+    pub time_preprocess: Duration,
 }
 
 impl Solver {
@@ -119,6 +148,7 @@ impl Solver {
             restart_init: options.restart_init,
             restart_inc: options.restart_inc,
         };
+        let restart_blocker = options.restart_blocking.map(RestartBlocker::new);
         let learning_strategy = LearningStrategy {
             min_learnts_limit: options.min_learnts_limit,
             learntsize_factor: options.learntsize_factor,
@@ -127,26 +157,43 @@ impl Solver {
             learntsize_adjust_inc: options.learntsize_adjust_inc,
         };
         let learning_guard = LearningGuard::new(learning_strategy);
+        let var_order = options.heuristic.build();
         Self {
             options,
             ca: ClauseAllocator::new(),
             db: ClauseDatabase::new(),
             watchlist: WatchList::new(),
+            occurs: OccurList::new(),
+            xors: XorSystem::new(),
+            xor_reasons: VarVec::new(),
+            xor_conflicts: Vec::new(),
             assignment: Assignment::new(),
-            var_order: VarOrder::new(),
+            var_order,
             polarity: VarVec::new(),
             // seen: Vec::new(),
             restart_strategy,
+            restart_blocker,
             learning_guard,
             ok: true,
             next_var: 0,
+            num_dec_vars: 0,
+            comments: Vec::new(),
+            preprocessed: false,
+            eliminated: VarVec::new(),
+            reconstruction: Reconstruction::new(),
+            interrupt: None,
+            progress_callback: None,
             // rng: StdRng::seed_from_u64(42),
             decisions: 0,
             propagations: 0,
             conflicts: 0,
             restarts: 0,
+            blocked_restarts: 0,
             simplifies: 0,
+            simplified_clauses: 0,
             reduces: 0,
+            reduced_learnts: 0,
+            last_reduce: ReduceStats::default(),
             time_search: Duration::new(0, 0),
             time_propagate: Duration::new(0, 0),
             time_analyze: Duration::new(0, 0),
@@ -155,10 +202,20 @@ impl Solver {
             time_restart: Duration::new(0, 0),
             time_simplify: Duration::new(0, 0),
             time_reduce: Duration::new(0, 0),
+            time_preprocess: Duration::new(0, 0),
         }
     }
 }
 
+/// Callback receiving the [progress estimate][Solver::progress_estimate].
+struct ProgressCallback(Box<dyn FnMut(f64) + Send>);
+
+impl Debug for ProgressCallback {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ProgressCallback")
+    }
+}
+
 impl Default for Solver {
     fn default() -> Self {
         Self::new(DEFAULT_OPTIONS)
@@ -183,6 +240,29 @@ impl Solver {
         }
     }
 
+    /// Same as [`init_from_file`][Solver::init_from_file], but also collects
+    /// the comment lines, which are then accessible via [`comments`][Solver::comments].
+    pub fn init_from_file_with_meta<P>(&mut self, path: P)
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        debug!("Initializing solver from '{}' (with comments)", path.display());
+        for line in parse_dimacs_with_comments(path) {
+            match line {
+                DimacsLine::Comment(comment) => self.comments.push(comment),
+                DimacsLine::Clause(clause) => {
+                    self.add_clause(&clause);
+                }
+            }
+        }
+    }
+
+    /// Comments collected by [`init_from_file_with_meta`][Solver::init_from_file_with_meta], in order.
+    pub fn comments(&self) -> &[String] {
+        &self.comments
+    }
+
     /// Number of variables.
     pub fn num_vars(&self) -> usize {
         self.next_var as usize
@@ -194,7 +274,7 @@ impl Solver {
         } else {
             self.assignment.trail_lim[0]
         };
-        self.var_order.num_dec_vars - num_ground_assignments
+        self.num_dec_vars - num_ground_assignments
     }
     /// Number of original clauses.
     pub fn num_clauses(&self) -> usize {
@@ -220,14 +300,67 @@ impl Solver {
     pub fn num_restarts(&self) -> usize {
         self.restarts
     }
+    /// Number of blocked restarts.
+    pub fn num_blocked_restarts(&self) -> usize {
+        self.blocked_restarts
+    }
     /// Number of clause database simplifies.
     pub fn num_simplifies(&self) -> usize {
         self.simplifies
     }
+    /// Total number of satisfied clauses removed by clause database simplifies.
+    pub fn num_simplified_clauses(&self) -> usize {
+        self.simplified_clauses
+    }
+    /// Snapshot of all the counters and timers.
+    pub fn statistics(&self) -> Statistics {
+        Statistics {
+            num_vars: self.num_vars(),
+            num_free_vars: self.num_free_vars(),
+            num_clauses: self.num_clauses(),
+            num_learnts: self.num_learnts(),
+            num_decisions: self.num_decisions(),
+            num_propagations: self.num_propagations(),
+            num_conflicts: self.num_conflicts(),
+            num_restarts: self.num_restarts(),
+            num_blocked_restarts: self.num_blocked_restarts(),
+            num_simplifies: self.num_simplifies(),
+            num_simplified_clauses: self.num_simplified_clauses(),
+            num_reduces: self.num_reduces(),
+            num_reduced_learnts: self.num_reduced_learnts(),
+            num_eliminated: self.eliminations().len(),
+            time_search: self.time_search,
+            time_propagate: self.time_propagate,
+            time_analyze: self.time_analyze,
+            time_backtrack: self.time_backtrack,
+            time_decide: self.time_decide,
+            time_restart: self.time_restart,
+            time_simplify: self.time_simplify,
+            time_reduce: self.time_reduce,
+            time_preprocess: self.time_preprocess,
+        }
+    }
+
+    /// Whether the variable was eliminated during preprocessing.
+    pub fn is_eliminated(&self, var: Var) -> bool {
+        self.eliminated[var]
+    }
+    /// Variable eliminations performed during preprocessing, in order.
+    pub fn eliminations(&self) -> &[Elimination] {
+        self.reconstruction.steps()
+    }
     /// Number of clause database reductions.
     pub fn num_reduces(&self) -> usize {
         self.reduces
     }
+    /// Total number of learnt clauses removed by clause database reductions.
+    pub fn num_reduced_learnts(&self) -> usize {
+        self.reduced_learnts
+    }
+    /// Statistics of the last clause database reduction.
+    pub fn reduce_db_stats(&self) -> ReduceStats {
+        self.last_reduce
+    }
 
     /// Reset the solver state.
     pub fn reset(&mut self) {
@@ -235,6 +368,18 @@ impl Solver {
         *self = Self::new(options);
     }
 
+    /// Reserve capacity for `num_vars` variables in all per-variable structures,
+    /// so that allocating variables up to `num_vars` does not cause reallocations.
+    pub fn reserve(&mut self, num_vars: usize) {
+        self.watchlist.reserve(num_vars);
+        self.occurs.reserve(num_vars);
+        self.assignment.reserve(num_vars);
+        self.polarity.reserve_len(num_vars);
+        self.eliminated.reserve_len(num_vars);
+        self.xor_reasons.reserve_len(num_vars);
+        self.var_order.reserve(num_vars);
+    }
+
     /// Allocate a new variable.
     pub fn new_var(&mut self) -> Var {
         let var = Var::new(self.next_var);
@@ -242,6 +387,7 @@ impl Solver {
 
         // Watch
         self.watchlist.init(var);
+        self.occurs.init(var);
 
         // Assignment
         self.assignment.assignment.push(LBool::Undef);
@@ -252,11 +398,18 @@ impl Solver {
         // Polarity
         self.polarity.push(true); // default phase is "negated=true"
 
+        // Elimination
+        self.eliminated.push(false);
+
+        // XOR reason
+        self.xor_reasons.push(None);
+
         // Seen
         // self.seen.push(false);
 
-        // VSIDS
+        // Decision heuristic
         self.var_order.init_var(var);
+        self.num_dec_vars += 1;
         // self.var_order.push_zero_activity();
         // self.var_order.insert_var_order(var);
 
@@ -294,6 +447,110 @@ impl Solver {
         self.assignment.decision_level()
     }
 
+    /// Rough estimate (in `[0, 1]`) of the explored fraction of the search space,
+    /// as in MiniSat's `progressEstimate`.
+    ///
+    /// Each assigned variable contributes `F^level / n`, where `n` is the number of variables
+    /// and `F = 1/n`, so the estimate is dominated by the root-level assignments,
+    /// and decisions at the deeper levels contribute less than `1/n` in total.
+    pub fn progress_estimate(&self) -> f64 {
+        let n = self.num_vars();
+        if n == 0 {
+            return 0.0;
+        }
+        let f = 1.0 / n as f64;
+        let trail = &self.assignment.trail;
+        let trail_lim = &self.assignment.trail_lim;
+        let mut progress = 0.0;
+        for level in 0..=self.decision_level() {
+            let begin = if level == 0 { 0 } else { trail_lim[level - 1] };
+            let end = trail_lim.get(level).copied().unwrap_or(trail.len());
+            progress += f.powi(level as i32) * (end - begin) as f64;
+        }
+        progress / n as f64
+    }
+
+    /// Extend the `partial` model (over the variables which were not eliminated)
+    /// to the variables eliminated during preprocessing.
+    ///
+    /// The resulting model satisfies the original clauses whenever
+    /// the `partial` model satisfies the preprocessed ones.
+    pub fn extend_model(&self, partial: &mut VarMap<LBool>) {
+        self.reconstruction.extend_model(partial);
+    }
+
+    /// Current model, extended to the eliminated variables.
+    ///
+    /// Should be called right after [`solve`][Solver::solve] returned [`SolveResult::Sat`].
+    pub fn model(&self) -> VarMap<LBool> {
+        let mut model = VarMap::new();
+        for i in 0..self.num_vars() {
+            let var = Var::new(i as u32);
+            if !self.is_eliminated(var) {
+                model.insert(var, self.value_var(var));
+            }
+        }
+        self.extend_model(&mut model);
+        model
+    }
+
+    /// Variables which are "don't cares" in the current (satisfying) assignment.
+    ///
+    /// Candidates are the decision variables whose decision did not imply anything.
+    /// A candidate is reported only when each original clause satisfied by it is also
+    /// satisfied by some other literal, which is not reported, so that all returned
+    /// variables can be dropped from the model simultaneously.
+    ///
+    /// Should be called right after [`solve`][Solver::solve] returned [`SolveResult::Sat`].
+    pub fn free_vars(&self) -> Vec<Var> {
+        let trail = &self.assignment.trail;
+        let trail_lim = &self.assignment.trail_lim;
+
+        // Decisions without implications:
+        let mut candidates = Vec::new();
+        for (level, &start) in trail_lim.iter().enumerate() {
+            let end = trail_lim.get(level + 1).copied().unwrap_or(trail.len());
+            if end - start == 1 {
+                candidates.push(trail[start].var());
+            }
+        }
+        if candidates.is_empty() {
+            return candidates;
+        }
+
+        // For each original clause, the number of literals satisfying it:
+        let clauses = self.clauses_iter().collect_vec();
+        let mut num_true = clauses
+            .iter()
+            .map(|clause| clause.iter().filter(|&&lit| self.value(lit) == LBool::True).count())
+            .collect_vec();
+        let mut occurs: VarVec<Vec<usize>> = VarVec::new();
+        for (i, clause) in clauses.iter().enumerate() {
+            for &lit in clause.iter() {
+                if self.value(lit) == LBool::True {
+                    occurs.init(&lit.var());
+                    occurs[lit.var()].push(i);
+                }
+            }
+        }
+
+        candidates.retain(|&var| {
+            let Some(occ) = occurs.get(&var) else {
+                // Satisfies no clause at all.
+                return true;
+            };
+            if occ.iter().all(|&i| num_true[i] >= 2) {
+                for &i in occ {
+                    num_true[i] -= 1;
+                }
+                true
+            } else {
+                false
+            }
+        });
+        candidates
+    }
+
     pub fn clause(&self, cref: ClauseRef) -> &Clause {
         self.ca.clause(cref)
     }
@@ -337,6 +594,9 @@ impl Solver {
         for _ in (self.num_vars() + 1)..=max_var.to_external() as _ {
             self.new_var();
         }
+        if !self.restore_eliminated(lits) {
+            return false;
+        }
 
         // TODO: handle unit clauses (better)
 
@@ -354,6 +614,54 @@ impl Solver {
         self.ok
     }
 
+    /// Add the XOR clause `lits[0] ^ lits[1] ^ ... = rhs`.
+    ///
+    /// XOR clauses are propagated via Gaussian elimination alongside the plain clauses.
+    ///
+    /// **Returns:**
+    ///
+    /// - `false`, if the solver is in UNSAT state (e.g., the XOR system became inconsistent),
+    /// - `true`, otherwise.
+    pub fn add_xor_clause(&mut self, lits: &[Lit], rhs: bool) -> bool {
+        if !self.ok {
+            return false;
+        }
+
+        if self.decision_level() > 0 {
+            self.backtrack(0);
+        }
+
+        // Auto-create missing variables.
+        if let Some(max_var) = lits.iter().map(|&lit| lit.var()).max() {
+            for _ in (self.num_vars() + 1)..=max_var.to_external() as _ {
+                self.new_var();
+            }
+        }
+        if !self.restore_eliminated(lits) {
+            return false;
+        }
+
+        if !self.xors.add(lits, rhs) {
+            debug!("Inconsistent XOR system");
+            self.ok = false;
+            return false;
+        }
+
+        // Enqueue the units implied by the XOR system alone, so that the reasons
+        // derived during propagation always have at least two literals:
+        for lit in self.xors.units() {
+            if !self.assignment.enqueue(lit, None) {
+                self.ok = false;
+                break;
+            }
+        }
+        self.ok
+    }
+
+    pub fn num_xor_clauses(&self) -> usize {
+        self.xors.len()
+    }
+
     pub fn add_learnt(&mut self, lits: &[Lit]) -> bool {
         assert_eq!(self.decision_level(), 0);
 
@@ -391,13 +699,82 @@ impl Solver {
         self.ok
     }
 
+    /// Export the learnt clauses, e.g. to reuse them in a fresh solver for a related instance
+    /// via [`import_clauses`][Solver::import_clauses].
+    ///
+    /// The root-level assigned literals are exported as unit clauses, the learnts satisfied
+    /// at the root level are skipped, and the root-level falsified literals are removed from the rest.
+    /// If the solver is in UNSAT state, the only exported clause is the empty one.
+    ///
+    /// Note: all exported clauses are implied by the formula (including the XOR constraints),
+    /// as long as all clauses passed to [`add_learnt`][Solver::add_learnt] are implied by it.
+    pub fn export_learnts(&self) -> Vec<Vec<Lit>> {
+        if !self.ok {
+            return vec![vec![]];
+        }
+
+        let root = self.assignment.trail_lim.first().copied().unwrap_or(self.assignment.trail.len());
+        let mut learnts = self.assignment.trail[..root].iter().map(|&lit| vec![lit]).collect_vec();
+        for clause in self.learnts_iter() {
+            if clause.is_deleted() || clause.iter().any(|&lit| self.assignment.fixed(lit) == LBool::True) {
+                continue;
+            }
+            let lits = clause.iter().copied().filter(|&lit| self.assignment.fixed(lit) != LBool::False);
+            learnts.push(lits.collect());
+        }
+        learnts
+    }
+
+    /// Add the given clauses (e.g. exported via [`export_learnts`][Solver::export_learnts])
+    /// as regular clauses, simplifying them w.r.t. the root-level assignment.
+    ///
+    /// **Returns:**
+    ///
+    /// - `false`, if the solver is in UNSAT state,
+    /// - `true`, otherwise.
+    pub fn import_clauses(&mut self, clauses: &[Vec<Lit>]) -> bool {
+        if self.decision_level() > 0 {
+            self.backtrack(0);
+        }
+
+        for clause in clauses.iter() {
+            let value = |lit: Lit| {
+                if lit.var().index() < self.num_vars() {
+                    self.value(lit)
+                } else {
+                    LBool::Undef
+                }
+            };
+            if clause.iter().any(|&lit| value(lit) == LBool::True) {
+                continue;
+            }
+            let lits = clause.iter().copied().filter(|&lit| value(lit) != LBool::False).collect_vec();
+            if !self.add_clause(&lits) {
+                return false;
+            }
+        }
+
+        self.ok
+    }
+
     fn attach_clause(&mut self, cref: ClauseRef) {
         let clause = self.ca.clause(cref);
         debug_assert!(clause.len() >= 2, "Clause must have at least 2 literals");
-        let a = clause[0];
-        let b = clause[1];
-        self.watchlist.insert(a, Watcher { cref, blocker: b });
-        self.watchlist.insert(b, Watcher { cref, blocker: a });
+        match self.options.propagation {
+            Propagation::TwoWatched => {
+                let a = clause[0];
+                let b = clause[1];
+                self.watchlist.insert(a, Watcher { cref, blocker: b });
+                self.watchlist.insert(b, Watcher { cref, blocker: a });
+            }
+            Propagation::Counting => {
+                let num_false = clause
+                    .iter()
+                    .filter(|&&lit| self.value(lit) == LBool::False && self.occurs.is_counted(lit.var()))
+                    .count();
+                self.occurs.insert(clause.lits(), cref, num_false);
+            }
+        }
     }
 
     fn report(&self, stage: &str) {
@@ -417,6 +794,44 @@ impl Solver {
         );
     }
 
+    /// Set the flag which, once raised (e.g. from another thread or a Ctrl-C handler),
+    /// makes the running (and any subsequent) solve stop and return [`SolveResult::Unknown`].
+    ///
+    /// The flag is checked after each conflict and at each restart. It is never cleared
+    /// by the solver, so the caller has to lower it before solving again.
+    pub fn set_interrupt(&mut self, flag: Arc<AtomicBool>) {
+        self.interrupt = Some(flag);
+    }
+
+    /// Set the callback which receives the [progress estimate][Solver::progress_estimate]
+    /// before each restart.
+    pub fn set_progress_callback<F>(&mut self, callback: F)
+    where
+        F: FnMut(f64) + Send + 'static,
+    {
+        self.progress_callback = Some(ProgressCallback(Box::new(callback)));
+    }
+
+    /// Seed the decision heuristic with the occurrence counts of the variables,
+    /// bumping each variable once per (original) clause it appears in.
+    ///
+    /// Call it before solving, so that the first descent prefers the most frequent variables.
+    pub fn seed_activities_from_occurrences(&mut self) {
+        let vars = self
+            .clauses_iter()
+            .filter(|clause| !clause.is_deleted())
+            .flat_map(|clause| clause.iter().map(|lit| lit.var()))
+            .collect_vec();
+        debug!("Seeding activities from {} occurrences", vars.len());
+        for var in vars {
+            self.var_order.bump(var);
+        }
+    }
+
+    fn is_interrupted(&self) -> bool {
+        self.interrupt.as_ref().is_some_and(|flag| flag.load(Ordering::Relaxed))
+    }
+
     pub fn solve(&mut self) -> SolveResult {
         self.solve_under_assumptions(&[])
     }
@@ -432,6 +847,17 @@ impl Solver {
             self.backtrack(0);
         }
 
+        // Preprocess before the first search:
+        if self.options.preprocess && !self.preprocessed {
+            self.preprocessed = true;
+            if !self.preprocess(assumptions) {
+                return SolveResult::Unsat;
+            }
+        }
+        if !self.restore_eliminated(assumptions) {
+            return SolveResult::Unsat;
+        }
+
         // Reset the limits for reduceDB:
         self.learning_guard.reset(self.num_clauses());
 
@@ -451,6 +877,8 @@ impl Solver {
                     status = SolveResult::Sat;
                 }
                 SearchResult::Unsat => {
+                    // Conflict on the root level, so the formula is UNSAT regardless of assumptions:
+                    self.ok = false;
                     status = SolveResult::Unsat;
                 }
                 SearchResult::Restart => {
@@ -471,6 +899,12 @@ impl Solver {
                     // TODO: save the `conflict`
                     status = SolveResult::Unsat;
                 }
+                SearchResult::Interrupted => {
+                    debug!("Interrupted");
+                    self.backtrack(0);
+                    self.time_search += time_search_start.elapsed();
+                    break;
+                }
             }
             let time_search = time_search_start.elapsed();
             self.time_search += time_search;
@@ -501,7 +935,11 @@ impl Solver {
         assert!(self.ok);
         assert_eq!(self.decision_level(), 0);
 
-        let confl_limit = if num_confl > 0 { self.conflicts + num_confl } else { usize::MAX };
+        if self.is_interrupted() {
+            return SearchResult::Interrupted;
+        }
+
+        let mut confl_limit = if num_confl > 0 { self.conflicts + num_confl } else { usize::MAX };
 
         // CDCL loop
         loop {
@@ -513,10 +951,22 @@ impl Solver {
                 return SearchResult::Unsat;
             }
 
+            // Interrupt:
+            if self.is_interrupted() {
+                return SearchResult::Interrupted;
+            }
+
             // Restart:
             if self.conflicts >= confl_limit {
-                self.restart();
-                return SearchResult::Restart;
+                if self.restart_blocker.as_ref().is_some_and(|b| b.should_block()) {
+                    // Postpone the restart:
+                    trace!("Blocked restart");
+                    self.blocked_restarts += 1;
+                    confl_limit = self.conflicts + num_confl;
+                } else {
+                    self.restart();
+                    return SearchResult::Restart;
+                }
             }
 
             // Simplify DB:
@@ -560,6 +1010,9 @@ impl Solver {
     fn propagate_analyze_backtrack(&mut self) -> bool {
         while let Some(conflict) = self.propagate() {
             self.conflicts += 1;
+            if let Some(blocker) = &mut self.restart_blocker {
+                blocker.on_conflict(self.assignment.trail.len());
+            }
 
             if self.decision_level() == 0 {
                 // conflict on root level => UNSAT
@@ -589,16 +1042,90 @@ impl Solver {
                 self.assignment.unchecked_enqueue(asserting_literal, Some(cref));
             }
 
-            self.var_order.var_decay_activity();
+            self.var_order.decay();
             self.db.cla_decay_activity();
             self.learning_guard.bump();
         }
+        self.debug_check_invariants();
         true
     }
 
     pub fn propagate(&mut self) -> Option<ClauseRef> {
         let time_propagate_start = Instant::now();
+        let conflict = loop {
+            let conflict = match self.options.propagation {
+                Propagation::TwoWatched => self.propagate_two_watched(),
+                Propagation::Counting => self.propagate_counting(),
+            };
+            if conflict.is_some() {
+                break conflict;
+            }
+
+            // Propagate the XOR clauses when the plain clauses reach the fixpoint:
+            let trail_len = self.assignment.trail.len();
+            if let Some(conflict) = self.propagate_xors() {
+                break Some(conflict);
+            }
+            if self.assignment.trail.len() == trail_len {
+                break None;
+            }
+        };
+        self.time_propagate += time_propagate_start.elapsed();
+        conflict
+    }
+
+    /// Propagate the XOR clauses via Gaussian elimination.
+    ///
+    /// The implied literals are enqueued without a reason clause, which is built
+    /// by [`explain`][Solver::explain] only when the conflict analysis needs it.
+    /// The falsified clause of a conflict is allocated, but neither stored in the
+    /// clause database nor watched.
+    fn propagate_xors(&mut self) -> Option<ClauseRef> {
+        if self.xors.is_empty() {
+            return None;
+        }
 
+        match self.xors.propagate(&self.assignment) {
+            Ok(implied) => {
+                for (lit, index) in implied {
+                    trace!("Propagated XOR unit {:?} by row {}", lit, index);
+                    self.propagations += 1;
+                    self.assignment.unchecked_enqueue(lit, None);
+                    if self.decision_level() > 0 {
+                        self.xor_reasons[lit.var()] = Some(index);
+                    }
+                }
+                None
+            }
+            Err(index) => {
+                let conflict = self.xors.conflict(index, &self.assignment);
+                let cref = self.ca.alloc(Clause::new(conflict, false));
+                self.xor_conflicts.push(cref);
+                trace!("Found XOR conflict by row {}: {:?} = {:?}", index, cref, self.clause(cref));
+                self.assignment.qhead = self.assignment.trail.len();
+                Some(cref)
+            }
+        }
+    }
+
+    /// The reason clause for `var`, first building it for the literals implied by the XOR clauses.
+    ///
+    /// Note: [`reason`][Solver::reason] returns `None` for such literals until they are explained,
+    ///  so the clause minimization, which does not explain them, conservatively keeps them.
+    fn explain(&mut self, var: Var) -> Option<ClauseRef> {
+        if let Some(index) = self.xor_reasons[var] {
+            if self.reason(var).is_none() {
+                let lit = Lit::new(var, self.value_var(var) == LBool::False);
+                let reason = self.xors.reason(index, lit, &self.assignment);
+                let cref = self.ca.alloc(Clause::new(reason, false));
+                trace!("Explained XOR unit {:?} with reason {:?} = {:?}", lit, cref, self.clause(cref));
+                self.assignment.var_data[var].reason = Some(cref);
+            }
+        }
+        self.reason(var)
+    }
+
+    fn propagate_two_watched(&mut self) -> Option<ClauseRef> {
         let mut conflict = None;
 
         #[inline]
@@ -696,10 +1223,59 @@ impl Solver {
             }
         }
 
-        self.time_propagate += time_propagate_start.elapsed();
         conflict
     }
 
+    /// Counter-based propagation: each clause keeps the number of its falsified literals
+    /// (see [`OccurList`]), and only the clauses whose counter reaches `len - 1` are inspected.
+    /// The clause is a conflict if all its literals are falsified, or unit if the remaining one
+    /// is unassigned, which is then moved to the front of the clause, so that the reason invariant holds.
+    fn propagate_counting(&mut self) -> Option<ClauseRef> {
+        while let Some(p) = self.assignment.dequeue() {
+            debug_assert_eq!(self.level(p.var()), self.decision_level());
+            self.propagations += 1;
+            let false_literal = !p;
+
+            // Note: the counters are updated for all the clauses first, so that they stay
+            //  consistent with the counted literals even when a conflict is found below.
+            self.occurs.count(p, &self.ca);
+
+            for k in 0..self.occurs.occurrences(false_literal).len() {
+                let cref = self.occurs.occurrences(false_literal)[k];
+                let num_false = self.occurs.num_false(cref);
+                let clause = self.ca.clause_mut(cref);
+
+                if num_false + 1 < clause.len() {
+                    // At least two literals are not falsified yet.
+                    continue;
+                }
+                if num_false == clause.len() {
+                    // conflict
+                    trace!("Found conflict: {:?} = {:?}", cref, self.clause(cref));
+                    self.assignment.qhead = self.assignment.trail.len();
+                    return Some(cref);
+                }
+
+                // The only literal not counted as falsified is either satisfied,
+                // or unassigned (unit), or falsified, but not propagated yet:
+                let i = clause
+                    .iter()
+                    .position(|&lit| self.assignment.value(lit) != LBool::False || !self.occurs.is_counted(lit.var()))
+                    .unwrap();
+                if self.assignment.value(clause[i]) == LBool::Undef {
+                    // unit
+                    let lits = &mut clause[..];
+                    lits.swap(0, i);
+                    let first = lits[0];
+                    trace!("Propagated unit {:?} with reason {:?} = {:?}", first, cref, self.clause(cref));
+                    self.assignment.unchecked_enqueue(first, Some(cref));
+                }
+            }
+        }
+
+        None
+    }
+
     /// Returns learnt clause and backtrack level.
     fn analyze(&mut self, conflict: ClauseRef) -> (Vec<Lit>, usize) {
         trace!("Analyze conflict {} at level {}", self.clause(conflict), self.decision_level());
@@ -726,7 +1302,7 @@ impl Solver {
                     seen[q.var()] = true;
 
                     // Bump `q` variable activity:
-                    self.var_order.var_bump_activity(q.var());
+                    self.var_order.bump(q.var());
 
                     if self.level(q.var()) < self.decision_level() {
                         lemma.push(q);
@@ -754,16 +1330,15 @@ impl Solver {
                 lemma.insert(0, !p);
                 break;
             }
-            reason = self.reason(p.var()).unwrap();
+            reason = self.explain(p.var()).unwrap();
             debug_assert_eq!(self.clause(reason)[0], p);
         }
 
         // Save learnt literals for later usage:
-        let analyze_to_clear = lemma.clone();
+        let mut analyze_to_clear = lemma.clone();
 
         // Minimize the learnt clause:
-        // Note: currently, only "local" minimization (i.e. not "recursive") is implemented.
-        lemma.retain(|&lit| !self.lit_redundant_basic(lit, &seen));
+        self.minimize(&mut lemma, &mut seen, &mut analyze_to_clear);
 
         // Clear the `seen` vector:
         for lit in analyze_to_clear {
@@ -796,6 +1371,37 @@ impl Solver {
         (lemma, bt_level)
     }
 
+    /// Minimize the learnt clause (`lemma`) according to [`Options::ccmin_mode`].
+    ///
+    /// The first (asserting) literal is always kept.
+    /// All variables marked as `seen` during the minimization are pushed to `to_clear`.
+    fn minimize(&self, lemma: &mut Vec<Lit>, seen: &mut VarVec<bool>, to_clear: &mut Vec<Lit>) {
+        match self.options.ccmin_mode {
+            0 => {}
+            1 => {
+                let mut i = 0;
+                lemma.retain(|&lit| {
+                    i += 1;
+                    i == 1 || !self.lit_redundant_basic(lit, seen)
+                });
+            }
+            2 => {
+                // Abstraction of the decision levels present in the lemma:
+                let abstract_levels = lemma[1..].iter().fold(0, |acc, lit| acc | self.abstract_level(lit.var()));
+                let mut i = 0;
+                lemma.retain(|&lit| {
+                    i += 1;
+                    i == 1 || self.reason(lit.var()).is_none() || !self.lit_redundant_recursive(lit, abstract_levels, seen, to_clear)
+                });
+            }
+            mode => panic!("Bad ccmin_mode: {}", mode),
+        }
+    }
+
+    fn abstract_level(&self, var: Var) -> u32 {
+        1 << (self.level(var) & 31)
+    }
+
     fn lit_redundant_basic(&self, lit: Lit, seen: &VarVec<bool>) -> bool {
         match self.reason(lit.var()) {
             None => false,
@@ -813,6 +1419,35 @@ impl Solver {
         }
     }
 
+    /// Check whether `lit` is implied by the other literals of the learnt clause,
+    /// recursively following the reasons (MiniSat's `litRedundant`).
+    fn lit_redundant_recursive(&self, lit: Lit, abstract_levels: u32, seen: &mut VarVec<bool>, to_clear: &mut Vec<Lit>) -> bool {
+        let top = to_clear.len();
+        let mut stack = vec![lit];
+        while let Some(p) = stack.pop() {
+            let cref = self.reason(p.var()).unwrap();
+            let clause = self.ca.clause(cref);
+            debug_assert_eq!(clause[0], !p);
+            for &q in &clause[1..] {
+                let v = q.var();
+                if !seen[v] && self.level(v) > 0 {
+                    if self.reason(v).is_some() && (self.abstract_level(v) & abstract_levels) != 0 {
+                        seen[v] = true;
+                        stack.push(q);
+                        to_clear.push(q);
+                    } else {
+                        // Cannot be removed, undo the marks:
+                        for x in to_clear.drain(top..) {
+                            seen[x.var()] = false;
+                        }
+                        return false;
+                    }
+                }
+            }
+        }
+        true
+    }
+
     fn backtrack(&mut self, level: usize) {
         trace!("Backtrack from {} to {}", self.decision_level(), level);
 
@@ -823,17 +1458,29 @@ impl Solver {
                 let var = self.assignment.trail[i].var();
                 // Phase saving:
                 self.polarity[var] = !self.assignment.value_var(var).bool().expect("must be assigned");
+                // Free the reason built for the XOR implication:
+                if self.xor_reasons[var].take().is_some() {
+                    if let Some(cref) = self.assignment.var_data[var].reason.take() {
+                        self.ca.free(cref);
+                    }
+                }
+                // Uncount the variable (for counting propagation):
+                self.occurs.uncount(self.assignment.trail[i]);
                 // Unassign the variable:
                 self.assignment.unassign(var);
                 // Put the variable into the order heap:
-                self.var_order.insert_var_order(var);
+                self.var_order.on_unassign(var);
             }
             self.assignment.qhead = self.assignment.trail_lim[level];
             self.assignment.trail.truncate(self.assignment.trail_lim[level]);
             self.assignment.trail_lim.truncate(level);
+            for cref in self.xor_conflicts.drain(..) {
+                self.ca.free(cref);
+            }
         }
 
         self.time_backtrack += time_backtrack_start.elapsed();
+        self.debug_check_invariants();
     }
 
     fn analyze_final(&mut self, p: Lit) -> Vec<Lit> {
@@ -848,10 +1495,11 @@ impl Solver {
         let mut seen = VarVec::from(vec![false; self.num_vars()]);
         seen[p.var()] = true;
 
-        for &lit in self.assignment.trail[self.assignment.trail_lim[0]..].iter().rev() {
+        for i in (self.assignment.trail_lim[0]..self.assignment.trail.len()).rev() {
+            let lit = self.assignment.trail[i];
             let var = lit.var();
             if seen[var] {
-                if let Some(reason) = self.reason(var) {
+                if let Some(reason) = self.explain(var) {
                     let reason = self.clause(reason);
                     assert_eq!(reason[0], lit);
                     for c in &reason[1..] {
@@ -888,10 +1536,11 @@ impl Solver {
             seen[p.var()] = true;
         }
 
-        for &lit in self.assignment.trail[self.assignment.trail_lim[0]..].iter().rev() {
+        for i in (self.assignment.trail_lim[0]..self.assignment.trail.len()).rev() {
+            let lit = self.assignment.trail[i];
             let var = lit.var();
             if seen[var] {
-                if let Some(reason) = self.reason(var) {
+                if let Some(reason) = self.explain(var) {
                     let reason = self.clause(reason);
                     assert_eq!(reason[0], lit);
                     for c in &reason[1..] {
@@ -955,7 +1604,7 @@ impl Solver {
     }
 
     fn pick_branching_variable(&mut self) -> Option<Var> {
-        self.var_order.pick_branching_variable(&self.assignment)
+        self.var_order.select(&self.assignment)
     }
 
     fn pick_phase(&mut self, var: Var) -> Lit {
@@ -971,6 +1620,10 @@ impl Solver {
         let time_restart_start = Instant::now();
         self.restarts += 1;
         self.report("restart");
+        let estimate = self.progress_estimate();
+        if let Some(callback) = &mut self.progress_callback {
+            (callback.0)(estimate);
+        }
         self.backtrack(0);
         self.time_restart += time_restart_start.elapsed();
     }
@@ -980,7 +1633,7 @@ impl Solver {
         let time_simplify_start = Instant::now();
         self.simplifies += 1;
         assert_eq!(self.decision_level(), 0);
-        self.db.simplify(&self.assignment, &mut self.ca);
+        self.simplified_clauses += self.db.simplify(&self.assignment, &mut self.ca);
         self.time_simplify += time_simplify_start.elapsed();
     }
 
@@ -988,9 +1641,200 @@ impl Solver {
         let time_reduce_start = Instant::now();
         self.reduces += 1;
         self.report("reduce");
-        self.db.reduce(&self.assignment, &mut self.ca);
+        let stats = self.db.reduce(&self.assignment, &mut self.ca);
+        debug!(
+            "Reduce: removed {}, kept {}, activity threshold {}",
+            stats.removed, stats.kept, stats.activity_threshold
+        );
+        self.reduced_learnts += stats.removed;
+        self.last_reduce = stats;
         self.time_reduce += time_reduce_start.elapsed();
     }
+
+    /// Run bounded variable elimination on the original clauses.
+    ///
+    /// The variables from `assumptions` are not eliminated.
+    /// Values of the eliminated variables in the model are arbitrary, use [`Solver::extend_model`] to fix them.
+    /// Eliminated variables occurring in new clauses and assumptions are re-introduced, see [`Solver::restore_eliminated`].
+    ///
+    /// **Returns:**
+    ///
+    /// - `false`, if the formula was found to be unsatisfiable,
+    /// - `true`, otherwise.
+    fn preprocess(&mut self, assumptions: &[Lit]) -> bool {
+        assert_eq!(self.decision_level(), 0);
+
+        // Note: learnt clauses may contain the eliminated variables, so we do not preprocess in their presence.
+        if !self.ok || self.num_learnts() > 0 {
+            return self.ok;
+        }
+
+        let time_preprocess_start = Instant::now();
+
+        // Simplify the clauses using the root-level assignment:
+        let clauses = self
+            .clauses_iter()
+            .filter(|clause| !clause.is_deleted() && clause.contains_fixed_literal(&self.assignment) != LBool::True)
+            .map(|clause| clause.iter().copied().filter(|&lit| self.value(lit) != LBool::False).collect_vec())
+            .collect_vec();
+        let frozen = assumptions.iter().map(|lit| lit.var()).chain(self.xors.vars()).collect_vec();
+        let (clauses, eliminations) = eliminate_variables(&clauses, self.num_vars(), &frozen, 0);
+        debug!("Eliminated {} variables", eliminations.len());
+
+        if !eliminations.is_empty() {
+            self.db.clear_clauses(&mut self.ca);
+            for elimination in eliminations {
+                self.eliminated[elimination.var] = true;
+                self.reconstruction.push(elimination);
+            }
+            for clause in clauses {
+                if !self.add_clause(&clause) {
+                    break;
+                }
+            }
+        }
+
+        self.time_preprocess += time_preprocess_start.elapsed();
+        self.ok
+    }
+
+    /// Re-introduce the eliminated variables occurring in `lits`.
+    ///
+    /// The elimination steps are undone in reverse order, adding back the removed clauses,
+    /// until none of the variables of `lits` is eliminated. Undoing the later steps first
+    /// guarantees that the restored clauses contain no eliminated variables.
+    ///
+    /// **Returns:**
+    ///
+    /// - `false`, if the solver is in UNSAT state,
+    /// - `true`, otherwise.
+    fn restore_eliminated(&mut self, lits: &[Lit]) -> bool {
+        assert_eq!(self.decision_level(), 0);
+
+        while lits.iter().any(|&lit| self.eliminated[lit.var()]) {
+            let Elimination { var, clauses } = self.reconstruction.pop().unwrap();
+            debug!("Re-introducing eliminated {}", var);
+            self.eliminated[var] = false;
+            for clause in clauses {
+                // Simplify the clause using the root-level assignment, as in `preprocess`:
+                if clause.iter().any(|&lit| self.value(lit) == LBool::True) {
+                    continue;
+                }
+                let clause = clause.into_iter().filter(|&lit| self.value(lit) != LBool::False).collect_vec();
+                if !self.add_clause(&clause) {
+                    return false;
+                }
+            }
+        }
+        self.ok
+    }
+
+    fn debug_check_invariants(&self) {
+        if cfg!(debug_assertions) && self.options.check_invariants {
+            self.check_invariants();
+        }
+    }
+
+    /// Check the consistency of the trail, reasons, watches and the decision heap, panicking on violation.
+    ///
+    /// The watch invariant is only checked when the propagation is complete,
+    /// that is, all the literals on the trail have been propagated:
+    /// each falsified watched literal must be preceded by some satisfied literal
+    /// of the same clause, assigned on the same or a lower level.
+    ///
+    /// Called after each propagation and backtrack when [`Options::check_invariants`] is set (in debug builds).
+    pub fn check_invariants(&self) {
+        let trail = &self.assignment.trail;
+        let trail_lim = &self.assignment.trail_lim;
+
+        // Trail:
+        assert!(self.assignment.qhead <= trail.len(), "qhead is out of the trail");
+        assert!(trail_lim.windows(2).all(|w| w[0] <= w[1]), "trail_lim is not sorted");
+        assert!(trail_lim.iter().all(|&lim| lim <= trail.len()), "trail_lim is out of the trail");
+        let mut on_trail = VarVec::from(vec![false; self.num_vars()]);
+        for (i, &lit) in trail.iter().enumerate() {
+            let var = lit.var();
+            assert!(!on_trail[var], "{} is on the trail twice", var);
+            on_trail[var] = true;
+            assert_eq!(self.value(lit), LBool::True, "{} is on the trail, but not satisfied", lit);
+            let level = trail_lim.partition_point(|&lim| lim <= i);
+            assert_eq!(self.level(var), level, "{} has a wrong level", lit);
+
+            // Reason:
+            if let Some(cref) = self.reason(var) {
+                let clause = self.clause(cref);
+                if level > 0 {
+                    assert!(!clause.is_deleted(), "Reason {} for {} is deleted", clause, lit);
+                }
+                if !clause.is_deleted() {
+                    assert_eq!(clause[0], lit, "Reason {} for {} must start with it", clause, lit);
+                    for &q in &clause[1..] {
+                        assert_eq!(self.value(q), LBool::False, "Reason {} for {} is not unit", clause, lit);
+                        assert!(self.level(q.var()) <= level, "Reason {} for {} has a later literal", clause, lit);
+                    }
+                }
+            }
+        }
+        for var in (0..self.num_vars()).map(|i| Var::new(i as u32)) {
+            assert_eq!(
+                on_trail[var],
+                self.value_var(var) != LBool::Undef,
+                "{} is assigned, but not on the trail",
+                var
+            );
+        }
+
+        // Watches (or occurrences, for counting propagation):
+        let propagated = self.assignment.qhead == trail.len();
+        for &cref in self.db.clauses().iter().chain(self.db.learnts()) {
+            let clause = self.clause(cref);
+            if clause.is_deleted() || clause.len() < 2 {
+                continue;
+            }
+            match self.options.propagation {
+                Propagation::TwoWatched => {
+                    for &w in &clause[..2] {
+                        let watchers = self.watchlist.watchers(w);
+                        assert!(
+                            watchers.iter().any(|watcher| watcher.cref == cref),
+                            "{} is not watched by {}",
+                            clause,
+                            w
+                        );
+                        if propagated && self.value(w) == LBool::False {
+                            let level = self.level(w.var());
+                            assert!(
+                                clause
+                                    .iter()
+                                    .any(|&lit| self.value(lit) == LBool::True && self.level(lit.var()) <= level),
+                                "{} has a falsified watch {}, but is not satisfied",
+                                clause,
+                                w
+                            );
+                        }
+                    }
+                }
+                Propagation::Counting => {
+                    for &lit in clause.iter() {
+                        let occurs = self.occurs.occurrences(lit);
+                        assert!(occurs.contains(&cref), "{} is not in the occurrence list of {}", clause, lit);
+                    }
+                    let num_false = clause
+                        .iter()
+                        .filter(|&&lit| self.value(lit) == LBool::False && self.occurs.is_counted(lit.var()))
+                        .count();
+                    assert_eq!(self.occurs.num_false(cref), num_false, "{} has a wrong counter", clause);
+                    if propagated && !clause.iter().any(|&lit| self.value(lit) == LBool::True) {
+                        let num_unassigned = clause.iter().filter(|&&lit| self.value(lit) == LBool::Undef).count();
+                        assert!(num_unassigned >= 2, "{} is falsified or unit, but not satisfied", clause);
+                    }
+                }
+            }
+        }
+
+        // Decision heap:
+        self.var_order.check();
+    }
 }
 
 impl Solver {
@@ -1285,7 +2129,7 @@ impl Solver {
                                     self.db.cla_bump_activity(cref, &mut self.ca);
                                 }
 
-                                self.var_order.var_decay_activity();
+                                self.var_order.decay();
                                 self.db.cla_decay_activity();
                                 self.learning_guard.bump();
                             } else {
@@ -1325,7 +2169,7 @@ impl Solver {
                 out_learnts.push(lemma);
             }
 
-            self.var_order.var_decay_activity();
+            self.var_order.decay();
             self.db.cla_decay_activity();
             self.learning_guard.bump();
 
@@ -1553,10 +2397,19 @@ impl Solver {
 mod tests {
     use crate::trie::build_trie;
 
+    use sat_nexus_core::cnf::Cnf;
+    use sat_nexus_core::utils::{pigeonhole, random_3sat};
     use test_log::test;
 
     use super::*;
 
+    /// Clauses of the `cnf` over the external (DIMACS) literals.
+    fn external_clauses(cnf: &Cnf) -> Vec<Vec<i32>> {
+        cnf.iter()
+            .map(|clause| clause.iter().map(|lit| lit.get()).collect_vec())
+            .collect_vec()
+    }
+
     #[test]
     fn test_correctness() {
         let mut solver = Solver::default();
@@ -1593,6 +2446,24 @@ mod tests {
         assert_eq!(res, SolveResult::Unsat);
     }
 
+    #[test]
+    fn test_unsat_is_sticky() {
+        let mut solver = Solver::default();
+        // (1 or 2) and (1 or -2) and (-1 or 3) and (-1 or -3) has no units,
+        // so the conflict on the root level is only found during the search:
+        solver.add_clause_external([1, 2]);
+        solver.add_clause_external([1, -2]);
+        solver.add_clause_external([-1, 3]);
+        solver.add_clause_external([-1, -3]);
+        assert_eq!(solver.solve(), SolveResult::Unsat);
+
+        // The formula stays UNSAT regardless of the later assumptions and clauses:
+        assert_eq!(solver.solve(), SolveResult::Unsat);
+        assert_eq!(solver.solve_under_assumptions_external([2, 3]), SolveResult::Unsat);
+        assert!(!solver.add_clause(&[Lit::from_external(4), Lit::from_external(5)]));
+        assert_eq!(solver.solve(), SolveResult::Unsat);
+    }
+
     #[test]
     fn test_auto_create_variables() {
         let mut solver = Solver::default();
@@ -1610,6 +2481,189 @@ mod tests {
         assert_eq!(solver.value(Lit::from_external(2)), LBool::True);
     }
 
+    fn analyze_crafted_conflict(ccmin_mode: u32) -> Vec<Lit> {
+        let mut solver = Solver::new(Options {
+            ccmin_mode,
+            ..DEFAULT_OPTIONS
+        });
+
+        let a = solver.new_lit();
+        let b = solver.new_lit();
+        let c = solver.new_lit();
+        let d = solver.new_lit();
+        let e = solver.new_lit();
+
+        // Level 1: a -> b -> c
+        solver.add_clause(&[-a, b]);
+        solver.add_clause(&[-b, c]);
+        // Level 2: d & c -> e, d & a -> ~e
+        solver.add_clause(&[-d, -c, e]);
+        solver.add_clause(&[-d, -a, -e]);
+
+        solver.assignment.new_decision_level();
+        solver.assignment.unchecked_enqueue(a, None);
+        assert_eq!(solver.propagate(), None);
+        solver.assignment.new_decision_level();
+        solver.assignment.unchecked_enqueue(d, None);
+        let conflict = solver.propagate().unwrap();
+
+        let (lemma, bt_level) = solver.analyze(conflict);
+        assert_eq!(lemma[0], -d);
+        assert_eq!(bt_level, 1);
+        lemma
+    }
+
+    #[test]
+    fn test_ccmin_mode() {
+        let lemma0 = analyze_crafted_conflict(0);
+        let lemma1 = analyze_crafted_conflict(1);
+        let lemma2 = analyze_crafted_conflict(2);
+        info!("lemma0 = {:?}", lemma0);
+        info!("lemma1 = {:?}", lemma1);
+        info!("lemma2 = {:?}", lemma2);
+
+        // Literal `~c` is only redundant through its reason `b`, which is not in the lemma:
+        assert_eq!(lemma0.len(), 3);
+        assert_eq!(lemma1.len(), 3);
+        assert_eq!(lemma2.len(), 2);
+        assert!(lemma2.iter().all(|lit| lemma0.contains(lit)));
+    }
+
+    #[test]
+    fn test_ccmin_mode_correctness() {
+        // Pigeonhole principle: SAT when pigeons <= holes, UNSAT otherwise.
+        for (pigeons, expected) in [(4, SolveResult::Sat), (5, SolveResult::Unsat)] {
+            let clauses = external_clauses(&pigeonhole(pigeons, 4));
+
+            for ccmin_mode in 0..=2 {
+                let mut solver = Solver::new(Options {
+                    ccmin_mode,
+                    ..DEFAULT_OPTIONS
+                });
+                for clause in clauses.iter() {
+                    solver.add_clause_external(clause.iter().copied());
+                }
+                let res = solver.solve();
+                assert_eq!(res, expected);
+                if res == SolveResult::Sat {
+                    for clause in clauses.iter() {
+                        assert!(clause.iter().any(|&lit| solver.value(Lit::from_external(lit)) == LBool::True));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_restart_blocking() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        // Random 3-SAT instance, slightly below the threshold:
+        let mut rng = StdRng::seed_from_u64(42);
+        let clauses = external_clauses(&random_3sat(200, 800, &mut rng));
+
+        let solve = |restart_blocking: Option<f64>| {
+            let mut solver = Solver::new(Options {
+                restart_init: 10,
+                restart_blocking,
+                ..DEFAULT_OPTIONS
+            });
+            for clause in clauses.iter() {
+                solver.add_clause_external(clause.iter().copied());
+            }
+            let res = solver.solve();
+            info!(
+                "blocking = {:?}: {:?}, conflicts = {}, restarts = {}, blocked = {}",
+                restart_blocking,
+                res,
+                solver.num_conflicts(),
+                solver.num_restarts(),
+                solver.num_blocked_restarts()
+            );
+            (res, solver.num_restarts(), solver.num_blocked_restarts())
+        };
+
+        let (res_plain, restarts_plain, blocked_plain) = solve(None);
+        let (res_blocking, restarts_blocking, blocked_blocking) = solve(Some(1.0));
+        assert_eq!(res_plain, res_blocking);
+        assert_eq!(blocked_plain, 0);
+        assert!(blocked_blocking > 0);
+        assert!(restarts_blocking < restarts_plain);
+    }
+
+    #[test]
+    fn test_reserve() {
+        let mut solver = Solver::default();
+        let n = 1000;
+        solver.reserve(n);
+
+        let capacities = |solver: &Solver| {
+            [
+                solver.watchlist.capacity(),
+                solver.assignment.capacity(),
+                solver.polarity.capacity(),
+                solver.var_order.capacity(),
+            ]
+        };
+        let before = capacities(&solver);
+        assert!(before[0] >= 2 * n);
+        assert!(before[1..].iter().all(|&c| c >= n));
+
+        for i in 1..(n as i32) {
+            solver.add_clause_external([-i, i + 1]);
+        }
+        assert_eq!(solver.num_vars(), n);
+        assert_eq!(capacities(&solver), before);
+    }
+
+    #[test]
+    fn test_free_vars() {
+        let mut solver = Solver::default();
+
+        let x = solver.new_lit();
+        let y = solver.new_lit();
+        let z = solver.new_lit();
+        let free = solver.new_lit();
+
+        // x <=> y, (x or z)
+        solver.add_clause(&[-x, y]);
+        solver.add_clause(&[x, -y]);
+        solver.add_clause(&[x, z]);
+        // `free` only occurs in clauses that are also satisfied by `y` or `z`:
+        solver.add_clause(&[free, y, z]);
+        solver.add_clause(&[-free, y, z]);
+
+        let res = solver.solve();
+        assert_eq!(res, SolveResult::Sat);
+
+        let free_vars = solver.free_vars();
+        info!("free vars: {:?}", free_vars);
+        assert!(free_vars.contains(&free.var()));
+
+        // Variables in the reported set must be jointly droppable:
+        for clause in solver.clauses_iter() {
+            assert!(clause
+                .iter()
+                .any(|&lit| !free_vars.contains(&lit.var()) && solver.value(lit) == LBool::True));
+        }
+    }
+
+    #[test]
+    fn test_init_from_file_with_meta() {
+        let path = std::env::temp_dir().join(format!("simple-sat-test-comments-{}.cnf", std::process::id()));
+        std::fs::write(&path, "c x1 = tie\nc x2 = shirt\np cnf 2 3\n-1 2 0\nc middle\n1 2 0\n-1 -2 0\nc\n").unwrap();
+
+        let mut solver = Solver::default();
+        solver.init_from_file_with_meta(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(solver.comments(), ["x1 = tie", "x2 = shirt", "middle", ""]);
+        assert_eq!(solver.num_vars(), 2);
+        assert_eq!(solver.num_clauses(), 3);
+        assert_eq!(solver.solve(), SolveResult::Sat);
+    }
+
     #[test]
     fn test_propcheck() {
         let mut solver = Solver::default();
@@ -1656,6 +2710,535 @@ mod tests {
         assert_eq!(count, count_trie);
     }
 
+    #[test]
+    fn test_interrupt() {
+        let mut solver = Solver::new(Options {
+            check_invariants: true,
+            ..DEFAULT_OPTIONS
+        });
+        // Pigeonhole principle (10 pigeons, 9 holes), which is hard enough to not be solved quickly:
+        let holes = 9;
+        for clause in external_clauses(&pigeonhole(holes + 1, holes)) {
+            solver.add_clause_external(clause);
+        }
+
+        // Raise the flag on the first restart (reported via the progress callback),
+        // so that the solving is interrupted right after it, independently of timing:
+        let flag = Arc::new(AtomicBool::new(false));
+        solver.set_interrupt(flag.clone());
+        solver.set_progress_callback({
+            let flag = flag.clone();
+            move |_| flag.store(true, Ordering::Relaxed)
+        });
+        assert_eq!(solver.solve(), SolveResult::Unknown);
+        assert_eq!(solver.num_restarts(), 1);
+        assert!(solver.num_conflicts() > 0);
+        assert_eq!(solver.decision_level(), 0);
+        solver.check_invariants();
+
+        // While the flag is raised, solving stops immediately:
+        let conflicts = solver.num_conflicts();
+        assert_eq!(solver.solve(), SolveResult::Unknown);
+        assert_eq!(solver.num_conflicts(), conflicts);
+
+        // After lowering the flag, the solver is still usable:
+        flag.store(false, Ordering::Relaxed);
+        // The first pigeon sits in no hole:
+        let assumptions = (1..=holes as i32).map(|v| -v).collect_vec();
+        assert_eq!(solver.solve_under_assumptions_external(assumptions), SolveResult::Unsat);
+    }
+
+    #[test]
+    fn test_check_invariants() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for (num_vars, num_clauses) in [(20, 80), (50, 210), (100, 430), (150, 640)] {
+            let mut solver = Solver::new(Options {
+                restart_init: 10,
+                min_learnts_limit: 10,
+                check_invariants: true,
+                ..DEFAULT_OPTIONS
+            });
+            let clauses = external_clauses(&random_3sat(num_vars, num_clauses, &mut rng));
+            for clause in clauses.iter() {
+                solver.add_clause_external(clause.iter().copied());
+            }
+            let res = solver.solve();
+            info!("{} vars, {} clauses: {:?}", num_vars, num_clauses, res);
+            if res == SolveResult::Sat {
+                solver.check_invariants();
+
+                // Incremental solving under assumptions:
+                let assumptions = (1..=5)
+                    .map(|v| Lit::from_external(if rng.gen_bool(0.5) { v } else { -v }))
+                    .collect_vec();
+                let res = solver.solve_under_assumptions(&assumptions);
+                info!("under assumptions: {:?}", res);
+                solver.check_invariants();
+            }
+        }
+    }
+
+    #[test]
+    fn test_simplify_frees_satisfied_clauses() {
+        let mut solver = Solver::default();
+        solver.add_clause_external([1, 2]);
+        solver.add_clause_external([1, -3]);
+        solver.add_clause_external([2, 3, 4]);
+        solver.add_clause_external([-2, 4, 5]);
+        assert_eq!(solver.ca.len(), 4);
+
+        // Fix 1 and simplify at the root level, which removes the two clauses containing 1:
+        solver.add_clause_external([1]);
+        assert!(solver.propagate().is_none());
+        solver.simplify();
+        assert_eq!(solver.ca.len(), solver.ca.iter().count());
+        assert_eq!(solver.ca.len(), 2);
+        assert_eq!(solver.ca.capacity() - solver.ca.len(), 2);
+        assert_eq!(solver.num_simplified_clauses(), 2);
+    }
+
+    #[test]
+    fn test_reduce_db_stats() {
+        let mut solver = Solver::new(Options {
+            min_learnts_limit: 20,
+            learntsize_factor: 0.1,
+            ..DEFAULT_OPTIONS
+        });
+        // Pigeonhole principle (7 pigeons, 6 holes) is hard enough to trigger reductions:
+        for clause in external_clauses(&pigeonhole(7, 6)) {
+            solver.add_clause_external(clause);
+        }
+        assert_eq!(solver.reduce_db_stats(), ReduceStats::default());
+
+        let res = solver.solve();
+        assert_eq!(res, SolveResult::Unsat);
+
+        let stats = solver.reduce_db_stats();
+        info!(
+            "reduces = {}, reduced learnts = {}, last = {:?}",
+            solver.num_reduces(),
+            solver.num_reduced_learnts(),
+            stats
+        );
+        assert!(solver.num_reduces() > 1);
+        assert!(solver.num_reduced_learnts() > 0);
+        assert!(solver.num_reduced_learnts() >= stats.removed);
+        assert!(stats.activity_threshold >= 0.0);
+        // Without preprocessing, the clauses are freed only by reductions and simplifies:
+        assert_eq!(
+            solver.ca.capacity() - solver.ca.len(),
+            solver.num_reduced_learnts() + solver.num_simplified_clauses()
+        );
+    }
+
+    #[test]
+    fn test_heuristics() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        use crate::var_order::HeuristicKind;
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let clauses = external_clauses(&random_3sat(50, 200, &mut rng));
+
+        let solve = |heuristic: HeuristicKind| {
+            let mut solver = Solver::new(Options {
+                heuristic,
+                check_invariants: true,
+                ..DEFAULT_OPTIONS
+            });
+            for clause in clauses.iter() {
+                solver.add_clause_external(clause.iter().copied());
+            }
+            let res = solver.solve();
+            assert_eq!(res, SolveResult::Sat);
+            for clause in clauses.iter() {
+                assert!(clause.iter().any(|&lit| solver.value(Lit::from_external(lit)) == LBool::True));
+            }
+            // Decisions of the final search, in order:
+            solver
+                .assignment
+                .trail_lim
+                .iter()
+                .map(|&i| solver.assignment.trail[i])
+                .collect_vec()
+        };
+
+        let decisions_vsids = solve(HeuristicKind::Vsids);
+        let decisions_random = solve(HeuristicKind::Random);
+        info!("decisions (vsids) = {}", DisplaySlice(&decisions_vsids));
+        info!("decisions (random) = {}", DisplaySlice(&decisions_random));
+        assert_ne!(decisions_vsids, decisions_random);
+
+        // Both heuristics also prove UNSAT of pigeonhole principle (5 pigeons, 4 holes):
+        for heuristic in [HeuristicKind::Vsids, HeuristicKind::Random] {
+            let mut solver = Solver::new(Options {
+                heuristic,
+                ..DEFAULT_OPTIONS
+            });
+            for clause in external_clauses(&pigeonhole(5, 4)) {
+                solver.add_clause_external(clause);
+            }
+            assert_eq!(solver.solve(), SolveResult::Unsat);
+        }
+    }
+
+    #[test]
+    fn test_propagation_counting() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        use crate::watch::Propagation;
+
+        let new_solver = |propagation: Propagation, clauses: &[Vec<i32>]| {
+            let mut solver = Solver::new(Options {
+                propagation,
+                check_invariants: true,
+                ..DEFAULT_OPTIONS
+            });
+            for clause in clauses.iter() {
+                solver.add_clause_external(clause.iter().copied());
+            }
+            solver
+        };
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let num_vars = 30;
+        let mut num_sat = 0;
+        let mut num_unsat = 0;
+        for num_clauses in [60, 90, 120, 130, 140, 150, 160, 200] {
+            let clauses = external_clauses(&random_3sat(num_vars as usize, num_clauses, &mut rng));
+            let mut watched = new_solver(Propagation::TwoWatched, &clauses);
+            let mut counting = new_solver(Propagation::Counting, &clauses);
+
+            // The implied assignments (as sets) and the conflicts coincide:
+            for _ in 0..20 {
+                let assumptions = (0..3)
+                    .map(|_| Lit::from_external(rng.gen_range(1..=num_vars) * if rng.gen_bool(0.5) { 1 } else { -1 }))
+                    .unique_by(|lit| lit.var())
+                    .collect_vec();
+                let (ok_watched, propagated_watched) = watched.propcheck_save_propagated(&assumptions);
+                let (ok_counting, propagated_counting) = counting.propcheck_save_propagated(&assumptions);
+                assert_eq!(ok_watched, ok_counting);
+                if ok_watched {
+                    assert_eq!(
+                        propagated_watched.iter().sorted_by_key(|lit| lit.inner()).collect_vec(),
+                        propagated_counting.iter().sorted_by_key(|lit| lit.inner()).collect_vec()
+                    );
+                }
+            }
+
+            let res_watched = watched.solve();
+            let res_counting = counting.solve();
+            assert_eq!(res_watched, res_counting);
+            if res_counting == SolveResult::Sat {
+                num_sat += 1;
+                // Note: the order of propagations differs, and so does the search,
+                //  thus the models are not necessarily the same, but both must be valid.
+                for solver in [&watched, &counting] {
+                    for clause in clauses.iter() {
+                        assert!(clause.iter().any(|&lit| solver.value(Lit::from_external(lit)) == LBool::True));
+                    }
+                }
+            } else {
+                num_unsat += 1;
+            }
+        }
+        assert!(num_sat > 0);
+        assert!(num_unsat > 0);
+    }
+
+    #[test]
+    fn test_xor_linear_system() {
+        let xors = [(vec![1, 2], true), (vec![2, 3], true), (vec![3, 4], false), (vec![1, 3, 4], true)];
+        let new_solver = || {
+            let mut solver = Solver::default();
+            for (lits, rhs) in xors.iter() {
+                let lits = lits.iter().map(|&lit| Lit::from_external(lit)).collect_vec();
+                assert!(solver.add_xor_clause(&lits, *rhs));
+            }
+            solver
+        };
+
+        // The unique solution is x1 = 1, x2 = 0, x3 = 1, x4 = 1:
+        let mut solver = new_solver();
+        assert_eq!(solver.num_xor_clauses(), 4);
+        assert_eq!(solver.solve(), SolveResult::Sat);
+        let model = (1..=4).map(|v| solver.value(Lit::from_external(v))).collect_vec();
+        assert_eq!(model, vec![LBool::True, LBool::False, LBool::True, LBool::True]);
+
+        // Flipping any variable makes the system UNSAT:
+        for lit in [-1, 2, -3, -4] {
+            assert_eq!(solver.solve_under_assumptions(&[Lit::from_external(lit)]), SolveResult::Unsat);
+        }
+
+        // Inconsistent system: (x1 ^ x2) ^ (x2 ^ x3) ^ (x1 ^ x3) = 1 ^ 1 ^ 1
+        let mut solver = Solver::default();
+        assert!(solver.add_xor_clause(&[Lit::from_external(1), Lit::from_external(2)], true));
+        assert!(solver.add_xor_clause(&[Lit::from_external(2), Lit::from_external(3)], true));
+        assert!(!solver.add_xor_clause(&[Lit::from_external(1), Lit::from_external(3)], true));
+        assert_eq!(solver.solve(), SolveResult::Unsat);
+
+        // Inconsistent only together with the clauses: x2 = x4, but x2 = 0 and x4 = 1.
+        let mut solver = new_solver();
+        solver.add_clause_external([2, 4]);
+        assert_eq!(solver.solve(), SolveResult::Sat);
+        let mut solver = Solver::default();
+        solver.add_xor_clause(&[Lit::from_external(1), Lit::from_external(2)], true);
+        solver.add_xor_clause(&[Lit::from_external(2), Lit::from_external(3)], true);
+        solver.add_xor_clause(&[Lit::from_external(3), Lit::from_external(4)], false);
+        solver.add_clause_external([1, 2]);
+        solver.add_clause_external([-1, -4]);
+        solver.add_clause_external([1, -4]);
+        solver.add_clause_external([-2, 4]);
+        assert_eq!(solver.solve(), SolveResult::Unsat);
+    }
+
+    #[test]
+    fn test_xor_random() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        use crate::watch::Propagation;
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let num_vars = 30;
+        let mut num_sat = 0;
+        let mut num_unsat = 0;
+        for (num_xors, num_clauses) in [(10, 60), (15, 60), (20, 40), (20, 80), (25, 30), (25, 60), (28, 20), (30, 10)] {
+            let xors = external_clauses(&random_3sat(num_vars, num_xors, &mut rng))
+                .into_iter()
+                .map(|lits| (lits, rng.gen_bool(0.5)))
+                .collect_vec();
+            let clauses = external_clauses(&random_3sat(num_vars, num_clauses, &mut rng));
+
+            // XOR clauses encoded directly in CNF (each forbidden assignment is blocked):
+            let mut cnf = Solver::default();
+            for clause in clauses.iter() {
+                cnf.add_clause_external(clause.iter().copied());
+            }
+            for (lits, rhs) in xors.iter() {
+                for signs in 0..(1 << lits.len()) {
+                    let parity = (0..lits.len()).filter(|&i| (signs >> i) & 1 != 0).count() % 2 == 1;
+                    if parity != *rhs {
+                        // Assignment with `lits[i]=1` for bits set in `signs` violates the XOR:
+                        let clause = lits
+                            .iter()
+                            .enumerate()
+                            .map(|(i, &lit)| if (signs >> i) & 1 != 0 { -lit } else { lit })
+                            .collect_vec();
+                        cnf.add_clause_external(clause);
+                    }
+                }
+            }
+            let expected = cnf.solve();
+
+            for propagation in [Propagation::TwoWatched, Propagation::Counting] {
+                let mut solver = Solver::new(Options {
+                    propagation,
+                    check_invariants: true,
+                    ..DEFAULT_OPTIONS
+                });
+                for clause in clauses.iter() {
+                    solver.add_clause_external(clause.iter().copied());
+                }
+                for (lits, rhs) in xors.iter() {
+                    let lits = lits.iter().map(|&lit| Lit::from_external(lit)).collect_vec();
+                    solver.add_xor_clause(&lits, *rhs);
+                }
+                let res = solver.solve();
+                info!("{} XORs, {} clauses: {:?}", num_xors, num_clauses, res);
+                assert_eq!(res, expected);
+                if res == SolveResult::Sat {
+                    for clause in clauses.iter() {
+                        assert!(clause.iter().any(|&lit| solver.value(Lit::from_external(lit)) == LBool::True));
+                    }
+                    for (lits, rhs) in xors.iter() {
+                        let parity = lits
+                            .iter()
+                            .filter(|&&lit| solver.value(Lit::from_external(lit)) == LBool::True)
+                            .count()
+                            % 2
+                            == 1;
+                        assert_eq!(parity, *rhs);
+                    }
+                }
+            }
+            if expected == SolveResult::Sat {
+                num_sat += 1;
+            } else {
+                num_unsat += 1;
+            }
+        }
+        assert!(num_sat > 0);
+        assert!(num_unsat > 0);
+    }
+
+    #[test]
+    fn test_preprocess() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for num_clauses in [200, 300, 350, 450] {
+            let clauses = external_clauses(&random_3sat(100, num_clauses, &mut rng));
+
+            let solve = |preprocess: bool| {
+                let mut solver = Solver::new(Options {
+                    preprocess,
+                    check_invariants: true,
+                    ..DEFAULT_OPTIONS
+                });
+                for clause in clauses.iter() {
+                    solver.add_clause_external(clause.iter().copied());
+                }
+                let res = solver.solve();
+                (res, solver)
+            };
+
+            let (res, _) = solve(false);
+            let (res_preprocess, solver) = solve(true);
+            info!(
+                "{} clauses: {:?}, eliminated {} variables, {} clauses left",
+                num_clauses,
+                res,
+                solver.eliminations().len(),
+                solver.num_clauses()
+            );
+            assert_eq!(res, res_preprocess);
+            assert!(!solver.eliminations().is_empty());
+
+            if res == SolveResult::Sat {
+                let model = solver.model();
+                for clause in clauses.iter() {
+                    assert!(clause.iter().any(|&lit| {
+                        let lit = Lit::from_external(lit);
+                        model[lit.var()] ^ lit.negated() == LBool::True
+                    }));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_extend_model() {
+        let mut solver = Solver::new(Options {
+            preprocess: true,
+            ..DEFAULT_OPTIONS
+        });
+        let x = solver.new_lit();
+        let y = solver.new_lit();
+        let z = solver.new_lit();
+        // x <=> (y or z), (~y or ~z), y
+        let clauses: [&[Lit]; 5] = [&[-x, y, z], &[x, -y], &[x, -z], &[-y, -z], &[y]];
+        for clause in clauses.iter() {
+            solver.add_clause(clause);
+        }
+
+        // `x` is eliminated, `y` is assumed, so only `z` could also be eliminated:
+        let res = solver.solve_under_assumptions(&[y]);
+        assert_eq!(res, SolveResult::Sat);
+        assert!(solver.is_eliminated(x.var()));
+        assert!(!solver.is_eliminated(y.var()));
+
+        let mut partial = VarMap::new();
+        for var in [y.var(), z.var()] {
+            if !solver.is_eliminated(var) {
+                partial.insert(var, solver.value_var(var));
+            }
+        }
+        solver.extend_model(&mut partial);
+        assert_eq!(partial.get(x.var()), Some(&LBool::True));
+        assert_eq!(partial.get(y.var()), Some(&LBool::True));
+        assert_eq!(partial.get(z.var()), Some(&LBool::False));
+        for clause in clauses.iter() {
+            assert!(clause.iter().any(|&lit| partial[lit.var()] ^ lit.negated() == LBool::True));
+        }
+    }
+
+    #[test]
+    fn test_restore_eliminated() {
+        let mut solver = Solver::new(Options {
+            preprocess: true,
+            ..DEFAULT_OPTIONS
+        });
+        let x = solver.new_lit();
+        let y = solver.new_lit();
+        let z = solver.new_lit();
+        // x <=> (y or z), (~y or ~z)
+        for clause in [[-x, y, z].as_slice(), &[x, -y], &[x, -z], &[-y, -z]] {
+            solver.add_clause(clause);
+        }
+        let res = solver.solve_under_assumptions(&[y]);
+        assert_eq!(res, SolveResult::Sat);
+        assert!(solver.is_eliminated(x.var()));
+
+        // Assuming the eliminated variable re-introduces it:
+        let res = solver.solve_under_assumptions(&[-x, y]);
+        assert_eq!(res, SolveResult::Unsat);
+        assert!(!solver.is_eliminated(x.var()));
+        let res = solver.solve_under_assumptions(&[x]);
+        assert_eq!(res, SolveResult::Sat);
+        assert_ne!(solver.value(y), solver.value(z));
+
+        // ...and so does adding a clause with it:
+        assert!(solver.add_clause(&[-y]));
+        assert!(solver.add_clause(&[-z]));
+        assert!(solver.eliminations().is_empty());
+        let res = solver.solve();
+        assert_eq!(res, SolveResult::Sat);
+        assert_eq!(solver.value(x), LBool::False);
+        assert_eq!(solver.value(y), LBool::False);
+        assert_eq!(solver.value(z), LBool::False);
+    }
+
+    #[test]
+    fn test_statistics() {
+        let mut solver = Solver::new(Options {
+            preprocess: true,
+            ..DEFAULT_OPTIONS
+        });
+        // Pigeonhole principle (5 pigeons, 4 holes):
+        for clause in external_clauses(&pigeonhole(5, 4)) {
+            solver.add_clause_external(clause);
+        }
+        assert_eq!(solver.solve(), SolveResult::Unsat);
+
+        let stats = solver.statistics();
+        info!("stats = {:?}", stats);
+        assert_eq!(stats.num_vars, solver.num_vars());
+        assert_eq!(stats.num_free_vars, solver.num_free_vars());
+        assert_eq!(stats.num_clauses, solver.num_clauses());
+        assert_eq!(stats.num_learnts, solver.num_learnts());
+        assert_eq!(stats.num_decisions, solver.num_decisions());
+        assert_eq!(stats.num_propagations, solver.num_propagations());
+        assert_eq!(stats.num_conflicts, solver.num_conflicts());
+        assert_eq!(stats.num_restarts, solver.num_restarts());
+        assert_eq!(stats.num_blocked_restarts, solver.num_blocked_restarts());
+        assert_eq!(stats.num_simplifies, solver.num_simplifies());
+        assert_eq!(stats.num_simplified_clauses, solver.num_simplified_clauses());
+        assert_eq!(stats.num_reduces, solver.num_reduces());
+        assert_eq!(stats.num_reduced_learnts, solver.num_reduced_learnts());
+        assert_eq!(stats.num_eliminated, solver.eliminations().len());
+        assert_eq!(stats.time_search, solver.time_search);
+        assert_eq!(stats.time_propagate, solver.time_propagate);
+        assert_eq!(stats.time_analyze, solver.time_analyze);
+        assert_eq!(stats.time_backtrack, solver.time_backtrack);
+        assert_eq!(stats.time_decide, solver.time_decide);
+        assert_eq!(stats.time_restart, solver.time_restart);
+        assert_eq!(stats.time_simplify, solver.time_simplify);
+        assert_eq!(stats.time_reduce, solver.time_reduce);
+        assert_eq!(stats.time_preprocess, solver.time_preprocess);
+        assert!(stats.num_conflicts > 0);
+
+        let json = serde_json::to_value(&stats).unwrap();
+        assert_eq!(json["num_conflicts"], stats.num_conflicts);
+        assert_eq!(json["time_search"], stats.time_search.as_secs_f64());
+    }
+
     #[test]
     fn test_propcheck_tieshirt() {
         let mut solver = Solver::default();
@@ -1678,4 +3261,128 @@ mod tests {
         info!("count = {}", count);
         assert_eq!(count, 1);
     }
+
+    #[test]
+    fn test_export_import_learnts() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let num_vars = 50;
+        let random_clause = |rng: &mut StdRng| {
+            rand::seq::index::sample(rng, num_vars, 3)
+                .into_iter()
+                .map(|i| Lit::new(Var::new(i as u32), rng.gen_bool(0.5)))
+                .collect_vec()
+        };
+        let new_solver = |clauses: &[Vec<Lit>]| {
+            let mut solver = Solver::default();
+            for clause in clauses.iter() {
+                solver.add_clause(clause);
+            }
+            solver
+        };
+
+        let mut num_exported = 0;
+        for _ in 0..20 {
+            // Near the threshold, so both SAT and UNSAT instances occur:
+            let base = (0..210).map(|_| random_clause(&mut rng)).collect_vec();
+            let mut solver = new_solver(&base);
+            let res = solver.solve();
+            let learnts = solver.export_learnts();
+            num_exported += learnts.len();
+
+            // All exported clauses are implied by the base formula:
+            for learnt in learnts.iter().take(20) {
+                let negated = learnt.iter().map(|&lit| !lit).collect_vec();
+                assert_eq!(new_solver(&base).solve_under_assumptions(&negated), SolveResult::Unsat);
+            }
+            if res == SolveResult::Unsat {
+                assert_eq!(learnts, vec![vec![]]);
+            }
+
+            // Related instance: the base formula with some additional clauses.
+            let extended = base.iter().cloned().chain((0..10).map(|_| random_clause(&mut rng))).collect_vec();
+            let expected = new_solver(&extended).solve();
+            let mut solver = new_solver(&extended);
+            solver.import_clauses(&learnts);
+            assert_eq!(solver.solve(), expected);
+            if expected == SolveResult::Sat {
+                for clause in extended.iter() {
+                    assert!(clause.iter().any(|&lit| solver.value(lit) == LBool::True));
+                }
+            }
+        }
+        assert!(num_exported > 0);
+    }
+
+    #[test]
+    fn test_seed_activities_from_occurrences() {
+        let mut solver = Solver::default();
+        let vars = (0..4).map(|_| solver.new_var()).collect_vec();
+        let [a, b, c, d] = [0, 1, 2, 3].map(|i| Lit::new(vars[i], false));
+
+        // Occurrences: d in 3 clauses, c in 2, a and b in 1.
+        solver.add_clause(&[d, c]);
+        solver.add_clause(&[-d, c, a]);
+        solver.add_clause(&[d, -b]);
+
+        // Deleted clauses do not count, even though `b` would be the most frequent otherwise:
+        for lits in [[b, a], [-b, c], [b, -c], [-b, -a]] {
+            solver.add_clause(&lits);
+            let cref = *solver.db.clauses().last().unwrap();
+            solver.ca.free(cref);
+        }
+
+        solver.seed_activities_from_occurrences();
+        assert_eq!(solver.pick_branching_variable(), Some(d.var()));
+
+        // Once `d` is assigned, the next most frequent variable is picked:
+        solver.assignment.new_decision_level();
+        solver.assignment.unchecked_enqueue(d, None);
+        assert_eq!(solver.pick_branching_variable(), Some(c.var()));
+    }
+
+    #[test]
+    fn test_progress_estimate() {
+        use std::sync::Mutex;
+
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut solver = Solver::new(Options {
+            restart_init: 10,
+            ..DEFAULT_OPTIONS
+        });
+        assert_eq!(solver.progress_estimate(), 0.0);
+
+        let estimates = Arc::new(Mutex::new(Vec::new()));
+        solver.set_progress_callback({
+            let estimates = Arc::clone(&estimates);
+            move |estimate| estimates.lock().unwrap().push(estimate)
+        });
+
+        // Random 3-SAT instance above the threshold (most likely UNSAT):
+        let mut rng = StdRng::seed_from_u64(42);
+        let num_vars = 100;
+        for _ in 0..500 {
+            let clause = rand::seq::index::sample(&mut rng, num_vars, 3)
+                .into_iter()
+                .map(|i| Lit::new(Var::new(i as u32), rng.gen_bool(0.5)))
+                .collect_vec();
+            solver.add_clause(&clause);
+        }
+        solver.solve();
+        let estimate = solver.progress_estimate();
+        assert!((0.0..=1.0).contains(&estimate));
+
+        let estimates = estimates.lock().unwrap();
+        info!("{} estimates: {:?}", estimates.len(), estimates);
+        assert!(!estimates.is_empty());
+        assert!(estimates.iter().all(|e| (0.0..=1.0).contains(e)));
+        // The root-level part never decreases, and all other levels contribute less than `1/n`:
+        for w in estimates.windows(2) {
+            assert!(w[1] >= w[0] - 1.0 / num_vars as f64, "Estimate dropped from {} to {}", w[0], w[1]);
+        }
+    }
 }
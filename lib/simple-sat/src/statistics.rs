@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_with::serde_as;
+use serde_with::DurationSecondsWithFrac;
+
+/// Snapshot of the solver counters and timers, see [`Solver::statistics`][crate::solver::Solver::statistics].
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Statistics {
+    pub num_vars: usize,
+    pub num_free_vars: usize,
+    pub num_clauses: usize,
+    pub num_learnts: usize,
+    pub num_decisions: usize,
+    pub num_propagations: usize,
+    pub num_conflicts: usize,
+    pub num_restarts: usize,
+    pub num_blocked_restarts: usize,
+    pub num_simplifies: usize,
+    pub num_simplified_clauses: usize,
+    pub num_reduces: usize,
+    pub num_reduced_learnts: usize,
+    pub num_eliminated: usize,
+    #[serde_as(as = "DurationSecondsWithFrac<f64>")]
+    pub time_search: Duration,
+    #[serde_as(as = "DurationSecondsWithFrac<f64>")]
+    pub time_propagate: Duration,
+    #[serde_as(as = "DurationSecondsWithFrac<f64>")]
+    pub time_analyze: Duration,
+    #[serde_as(as = "DurationSecondsWithFrac<f64>")]
+    pub time_backtrack: Duration,
+    #[serde_as(as = "DurationSecondsWithFrac<f64>")]
+    pub time_decide: Duration,
+    #[serde_as(as = "DurationSecondsWithFrac<f64>")]
+    pub time_restart: Duration,
+    #[serde_as(as = "DurationSecondsWithFrac<f64>")]
+    pub time_simplify: Duration,
+    #[serde_as(as = "DurationSecondsWithFrac<f64>")]
+    pub time_reduce: Duration,
+    #[serde_as(as = "DurationSecondsWithFrac<f64>")]
+    pub time_preprocess: Duration,
+}
@@ -2,6 +2,7 @@ use std::ops::{Index, IndexMut};
 use tracing::trace;
 
 use crate::arena::{Arena, Id};
+use crate::lit::Lit;
 
 #[derive(Debug)]
 pub struct TrieNode {
@@ -253,6 +254,162 @@ impl<'a> Iterator for TrieIter<'a> {
     }
 }
 
+// ==========================================
+
+#[derive(Debug)]
+pub struct ClauseTrieNode {
+    children: Vec<(Lit, Id)>, // sorted by literal
+    is_end: bool,
+}
+
+impl ClauseTrieNode {
+    pub fn new() -> Self {
+        Self {
+            children: Vec::new(),
+            is_end: false,
+        }
+    }
+}
+
+impl Default for ClauseTrieNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Trie over clauses (sets of literals), used for deduplication and subsumption checks.
+///
+/// Clauses are stored as sorted sequences of distinct literals, so the order
+/// and repetitions of literals in the inserted clause do not matter.
+#[derive(Debug)]
+pub struct ClauseTrie {
+    nodes: Arena<ClauseTrieNode>,
+    root: Id,
+    num_clauses: usize,
+}
+
+impl ClauseTrie {
+    pub fn new() -> Self {
+        let mut nodes = Arena::new();
+        let root = nodes.alloc(ClauseTrieNode::new());
+        Self {
+            nodes,
+            root,
+            num_clauses: 0,
+        }
+    }
+}
+
+impl Default for ClauseTrie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn normalize(clause: &[Lit]) -> Vec<Lit> {
+    let mut lits = clause.to_vec();
+    lits.sort_by_key(|lit| lit.inner());
+    lits.dedup();
+    lits
+}
+
+impl ClauseTrie {
+    /// Number of distinct clauses in the trie.
+    pub fn len(&self) -> usize {
+        self.num_clauses
+    }
+    pub fn is_empty(&self) -> bool {
+        self.num_clauses == 0
+    }
+
+    /// Insert the clause into the trie.
+    ///
+    /// Returns `true` if the clause was not present before.
+    pub fn insert(&mut self, clause: &[Lit]) -> bool {
+        let mut current = self.root;
+        for lit in normalize(clause) {
+            let children = &self.nodes[current].children;
+            current = match children.binary_search_by_key(&lit.inner(), |(x, _)| x.inner()) {
+                Ok(i) => children[i].1,
+                Err(i) => {
+                    let child = self.nodes.alloc(ClauseTrieNode::new());
+                    self.nodes[current].children.insert(i, (lit, child));
+                    child
+                }
+            };
+        }
+        if self.nodes[current].is_end {
+            false
+        } else {
+            self.nodes[current].is_end = true;
+            self.num_clauses += 1;
+            true
+        }
+    }
+
+    /// Check whether the clause (as a set of literals) is present in the trie.
+    pub fn contains(&self, clause: &[Lit]) -> bool {
+        let mut current = self.root;
+        for lit in normalize(clause) {
+            let children = &self.nodes[current].children;
+            match children.binary_search_by_key(&lit.inner(), |(x, _)| x.inner()) {
+                Ok(i) => current = children[i].1,
+                Err(_) => return false,
+            }
+        }
+        self.nodes[current].is_end
+    }
+
+    /// Check whether the trie contains some clause which is a subset of the given `clause`,
+    /// that is, whether the given clause is subsumed by one of the stored clauses.
+    pub fn contains_subset(&self, clause: &[Lit]) -> bool {
+        self.contains_subset_from(self.root, &normalize(clause))
+    }
+
+    fn contains_subset_from(&self, node: Id, lits: &[Lit]) -> bool {
+        if self.nodes[node].is_end {
+            return true;
+        }
+        let children = &self.nodes[node].children;
+        for (i, lit) in lits.iter().enumerate() {
+            if let Ok(j) = children.binary_search_by_key(&lit.inner(), |(x, _)| x.inner()) {
+                if self.contains_subset_from(children[j].1, &lits[i + 1..]) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /// Check whether the trie contains some clause which is a superset of the given `clause`,
+    /// that is, whether the given clause subsumes one of the stored clauses.
+    pub fn contains_superset(&self, clause: &[Lit]) -> bool {
+        self.contains_superset_from(self.root, &normalize(clause))
+    }
+
+    fn contains_superset_from(&self, node: Id, lits: &[Lit]) -> bool {
+        let Some((first, rest)) = lits.split_first() else {
+            // Any clause in the subtree is a superset. Note: all leaves are clause ends.
+            return self.nodes[node].is_end || !self.nodes[node].children.is_empty();
+        };
+        for &(lit, child) in self.nodes[node].children.iter() {
+            if lit.inner() < first.inner() {
+                // Skip the extra literal:
+                if self.contains_superset_from(child, lits) {
+                    return true;
+                }
+            } else {
+                if lit == *first && self.contains_superset_from(child, rest) {
+                    return true;
+                }
+                // Children are sorted, thus the first literal can no longer be matched.
+                break;
+            }
+        }
+        false
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -313,4 +470,47 @@ mod tests {
             assert_ne!(trie.search(cube), 0);
         }
     }
+
+    fn clause(lits: &[i32]) -> Vec<Lit> {
+        lits.iter().map(|&lit| Lit::from_external(lit)).collect()
+    }
+
+    #[test]
+    fn test_clause_trie_dedup() {
+        let mut trie = ClauseTrie::new();
+        assert!(trie.is_empty());
+        assert!(trie.insert(&clause(&[1, -2, 3])));
+        assert!(trie.insert(&clause(&[1, -2])));
+        assert!(trie.insert(&clause(&[1, -2, 4])));
+        // Same clauses, with literals reordered and repeated:
+        assert!(!trie.insert(&clause(&[3, 1, -2])));
+        assert!(!trie.insert(&clause(&[-2, 1, -2])));
+        assert_eq!(trie.len(), 3);
+        assert!(trie.contains(&clause(&[-2, 3, 1])));
+        assert!(!trie.contains(&clause(&[1])));
+        assert!(!trie.contains(&clause(&[1, 2])));
+    }
+
+    #[test]
+    fn test_clause_trie_subset() {
+        let mut trie = ClauseTrie::new();
+        trie.insert(&clause(&[1, -2, 3]));
+        trie.insert(&clause(&[2, 4]));
+        trie.insert(&clause(&[-1, 5]));
+
+        assert!(trie.contains_subset(&clause(&[1, -2, 3])));
+        assert!(trie.contains_subset(&clause(&[1, -2, 3, 5])));
+        assert!(trie.contains_subset(&clause(&[6, 4, 3, 2])));
+        assert!(trie.contains_subset(&clause(&[5, -1])));
+        assert!(!trie.contains_subset(&clause(&[1, -2])));
+        assert!(!trie.contains_subset(&clause(&[1, 2, 3])));
+        assert!(!trie.contains_subset(&clause(&[-1, -5, 4])));
+        assert!(!trie.contains_subset(&[]));
+
+        assert!(trie.contains_superset(&clause(&[1, 3])));
+        assert!(trie.contains_superset(&clause(&[4])));
+        assert!(trie.contains_superset(&[]));
+        assert!(!trie.contains_superset(&clause(&[1, 2])));
+        assert!(!trie.contains_superset(&clause(&[-1, 5, 6])));
+    }
 }
@@ -32,6 +32,26 @@ where
 }
 
 pub fn parse_dimacs<P>(path: P) -> impl Iterator<Item = Vec<Lit>>
+where
+    P: AsRef<Path>,
+{
+    parse_dimacs_with_comments(path).filter_map(|line| match line {
+        DimacsLine::Comment(comment) => {
+            trace!("Skipping comment '{}'", comment);
+            None
+        }
+        DimacsLine::Clause(clause) => Some(clause),
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DimacsLine {
+    /// Comment text, without the leading `c` and a single space after it.
+    Comment(String),
+    Clause(Vec<Lit>),
+}
+
+pub fn parse_dimacs_with_comments<P>(path: P) -> impl Iterator<Item = DimacsLine>
 where
     P: AsRef<Path>,
 {
@@ -39,15 +59,15 @@ where
         if line.is_empty() {
             trace!("Skipping empty line");
             None
-        } else if line.starts_with('c') {
-            trace!("Skipping comment '{}'", line);
-            None
+        } else if let Some(comment) = line.strip_prefix('c') {
+            let comment = comment.strip_prefix(' ').unwrap_or(comment);
+            Some(DimacsLine::Comment(comment.to_string()))
         } else if line.starts_with('p') {
             trace!("Skipping header '{}'", line);
             None
         } else {
             let clause = parse_dimacs_clause(&line);
-            Some(clause)
+            Some(DimacsLine::Clause(clause))
         }
     })
 }
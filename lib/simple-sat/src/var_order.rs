@@ -1,12 +1,82 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use tracing::debug;
 
 use crate::assignment::Assignment;
 use crate::idx::{VarHeap, VarMap};
 use crate::var::Var;
 
+/// Strategy for picking the decision variables.
+pub trait DecisionHeuristic: Debug {
+    /// Register a new variable.
+    fn init_var(&mut self, var: Var);
+
+    /// Reserve capacity for `num_vars` variables.
+    fn reserve(&mut self, num_vars: usize);
+
+    fn capacity(&self) -> usize;
+
+    /// Pick an unassigned variable to branch on, or `None` if all variables are assigned.
+    fn select(&mut self, assignment: &Assignment) -> Option<Var>;
+
+    /// Bump the variable participating in the conflict analysis.
+    fn bump(&mut self, var: Var);
+
+    /// Called after each conflict.
+    fn decay(&mut self) {}
+
+    /// Called when the variable becomes unassigned during backtracking.
+    fn on_unassign(&mut self, var: Var);
+
+    /// Check the internal consistency, panicking on violation.
+    fn check(&self) {}
+}
+
+/// Kind of the [decision heuristic][DecisionHeuristic] used by the solver.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum HeuristicKind {
+    Vsids,
+    Random,
+}
+
+impl HeuristicKind {
+    pub fn build(self) -> Box<dyn DecisionHeuristic> {
+        match self {
+            HeuristicKind::Vsids => Box::new(Vsids::new()),
+            HeuristicKind::Random => Box::new(RandomOrder::new(42)),
+        }
+    }
+}
+
+impl Display for HeuristicKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HeuristicKind::Vsids => write!(f, "vsids"),
+            HeuristicKind::Random => write!(f, "random"),
+        }
+    }
+}
+
+impl FromStr for HeuristicKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "vsids" => Ok(HeuristicKind::Vsids),
+            "random" => Ok(HeuristicKind::Random),
+            _ => Err(format!("Unknown heuristic '{}' (expected 'vsids' or 'random')", s)),
+        }
+    }
+}
+
+// ==========================================
+
+/// Variable State Independent Decaying Sum (VSIDS) heuristic.
 #[derive(Debug)]
-pub struct VarOrder {
-    pub(crate) num_dec_vars: usize,
+pub struct Vsids {
     activity: VarMap<f64>,
     order_heap: VarHeap,
     var_decay: f64,
@@ -16,10 +86,9 @@ pub struct VarOrder {
 const DEFAULT_VAR_DECAY: f64 = 0.95;
 const DEFAULT_VAR_INC: f64 = 1.0;
 
-impl VarOrder {
+impl Vsids {
     pub fn new() -> Self {
         Self {
-            num_dec_vars: 0,
             activity: VarMap::new(),
             order_heap: VarHeap::new(),
             var_decay: DEFAULT_VAR_DECAY,
@@ -28,19 +97,13 @@ impl VarOrder {
     }
 }
 
-impl Default for VarOrder {
+impl Default for Vsids {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl VarOrder {
-    pub(crate) fn init_var(&mut self, var: Var) {
-        self.activity.insert(var, 0.0);
-        self.num_dec_vars += 1;
-        self.insert_var_order(var);
-    }
-
+impl Vsids {
     pub fn var_decay_activity(&mut self) {
         self.var_inc /= self.var_decay;
     }
@@ -54,9 +117,9 @@ impl VarOrder {
             self.var_rescale_activity();
         }
 
-        // Update `var` in heap:
+        // Update `var` in heap (the activity only grew, so it can only move up):
         if self.order_heap.contains(&var) {
-            self.update_var_order(var);
+            self.order_heap.decrease_by(var, |&a, &b| self.activity[a] > self.activity[b]);
         }
     }
 
@@ -90,6 +153,11 @@ impl VarOrder {
         // });
     }
 
+    /// Validate the order heap, see [`IdxHeap::check_heap_property_by`][crate::idx::idx_heap::IdxHeap::check_heap_property_by].
+    pub fn check_heap_property(&self) {
+        self.order_heap.check_heap_property_by(|&a, &b| self.activity[a] > self.activity[b]);
+    }
+
     pub fn pick_branching_variable(&mut self, assignment: &Assignment) -> Option<Var> {
         self.order_heap
             .sorted_iter_by(|&a, &b| self.activity[a] > self.activity[b])
@@ -101,3 +169,90 @@ impl VarOrder {
             .find(|&var| assignment.value_var(var).is_undef())
     }
 }
+
+impl DecisionHeuristic for Vsids {
+    fn init_var(&mut self, var: Var) {
+        self.activity.insert(var, 0.0);
+        self.insert_var_order(var);
+    }
+
+    fn reserve(&mut self, num_vars: usize) {
+        self.activity.reserve_len(num_vars);
+        self.order_heap.reserve_len(num_vars);
+    }
+
+    fn capacity(&self) -> usize {
+        self.activity.capacity().min(self.order_heap.capacity())
+    }
+
+    fn select(&mut self, assignment: &Assignment) -> Option<Var> {
+        self.pick_branching_variable(assignment)
+    }
+
+    fn bump(&mut self, var: Var) {
+        self.var_bump_activity(var);
+    }
+
+    fn decay(&mut self) {
+        self.var_decay_activity();
+    }
+
+    fn on_unassign(&mut self, var: Var) {
+        self.insert_var_order(var);
+    }
+
+    fn check(&self) {
+        self.check_heap_property();
+    }
+}
+
+// ==========================================
+
+/// Uniformly random choice among the unassigned variables.
+#[derive(Debug)]
+pub struct RandomOrder {
+    vars: Vec<Var>,
+    rng: StdRng,
+}
+
+impl RandomOrder {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            vars: Vec::new(),
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl DecisionHeuristic for RandomOrder {
+    fn init_var(&mut self, var: Var) {
+        self.vars.push(var);
+    }
+
+    fn reserve(&mut self, num_vars: usize) {
+        self.vars.reserve(num_vars.saturating_sub(self.vars.len()));
+    }
+
+    fn capacity(&self) -> usize {
+        self.vars.capacity()
+    }
+
+    fn select(&mut self, assignment: &Assignment) -> Option<Var> {
+        // Move the assigned variables out of the sampled range, so that the pick is uniform among the unassigned ones:
+        let mut n = self.vars.len();
+        while n > 0 {
+            let i = self.rng.gen_range(0..n);
+            let var = self.vars[i];
+            if assignment.value_var(var).is_undef() {
+                return Some(var);
+            }
+            n -= 1;
+            self.vars.swap(i, n);
+        }
+        None
+    }
+
+    fn bump(&mut self, _var: Var) {}
+
+    fn on_unassign(&mut self, _var: Var) {}
+}
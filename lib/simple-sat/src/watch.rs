@@ -1,10 +1,44 @@
+use std::fmt::{Display, Formatter};
 use std::ops::IndexMut;
+use std::str::FromStr;
 
+use crate::clause_allocator::ClauseAllocator;
 use crate::cref::ClauseRef;
-use crate::idx::LitVec;
+use crate::idx::{LitVec, VarVec};
 use crate::lit::Lit;
 use crate::var::Var;
 
+/// Unit propagation scheme.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Propagation {
+    /// Two watched literals per clause (with blockers).
+    TwoWatched,
+    /// Full occurrence lists with a counter of the falsified literals in each clause,
+    /// so that a clause is inspected only when it becomes unit or falsified.
+    Counting,
+}
+
+impl Display for Propagation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Propagation::TwoWatched => write!(f, "two-watched"),
+            Propagation::Counting => write!(f, "counting"),
+        }
+    }
+}
+
+impl FromStr for Propagation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "two-watched" => Ok(Propagation::TwoWatched),
+            "counting" => Ok(Propagation::Counting),
+            _ => Err(format!("Unknown propagation '{}' (expected 'two-watched' or 'counting')", s)),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct Watcher {
     pub(crate) cref: ClauseRef,
@@ -26,6 +60,19 @@ impl WatchList {
         self.watchlist.init(&Lit::new(var, true));
     }
 
+    /// Reserve capacity for `num_vars` variables.
+    pub fn reserve(&mut self, num_vars: usize) {
+        self.watchlist.reserve_len(2 * num_vars);
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.watchlist.capacity()
+    }
+
+    pub fn watchers(&self, lit: Lit) -> &[Watcher] {
+        &self.watchlist[lit]
+    }
+
     pub fn lookup(&mut self, lit: Lit) -> &mut Vec<Watcher> {
         self.watchlist.index_mut(lit)
     }
@@ -34,3 +81,98 @@ impl WatchList {
         self.watchlist[lit].push(watch);
     }
 }
+
+/// Occurrence lists: all clauses containing each literal, used by [`Propagation::Counting`],
+/// together with the number of falsified literals in each clause.
+///
+/// A falsified literal is counted once its negation is propagated, see [`count`][OccurList::count],
+/// and uncounted on backtracking, see [`uncount`][OccurList::uncount].
+#[derive(Debug)]
+pub struct OccurList {
+    occurs: LitVec<Vec<ClauseRef>>,
+    /// Number of counted falsified literals, indexed by the clause reference.
+    num_false: Vec<u32>,
+    /// Whether the assignment of the variable is reflected in the counters.
+    counted: VarVec<bool>,
+}
+
+impl OccurList {
+    pub const fn new() -> Self {
+        Self {
+            occurs: LitVec::new(),
+            num_false: Vec::new(),
+            counted: VarVec::new(),
+        }
+    }
+}
+
+impl Default for OccurList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OccurList {
+    pub fn init(&mut self, var: Var) {
+        self.occurs.init(&Lit::new(var, false));
+        self.occurs.init(&Lit::new(var, true));
+        self.counted.push(false);
+    }
+
+    /// Reserve capacity for `num_vars` variables.
+    pub fn reserve(&mut self, num_vars: usize) {
+        self.occurs.reserve_len(2 * num_vars);
+        self.counted.reserve_len(num_vars);
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.occurs.capacity()
+    }
+
+    pub fn occurrences(&self, lit: Lit) -> &[ClauseRef] {
+        &self.occurs[lit]
+    }
+
+    pub fn is_counted(&self, var: Var) -> bool {
+        self.counted[var]
+    }
+
+    /// Number of counted falsified literals in the clause.
+    pub fn num_false(&self, cref: ClauseRef) -> usize {
+        self.num_false[cref.0] as usize
+    }
+
+    /// Insert the clause into the occurrence lists of its literals,
+    /// with `num_false` falsified literals already counted.
+    pub fn insert(&mut self, lits: &[Lit], cref: ClauseRef, num_false: usize) {
+        for &lit in lits.iter() {
+            self.occurs[lit].push(cref);
+        }
+        if cref.0 >= self.num_false.len() {
+            self.num_false.resize(cref.0 + 1, 0);
+        }
+        self.num_false[cref.0] = num_false as u32;
+    }
+
+    /// Count the falsified literal `!p` in all clauses containing it,
+    /// dropping the deleted clauses from its occurrence list.
+    pub fn count(&mut self, p: Lit, ca: &ClauseAllocator) {
+        debug_assert!(!self.counted[p.var()]);
+        self.counted[p.var()] = true;
+        let occurs = &mut self.occurs[!p];
+        occurs.retain(|&cref| !ca.clause(cref).is_deleted());
+        for &cref in occurs.iter() {
+            self.num_false[cref.0] += 1;
+        }
+    }
+
+    /// Undo [`count`][OccurList::count] for the unassigned `p`, if it was counted.
+    pub fn uncount(&mut self, p: Lit) {
+        if self.counted[p.var()] {
+            self.counted[p.var()] = false;
+            for &cref in self.occurs[!p].iter() {
+                self.num_false[cref.0] -= 1;
+            }
+        }
+    }
+}
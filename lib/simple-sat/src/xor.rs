@@ -0,0 +1,308 @@
+//! XOR constraints and Gaussian elimination.
+//!
+//! XOR clauses are stored as rows of a matrix over GF(2), which is kept in the reduced
+//! row echelon form, so that an inconsistent system is detected as soon as it is added.
+//! Each row has a pivot variable, which does not occur in any other row.
+//! During the search, the matrix is maintained incrementally: when the pivot of a row
+//! gets assigned, another unassigned variable of the row becomes its pivot and is
+//! eliminated from the other rows. The row operations preserve the system, so nothing
+//! has to be undone on backtracking. Then, each row with only the pivot unassigned
+//! implies its value, and each row without unassigned variables, but with a wrong parity,
+//! is a conflict. The implications (and conflicts) are explained by plain clauses over
+//! the row variables, built on demand.
+
+use crate::assignment::Assignment;
+use crate::lbool::LBool;
+use crate::lit::Lit;
+use crate::var::Var;
+
+/// Row of a matrix over GF(2): the set of variables and the right-hand side of the XOR.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Row {
+    bits: Vec<u64>,
+    rhs: bool,
+}
+
+impl Row {
+    fn new(num_vars: usize) -> Self {
+        Self {
+            bits: vec![0; num_vars.div_ceil(64)],
+            rhs: false,
+        }
+    }
+
+    fn resize(&mut self, num_vars: usize) {
+        self.bits.resize(num_vars.div_ceil(64), 0);
+    }
+
+    fn get(&self, var: Var) -> bool {
+        let i = var.index();
+        (self.bits[i / 64] >> (i % 64)) & 1 != 0
+    }
+
+    fn flip(&mut self, var: Var) {
+        let i = var.index();
+        self.bits[i / 64] ^= 1 << (i % 64);
+    }
+
+    fn xor_assign(&mut self, other: &Row) {
+        for (a, b) in self.bits.iter_mut().zip(other.bits.iter()) {
+            *a ^= b;
+        }
+        self.rhs ^= other.rhs;
+    }
+
+    fn vars(&self) -> impl Iterator<Item = Var> + '_ {
+        self.bits.iter().enumerate().flat_map(|(k, &word)| {
+            (0..64)
+                .filter(move |&b| (word >> b) & 1 != 0)
+                .map(move |b| Var::new((k * 64 + b) as u32))
+        })
+    }
+
+    fn first(&self) -> Option<Var> {
+        self.vars().next()
+    }
+}
+
+/// Set of XOR constraints, kept in the reduced row echelon form.
+#[derive(Debug, Clone, Default)]
+pub struct XorSystem {
+    rows: Vec<Row>,
+    /// Pivot variable of each row, occurring in no other row.
+    pivots: Vec<Var>,
+    num_vars: usize,
+}
+
+impl XorSystem {
+    pub const fn new() -> Self {
+        Self {
+            rows: Vec::new(),
+            pivots: Vec::new(),
+            num_vars: 0,
+        }
+    }
+
+    /// Number of linearly independent XOR constraints.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Variables occurring in the constraints.
+    pub fn vars(&self) -> Vec<Var> {
+        let mut all = Row::new(self.num_vars);
+        for row in self.rows.iter() {
+            for (a, b) in all.bits.iter_mut().zip(row.bits.iter()) {
+                *a |= b;
+            }
+        }
+        all.vars().collect()
+    }
+
+    /// Add the constraint `lits[0] ^ lits[1] ^ ... = rhs`.
+    ///
+    /// **Returns:**
+    ///
+    /// - `false`, if the system became inconsistent,
+    /// - `true`, otherwise.
+    pub fn add(&mut self, lits: &[Lit], rhs: bool) -> bool {
+        if let Some(max_var) = lits.iter().map(|lit| lit.var().index() + 1).max() {
+            if max_var > self.num_vars {
+                self.num_vars = max_var;
+                for row in self.rows.iter_mut() {
+                    row.resize(max_var);
+                }
+            }
+        }
+
+        // Note: `~x = x ^ 1`, and duplicate variables cancel out.
+        let mut new_row = Row::new(self.num_vars);
+        new_row.rhs = rhs;
+        for &lit in lits.iter() {
+            new_row.flip(lit.var());
+            new_row.rhs ^= lit.negated();
+        }
+
+        // Reduce the new row using the existing pivots:
+        for (row, &pivot) in self.rows.iter().zip(self.pivots.iter()) {
+            if new_row.get(pivot) {
+                new_row.xor_assign(row);
+            }
+        }
+
+        match new_row.first() {
+            // Redundant (`0 = 0`) or inconsistent (`0 = 1`) constraint:
+            None => !new_row.rhs,
+            Some(pivot) => {
+                self.rows.push(new_row);
+                self.pivots.push(pivot);
+                self.eliminate(self.rows.len() - 1);
+                true
+            }
+        }
+    }
+
+    /// Eliminate the pivot of the `index`-th row from all other rows.
+    fn eliminate(&mut self, index: usize) {
+        let pivot = self.pivots[index];
+        let (before, rest) = self.rows.split_at_mut(index);
+        let (row, after) = rest.split_first_mut().unwrap();
+        for other in before.iter_mut().chain(after.iter_mut()) {
+            if other.get(pivot) {
+                other.xor_assign(row);
+            }
+        }
+    }
+
+    /// Literals implied by the system alone (without any assignment).
+    pub fn units(&self) -> Vec<Lit> {
+        self.rows
+            .iter()
+            .filter_map(|row| {
+                let mut vars = row.vars();
+                let var = vars.next().unwrap();
+                if vars.next().is_none() {
+                    Some(Lit::new(var, !row.rhs))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Move the pivots off the assigned variables and derive the implied literals.
+    ///
+    /// **Returns:**
+    ///
+    /// - [`Ok(implied)`][Ok] with each implied literal and the index of the row implying it,
+    /// - [`Err(index)`][Err] with the index of a row violated by the assignment.
+    ///
+    /// Use [`reason`][XorSystem::reason] and [`conflict`][XorSystem::conflict] to explain them.
+    /// The explanations stay valid while the row variables remain assigned, since such rows
+    /// are never touched by the elimination.
+    pub fn propagate(&mut self, assignment: &Assignment) -> Result<Vec<(Lit, usize)>, usize> {
+        for i in 0..self.rows.len() {
+            if assignment.value_var(self.pivots[i]) != LBool::Undef {
+                let pivot = self.rows[i].vars().find(|&var| assignment.value_var(var) == LBool::Undef);
+                if let Some(pivot) = pivot {
+                    self.pivots[i] = pivot;
+                    self.eliminate(i);
+                }
+            }
+        }
+
+        let mut implied = Vec::new();
+        'rows: for (i, row) in self.rows.iter().enumerate() {
+            let pivot = self.pivots[i];
+            let mut parity = row.rhs;
+            for var in row.vars() {
+                match assignment.value_var(var) {
+                    LBool::Undef if var == pivot => {}
+                    LBool::Undef => continue 'rows,
+                    value => parity ^= value == LBool::True,
+                }
+            }
+            if assignment.value_var(pivot) == LBool::Undef {
+                implied.push((Lit::new(pivot, !parity), i));
+            } else if parity {
+                return Err(i);
+            }
+        }
+        Ok(implied)
+    }
+
+    /// Reason clause for the literal `lit` implied by the `index`-th row:
+    /// the first literal is the implied one and all others are falsified.
+    pub fn reason(&self, index: usize, lit: Lit, assignment: &Assignment) -> Vec<Lit> {
+        let mut reason = vec![lit];
+        reason.extend(
+            self.rows[index]
+                .vars()
+                .filter(|&var| var != lit.var())
+                .map(|var| Lit::new(var, assignment.value_var(var) == LBool::True)),
+        );
+        reason
+    }
+
+    /// Falsified clause for the `index`-th row violated by the assignment.
+    pub fn conflict(&self, index: usize, assignment: &Assignment) -> Vec<Lit> {
+        self.rows[index]
+            .vars()
+            .map(|var| Lit::new(var, assignment.value_var(var) == LBool::True))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::assignment::VarData;
+
+    fn lits(lits: &[i32]) -> Vec<Lit> {
+        lits.iter().map(|&lit| Lit::from_external(lit)).collect()
+    }
+
+    fn unassigned(num_vars: usize) -> Assignment {
+        let mut assignment = Assignment::new();
+        for _ in 0..num_vars {
+            assignment.assignment.push(LBool::Undef);
+            assignment.var_data.push(VarData { reason: None, level: 0 });
+        }
+        assignment
+    }
+
+    #[test]
+    fn test_xor_system() {
+        let mut system = XorSystem::new();
+        assert!(system.add(&lits(&[1, 2]), true));
+        assert!(system.add(&lits(&[2, -3]), false));
+        assert_eq!(system.len(), 2);
+        assert!(system.units().is_empty());
+
+        // Redundant: x1 ^ x3 = 0
+        assert!(system.add(&lits(&[1, 3]), false));
+        assert_eq!(system.len(), 2);
+
+        // Inconsistent: x1 ^ x3 = 1
+        let mut inconsistent = system.clone();
+        assert!(!inconsistent.add(&lits(&[3, 1]), true));
+
+        // x3 = 1 => x1 = 1, x2 = 0
+        assert!(system.add(&lits(&[3]), true));
+        let mut units = system.units();
+        units.sort_by_key(|lit| lit.inner());
+        assert_eq!(units, lits(&[1, -2, 3]));
+    }
+
+    #[test]
+    fn test_xor_propagate() {
+        let mut system = XorSystem::new();
+        assert!(system.add(&lits(&[1, 2, 3]), true));
+        assert!(system.add(&lits(&[3, 4]), false));
+
+        let mut assignment = unassigned(4);
+        assignment.new_decision_level();
+        assignment.unchecked_enqueue(Lit::from_external(1), None);
+        assert_eq!(system.propagate(&assignment), Ok(vec![]));
+
+        // x1 = 1, x4 = 0 => x2 = 0 (via x1 ^ x2 ^ x4 = 1), x3 = 0
+        assignment.unchecked_enqueue(Lit::from_external(-4), None);
+        let implied = system.propagate(&assignment).unwrap();
+        assert_eq!(implied.iter().map(|&(lit, _)| lit).collect::<Vec<_>>(), lits(&[-2, -3]));
+        let (lit, index) = implied[0];
+        assert_eq!(system.reason(index, lit, &assignment), lits(&[-2, -1, 4]));
+
+        // The rows are kept as is, and the same literals are implied again:
+        assert_eq!(system.propagate(&assignment), Ok(implied));
+
+        // x2 = 1 violates the first row:
+        assignment.unchecked_enqueue(Lit::from_external(2), None);
+        let index = system.propagate(&assignment).unwrap_err();
+        assert_eq!(system.conflict(index, &assignment), lits(&[-1, -2, 4]));
+    }
+}
@@ -4,13 +4,16 @@ use std::fmt::{Display, Formatter};
 use strum::IntoStaticStr;
 
 use sat_nexus_core::lit::Lit;
-use sat_nexus_core::solver::delegate::DelegateSolver;
+use sat_nexus_core::solver::delegate::{CachingSolver, DelegateSolver};
 use sat_nexus_core::solver::simple::SimpleSolver;
-use sat_nexus_core::solver::{LitValue, SolveResponse, Solver};
+use sat_nexus_core::solver::{Capabilities, LitValue, SolveResponse, Solver};
 
 use crate::cadical_dynamic::CadicalDynamicSolver;
+use crate::ipasir::IpasirSolver;
+use crate::kind::{ParseSolverKindError, SolverKind};
 use crate::kissat_dynamic::KissatDynamicSolver;
 use crate::minisat_dynamic::MiniSatDynamicSolver;
+use crate::simplesat::SimpleSatSolver;
 
 #[derive(Debug, IntoStaticStr)]
 #[strum(ascii_case_insensitive)]
@@ -49,14 +52,31 @@ impl DispatchSolver {
         Self::from(KissatDynamicSolver::new())
     }
 
-    pub fn by_name(name: &str) -> Self {
-        match name.to_ascii_lowercase().as_str() {
-            "minisat" => Self::new_minisat(),
-            "cadical" => Self::new_cadical(),
-            "kissat" => Self::new_kissat(),
-            _ => panic!("Bad name '{}'", name),
+    pub fn new_kind(kind: SolverKind) -> Self {
+        match kind {
+            SolverKind::MiniSat => Self::new_minisat(),
+            SolverKind::Cadical => Self::new_cadical(),
+            SolverKind::Kissat => Self::new_kissat(),
+            SolverKind::Glucose => Self::new_delegate_wrap(IpasirSolver::new_glucose()),
+            SolverKind::Simple => Self::new_delegate_wrap(SimpleSatSolver::new()),
         }
     }
+
+    /// Create the solver by its name, see [`SolverKind`] for the accepted names.
+    pub fn by_name(name: &str) -> Result<Self, ParseSolverKindError> {
+        name.parse().map(Self::new_kind)
+    }
+
+    /// Wrap the solver into the cache of solve results, keyed by the set of assumptions,
+    /// see [`CachingSolver`].
+    pub fn cached(self) -> CachingSolver<Self> {
+        CachingSolver::new(self)
+    }
+
+    /// Signature of the underlying backend solver, including its version.
+    pub fn backend_signature(&self) -> String {
+        dispatch!(self, inner => inner.signature().into_owned())
+    }
 }
 
 impl From<DelegateSolver> for DispatchSolver {
@@ -111,6 +131,14 @@ impl Solver for DispatchSolver {
         dispatch_delegate!(self, release())
     }
 
+    fn set_deterministic(&mut self, seed: u64) {
+        dispatch_delegate!(self, set_deterministic(seed))
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        dispatch_delegate!(self, capabilities())
+    }
+
     fn num_vars(&self) -> usize {
         dispatch_delegate!(self, num_vars())
     }
@@ -123,6 +151,10 @@ impl Solver for DispatchSolver {
         dispatch_delegate!(self, new_var())
     }
 
+    fn reserve_vars(&mut self, num_vars: usize) {
+        dispatch_delegate!(self, reserve_vars(num_vars))
+    }
+
     fn assume<L>(&mut self, lit: L)
     where
         L: Into<Lit>,
@@ -162,12 +194,15 @@ impl Solver for DispatchSolver {
     {
         dispatch_delegate!(self, value(lit))
     }
+
+    fn is_delegate(&self) -> bool {
+        dispatch_delegate!(self, is_delegate())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::cadical_dynamic::CadicalDynamicSolver;
 
     fn run_test(mut solver: DispatchSolver, use_assumptions: bool) -> color_eyre::Result<()> {
         // Initializing variables
@@ -203,6 +238,106 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_backend_signature() {
+        let solver = DispatchSolver::new_delegate_wrap(SimpleSatSolver::new());
+        let signature = solver.backend_signature();
+        println!("simple-sat signature: {}", signature);
+        assert!(signature.starts_with("simple-sat "));
+
+        for name in ["minisat", "cadical", "kissat"] {
+            let solver = DispatchSolver::by_name(name).unwrap();
+            let signature = solver.backend_signature();
+            println!("{} signature: {}", name, signature);
+            assert!(!signature.is_empty());
+            assert!(signature.contains(name));
+        }
+    }
+
+    #[test]
+    fn test_by_name() {
+        let solver = DispatchSolver::by_name("simple-sat").unwrap();
+        assert!(solver.backend_signature().starts_with("simple-sat "));
+        let solver = DispatchSolver::by_name("CaDiCaL").unwrap();
+        assert!(matches!(solver, DispatchSolver::CadicalDynamic(_)));
+    }
+
+    #[test]
+    fn test_by_name_unknown() {
+        let err = DispatchSolver::by_name("lingeling").unwrap_err();
+        assert!(err.to_string().contains("'lingeling'"), "{}", err);
+    }
+
+    #[test]
+    fn test_capabilities() {
+        let cadical = DispatchSolver::new_cadical().capabilities();
+        assert!(cadical.incremental);
+        assert!(cadical.assumptions);
+
+        let kissat = DispatchSolver::new_kissat().capabilities();
+        assert!(!kissat.incremental);
+        assert!(!kissat.assumptions);
+
+        // Capabilities are forwarded through the delegate:
+        let simple = DispatchSolver::new_delegate_wrap(SimpleSatSolver::new()).capabilities();
+        assert_eq!(simple, Capabilities::INCREMENTAL);
+    }
+
+    #[test]
+    fn test_num_clauses() {
+        let solvers = vec![
+            DispatchSolver::new_delegate_wrap(SimpleSatSolver::new()),
+            DispatchSolver::new_minisat(),
+            DispatchSolver::new_cadical(),
+            DispatchSolver::new_kissat(),
+        ];
+        for mut solver in solvers {
+            let lits: Vec<Lit> = (0..5).map(|_| solver.new_var()).collect();
+            assert_eq!(solver.num_clauses(), 0);
+            // Note: backends may keep units as assignments rather than clauses, so only longer clauses are added.
+            let n = 7;
+            for i in 0..n {
+                match i % 2 {
+                    0 => solver.add_clause([lits[i % 5], -lits[(i + 1) % 5]]),
+                    _ => solver.add_clause(&[-lits[i % 5], lits[(i + 2) % 5], lits[(i + 3) % 5]]),
+                }
+            }
+            assert_eq!(solver.num_clauses(), n, "Bad num_clauses for {}", solver.signature());
+        }
+    }
+
+    #[test]
+    fn test_cached() {
+        let mut solver = DispatchSolver::new_delegate_wrap(SimpleSatSolver::new()).cached();
+        let a = solver.new_var();
+        let b = solver.new_var();
+        solver.add_clause([a, b]);
+
+        solver.assume(-a);
+        assert_eq!(solver.solve(), SolveResponse::Sat);
+        assert_eq!(solver.cache_hits(), 0);
+        solver.assume(-a);
+        assert_eq!(solver.solve(), SolveResponse::Sat);
+        assert_eq!(solver.cache_hits(), 1);
+        assert_eq!(solver.value(a), LitValue::False);
+        assert_eq!(solver.value(b), LitValue::True);
+
+        // Different assumptions are not served from the cache:
+        solver.assume(-b);
+        assert_eq!(solver.solve(), SolveResponse::Sat);
+        assert_eq!(solver.cache_hits(), 1);
+        assert_eq!(solver.cache_misses(), 2);
+        assert_eq!(solver.value(a), LitValue::True);
+        assert_eq!(solver.value(b), LitValue::False);
+
+        // New clause invalidates the cache:
+        solver.add_clause([-b]);
+        solver.assume(-a);
+        assert_eq!(solver.solve(), SolveResponse::Unsat);
+        assert_eq!(solver.cache_hits(), 1);
+        assert_eq!(solver.cache_misses(), 3);
+    }
+
     #[test]
     fn test_dispatch_delegate_minisat() -> color_eyre::Result<()> {
         let solver = DispatchSolver::new_delegate_wrap(MiniSatDynamicSolver::new());
@@ -0,0 +1,97 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Kind of the backend solver, selectable by name (e.g. from the command line).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum SolverKind {
+    MiniSat,
+    Cadical,
+    Kissat,
+    Glucose,
+    Simple,
+}
+
+impl SolverKind {
+    pub const ALL: [SolverKind; 5] = [
+        SolverKind::MiniSat,
+        SolverKind::Cadical,
+        SolverKind::Kissat,
+        SolverKind::Glucose,
+        SolverKind::Simple,
+    ];
+}
+
+impl Display for SolverKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolverKind::MiniSat => write!(f, "minisat"),
+            SolverKind::Cadical => write!(f, "cadical"),
+            SolverKind::Kissat => write!(f, "kissat"),
+            SolverKind::Glucose => write!(f, "glucose"),
+            SolverKind::Simple => write!(f, "simple"),
+        }
+    }
+}
+
+/// Error of parsing an unknown solver name into [`SolverKind`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseSolverKindError {
+    name: String,
+}
+
+impl Display for ParseSolverKindError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Unknown solver '{}', expected one of: {}",
+            self.name,
+            SolverKind::ALL.map(|kind| kind.to_string()).join(", ")
+        )
+    }
+}
+
+impl std::error::Error for ParseSolverKindError {}
+
+impl FromStr for SolverKind {
+    type Err = ParseSolverKindError;
+
+    /// Parse the solver name, case-insensitively.
+    /// Besides the [`Display`] names, `simple-sat` and `simplesat` are accepted for [`SolverKind::Simple`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "minisat" => Ok(SolverKind::MiniSat),
+            "cadical" => Ok(SolverKind::Cadical),
+            "kissat" => Ok(SolverKind::Kissat),
+            "glucose" => Ok(SolverKind::Glucose),
+            "simple" | "simple-sat" | "simplesat" => Ok(SolverKind::Simple),
+            _ => Err(ParseSolverKindError { name: s.to_string() }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solver_kind_from_str() {
+        assert_eq!("minisat".parse(), Ok(SolverKind::MiniSat));
+        assert_eq!("CaDiCaL".parse(), Ok(SolverKind::Cadical));
+        assert_eq!("kissat".parse(), Ok(SolverKind::Kissat));
+        assert_eq!("Glucose".parse(), Ok(SolverKind::Glucose));
+        assert_eq!("simple".parse(), Ok(SolverKind::Simple));
+        assert_eq!("simple-sat".parse(), Ok(SolverKind::Simple));
+        assert_eq!("SimpleSat".parse(), Ok(SolverKind::Simple));
+
+        for kind in SolverKind::ALL {
+            assert_eq!(kind.to_string().parse(), Ok(kind));
+        }
+    }
+
+    #[test]
+    fn test_solver_kind_unknown() {
+        let err = "lingeling".parse::<SolverKind>().unwrap_err().to_string();
+        assert!(err.contains("'lingeling'"));
+        assert!(err.ends_with("minisat, cadical, kissat, glucose, simple"), "{}", err);
+    }
+}
@@ -22,3 +22,6 @@ pub mod kissat_static;
 pub mod simplesat;
 
 pub mod dispatch;
+pub mod kind;
+pub mod prelude;
+mod timing;
@@ -0,0 +1,37 @@
+//! Solver traits, types and the common backends, importable with a single `use`.
+//!
+//! # Examples
+//!
+//! ```
+//! use sat_nexus_wrappers::prelude::*;
+//!
+//! let mut solver = DispatchSolver::new_delegate_wrap(SimpleSatSolver::new());
+//! let a = solver.new_var();
+//! let b = solver.new_var();
+//! // (a or b) and (not a or b) and (a or not b)
+//! solver.add_clause([a, b]);
+//! solver.add_clause([-a, b]);
+//! solver.add_clause([a, -b]);
+//! assert_eq!(solver.solve(), SolveResponse::Sat);
+//! assert_eq!(solver.value(a), LitValue::True);
+//! assert_eq!(solver.value(b), LitValue::True);
+//!
+//! // Solvers can also be selected by name:
+//! let solver = DispatchSolver::by_name("simple").unwrap();
+//! assert!(solver.signature().contains("simple-sat"));
+//! ```
+
+pub use sat_nexus_core::solver::prelude::*;
+
+pub use crate::cadical_dynamic::CadicalDynamicSolver;
+pub use crate::dispatch::DispatchSolver;
+pub use crate::ipasir::IpasirSolver;
+pub use crate::kind::SolverKind;
+pub use crate::kissat_dynamic::KissatDynamicSolver;
+pub use crate::minisat_dynamic::MiniSatDynamicSolver;
+pub use crate::simplesat::SimpleSatSolver;
+
+#[cfg(feature = "cadical-static")]
+pub use crate::cadical_static::CadicalStaticSolver;
+#[cfg(feature = "kissat-static")]
+pub use crate::kissat_static::KissatStaticSolver;
@@ -0,0 +1,72 @@
+use std::time::{Duration, Instant};
+
+/// Durations of the calls to `solve`, tracked by the solver wrappers.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SolveTimer {
+    last: Duration,
+    total: Duration,
+}
+
+impl SolveTimer {
+    pub const fn new() -> Self {
+        Self {
+            last: Duration::ZERO,
+            total: Duration::ZERO,
+        }
+    }
+
+    /// Duration of the last call to `solve`.
+    pub fn last(&self) -> Duration {
+        self.last
+    }
+
+    /// Total duration of all calls to `solve`.
+    pub fn total(&self) -> Duration {
+        self.total
+    }
+
+    /// Run `solve`, recording its duration as the last one and adding it to the total.
+    pub fn time<T, F>(&mut self, solve: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        let time_solve_start = Instant::now();
+        let result = solve();
+        self.last = time_solve_start.elapsed();
+        self.total += self.last;
+        result
+    }
+
+    /// Forget all recorded durations, e.g. when the solver is reset.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_timer() {
+        let mut timer = SolveTimer::new();
+        assert_eq!(timer.last(), Duration::ZERO);
+        assert_eq!(timer.total(), Duration::ZERO);
+
+        let x = timer.time(|| {
+            std::thread::sleep(Duration::from_millis(1));
+            42
+        });
+        assert_eq!(x, 42);
+        let first = timer.last();
+        assert!(first > Duration::ZERO);
+        assert_eq!(timer.total(), first);
+
+        timer.time(|| std::thread::sleep(Duration::from_millis(1)));
+        assert_eq!(timer.total(), first + timer.last());
+
+        timer.reset();
+        assert_eq!(timer.last(), Duration::ZERO);
+        assert_eq!(timer.total(), Duration::ZERO);
+    }
+}
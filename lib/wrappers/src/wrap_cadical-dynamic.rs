@@ -1,16 +1,19 @@
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
+use std::time::Duration;
 
 use itertools::Itertools;
 
 use cadical::dynamic::Cadical;
 use sat_nexus_core::lit::Lit;
-use sat_nexus_core::solver::{LitValue, SolveResponse, Solver};
+use sat_nexus_core::solver::{Capabilities, LitValue, SolveResponse, Solver};
+
+use crate::timing::SolveTimer;
 
 pub struct CadicalDynamicSolver {
     inner: Cadical,
     nvars: usize,
-    nclauses: usize,
+    solve_timer: SolveTimer,
 }
 
 impl CadicalDynamicSolver {
@@ -22,9 +25,19 @@ impl CadicalDynamicSolver {
         Self {
             inner,
             nvars: 0,
-            nclauses: 0,
+            solve_timer: SolveTimer::new(),
         }
     }
+
+    /// Duration of the last call to `solve`.
+    pub fn last_solve_time(&self) -> Duration {
+        self.solve_timer.last()
+    }
+
+    /// Total duration of all calls to `solve`.
+    pub fn total_solve_time(&self) -> Duration {
+        self.solve_timer.total()
+    }
 }
 
 impl Default for CadicalDynamicSolver {
@@ -58,16 +71,26 @@ impl Solver for CadicalDynamicSolver {
 
     fn reset(&mut self) {
         self.inner.reset();
+        self.nvars = 0;
+        self.solve_timer.reset();
     }
     fn release(&mut self) {
         self.inner.release();
     }
 
+    fn set_deterministic(&mut self, seed: u64) {
+        self.inner.set_option("seed", (seed & i32::MAX as u64) as i32);
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::INCREMENTAL
+    }
+
     fn num_vars(&self) -> usize {
         self.nvars
     }
     fn num_clauses(&self) -> usize {
-        self.nclauses
+        self.inner.irredundant() as usize
     }
 
     fn new_var(&mut self) -> Lit {
@@ -75,6 +98,13 @@ impl Solver for CadicalDynamicSolver {
         Lit::new(self.nvars as i32)
     }
 
+    fn reserve_vars(&mut self, num_vars: usize) {
+        if num_vars > self.nvars {
+            self.inner.reserve(num_vars as i32);
+            self.nvars = num_vars;
+        }
+    }
+
     fn assume<L>(&mut self, lit: L)
     where
         L: Into<Lit>,
@@ -87,13 +117,12 @@ impl Solver for CadicalDynamicSolver {
         I: IntoIterator,
         I::Item: Into<Lit>,
     {
-        self.nclauses += 1;
         self.inner.add_clause(lits.into_iter().map_into::<Lit>());
     }
 
     fn solve(&mut self) -> SolveResponse {
         use cadical::SolveResponse as CadicalSolveResponse;
-        match self.inner.solve() {
+        match self.solve_timer.time(|| self.inner.solve()) {
             Ok(CadicalSolveResponse::Sat) => SolveResponse::Sat,
             Ok(CadicalSolveResponse::Unsat) => SolveResponse::Unsat,
             Ok(CadicalSolveResponse::Interrupted) => SolveResponse::Unknown,
@@ -157,4 +186,23 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_solve_time() {
+        let mut solver = CadicalDynamicSolver::new();
+        solver.add_clause([1, 2]);
+        solver.add_clause([-1, -2]);
+        assert_eq!(solver.last_solve_time(), Duration::ZERO);
+
+        assert_eq!(solver.solve(), SolveResponse::Sat);
+        let first = solver.last_solve_time();
+        assert!(first > Duration::ZERO);
+        assert_eq!(solver.total_solve_time(), first);
+
+        solver.add_clause([1]);
+        assert_eq!(solver.solve(), SolveResponse::Sat);
+        assert!(solver.last_solve_time() > Duration::ZERO);
+        assert!(solver.total_solve_time() >= solver.last_solve_time());
+        assert_eq!(solver.total_solve_time(), first + solver.last_solve_time());
+    }
 }
@@ -1,16 +1,19 @@
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
+use std::time::Duration;
 
 use itertools::Itertools;
 
 use cadical::statik::Cadical;
 use sat_nexus_core::lit::Lit;
-use sat_nexus_core::solver::{LitValue, SolveResponse, Solver};
+use sat_nexus_core::solver::{Capabilities, LitValue, SolveResponse, Solver};
+
+use crate::timing::SolveTimer;
 
 pub struct CadicalStaticSolver {
     inner: Cadical,
     nvars: usize,
-    nclauses: usize,
+    solve_timer: SolveTimer,
 }
 
 impl CadicalStaticSolver {
@@ -18,9 +21,19 @@ impl CadicalStaticSolver {
         Self {
             inner,
             nvars: 0,
-            nclauses: 0,
+            solve_timer: SolveTimer::new(),
         }
     }
+
+    /// Duration of the last call to `solve`.
+    pub fn last_solve_time(&self) -> Duration {
+        self.solve_timer.last()
+    }
+
+    /// Total duration of all calls to `solve`.
+    pub fn total_solve_time(&self) -> Duration {
+        self.solve_timer.total()
+    }
 }
 
 impl Default for CadicalStaticSolver {
@@ -54,16 +67,29 @@ impl Solver for CadicalStaticSolver {
 
     fn reset(&mut self) {
         self.inner.reset();
+        self.nvars = 0;
+        self.solve_timer.reset();
     }
     fn release(&mut self) {
         self.inner.release();
     }
 
+    fn set_deterministic(&mut self, seed: u64) {
+        self.inner.set_option("seed", (seed & i32::MAX as u64) as i32);
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            proofs: true,
+            ..Capabilities::INCREMENTAL
+        }
+    }
+
     fn num_vars(&self) -> usize {
         self.nvars
     }
     fn num_clauses(&self) -> usize {
-        self.nclauses
+        self.inner.irredundant() as usize
     }
 
     fn new_var(&mut self) -> Lit {
@@ -71,6 +97,13 @@ impl Solver for CadicalStaticSolver {
         Lit::new(self.nvars as i32)
     }
 
+    fn reserve_vars(&mut self, num_vars: usize) {
+        if num_vars > self.nvars {
+            self.inner.reserve(num_vars as i32);
+            self.nvars = num_vars;
+        }
+    }
+
     fn assume<L>(&mut self, lit: L)
     where
         L: Into<Lit>,
@@ -83,13 +116,12 @@ impl Solver for CadicalStaticSolver {
         I: IntoIterator,
         I::Item: Into<Lit>,
     {
-        self.nclauses += 1;
         self.inner.add_clause(lits.into_iter().map_into::<Lit>());
     }
 
     fn solve(&mut self) -> SolveResponse {
         use cadical::SolveResponse as CadicalSolveResponse;
-        match self.inner.solve() {
+        match self.solve_timer.time(|| self.inner.solve()) {
             Ok(CadicalSolveResponse::Sat) => SolveResponse::Sat,
             Ok(CadicalSolveResponse::Unsat) => SolveResponse::Unsat,
             Ok(CadicalSolveResponse::Interrupted) => SolveResponse::Unknown,
@@ -153,4 +185,33 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_bootstrap_reserves_vars() {
+        use sat_nexus_core::cnf::Cnf;
+        use sat_nexus_core::utils::bootstrap_solver_from_cnf;
+
+        // (x1 or x2) and (~x1 or x7) and (~x2) and (~x7 or x3)
+        let cnf = Cnf::from_iter([vec![1, 2], vec![-1, 7], vec![-2], vec![-7, 3]]);
+
+        let mut solver = CadicalStaticSolver::default();
+        bootstrap_solver_from_cnf(&mut solver, &cnf);
+        assert_eq!(solver.num_vars(), 7);
+        assert_eq!(solver.inner.vars(), 7);
+
+        // Same result as for a solver fed without reserving variables:
+        let mut plain = CadicalStaticSolver::default();
+        for clause in cnf.iter() {
+            plain.add_clause(&clause.lits);
+        }
+        assert_eq!(solver.solve(), SolveResponse::Sat);
+        assert_eq!(plain.solve(), SolveResponse::Sat);
+        for lit in [1, 2, 3, 7] {
+            assert_eq!(solver.value(lit), plain.value(lit));
+        }
+
+        // Solving does not grow the variables beyond `max_var`:
+        assert_eq!(solver.inner.vars(), 7);
+        assert_eq!(solver.new_var().get(), 8);
+    }
 }
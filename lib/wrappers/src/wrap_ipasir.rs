@@ -1,17 +1,21 @@
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
+use std::time::Duration;
 
 use itertools::Itertools;
 use tap::Pipe;
 
 use ipasir::Ipasir;
 use sat_nexus_core::lit::Lit;
-use sat_nexus_core::solver::{LitValue, SolveResponse, Solver};
+use sat_nexus_core::solver::{Capabilities, LitValue, SolveResponse, Solver};
+
+use crate::timing::SolveTimer;
 
 pub struct IpasirSolver {
     inner: Ipasir,
     nvars: usize,
     nclauses: usize,
+    solve_timer: SolveTimer,
 }
 
 impl IpasirSolver {
@@ -20,6 +24,7 @@ impl IpasirSolver {
             inner,
             nvars: 0,
             nclauses: 0,
+            solve_timer: SolveTimer::new(),
         }
     }
 
@@ -32,6 +37,21 @@ impl IpasirSolver {
     pub fn new_glucose() -> Self {
         Self::new(Ipasir::new_glucose())
     }
+
+    /// Name of the loaded IPASIR solver, e.g. `"cadical"`.
+    pub fn backend_name(&self) -> &'static str {
+        self.inner.backend_name()
+    }
+
+    /// Duration of the last call to `solve`.
+    pub fn last_solve_time(&self) -> Duration {
+        self.solve_timer.last()
+    }
+
+    /// Total duration of all calls to `solve`.
+    pub fn total_solve_time(&self) -> Duration {
+        self.solve_timer.total()
+    }
 }
 
 impl From<Ipasir> for IpasirSolver {
@@ -59,11 +79,18 @@ impl Solver for IpasirSolver {
 
     fn reset(&mut self) {
         self.inner.reset();
+        self.nvars = 0;
+        self.nclauses = 0;
+        self.solve_timer.reset();
     }
     fn release(&mut self) {
         self.inner.release();
     }
 
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::INCREMENTAL
+    }
+
     fn num_vars(&self) -> usize {
         self.nvars
     }
@@ -93,7 +120,7 @@ impl Solver for IpasirSolver {
     }
 
     fn solve(&mut self) -> SolveResponse {
-        match self.inner.solve() {
+        match self.solve_timer.time(|| self.inner.solve()) {
             Ok(ipasir::SolveResponse::Sat) => SolveResponse::Sat,
             Ok(ipasir::SolveResponse::Unsat) => SolveResponse::Unsat,
             Ok(ipasir::SolveResponse::Interrupted) => SolveResponse::Unknown,
@@ -164,4 +191,10 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_backend_name() {
+        let solver = IpasirSolver::new_cadical();
+        assert!(solver.backend_name().starts_with("cadical"));
+    }
 }
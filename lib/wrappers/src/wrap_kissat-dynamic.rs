@@ -1,16 +1,20 @@
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
+use std::time::Duration;
 
 use itertools::Itertools;
 
 use kissat::dynamic::Kissat;
 use sat_nexus_core::lit::Lit;
-use sat_nexus_core::solver::{LitValue, SolveResponse, Solver};
+use sat_nexus_core::solver::{Capabilities, LitValue, SolveResponse, Solver};
+
+use crate::timing::SolveTimer;
 
 pub struct KissatDynamicSolver {
     inner: Kissat,
     nvars: usize,
     nclauses: usize,
+    solve_timer: SolveTimer,
 }
 
 impl KissatDynamicSolver {
@@ -23,8 +27,19 @@ impl KissatDynamicSolver {
             inner,
             nvars: 0,
             nclauses: 0,
+            solve_timer: SolveTimer::new(),
         }
     }
+
+    /// Duration of the last call to `solve`.
+    pub fn last_solve_time(&self) -> Duration {
+        self.solve_timer.last()
+    }
+
+    /// Total duration of all calls to `solve`.
+    pub fn total_solve_time(&self) -> Duration {
+        self.solve_timer.total()
+    }
 }
 
 impl Default for KissatDynamicSolver {
@@ -58,11 +73,23 @@ impl Solver for KissatDynamicSolver {
 
     fn reset(&mut self) {
         self.inner.reset();
+        self.nvars = 0;
+        self.nclauses = 0;
+        self.solve_timer.reset();
     }
     fn release(&mut self) {
         self.inner.release();
     }
 
+    fn set_deterministic(&mut self, seed: u64) {
+        self.inner.set_option("seed", (seed & i32::MAX as u64) as i32);
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        // Kissat supports neither assumptions nor incremental solving.
+        Capabilities::NONE
+    }
+
     fn num_vars(&self) -> usize {
         self.nvars
     }
@@ -75,6 +102,13 @@ impl Solver for KissatDynamicSolver {
         Lit::new(self.nvars as i32)
     }
 
+    fn reserve_vars(&mut self, num_vars: usize) {
+        if num_vars > self.nvars {
+            self.inner.reserve(num_vars as i32);
+            self.nvars = num_vars;
+        }
+    }
+
     fn assume<L>(&mut self, _lit: L)
     where
         L: Into<Lit>,
@@ -93,7 +127,8 @@ impl Solver for KissatDynamicSolver {
 
     fn solve(&mut self) -> SolveResponse {
         use kissat::SolveResponse as KissatSolveResponse;
-        match self.inner.solve() {
+        let response = self.solve_timer.time(|| self.inner.solve());
+        match response.unwrap_or_else(|e| panic!("{}", e)) {
             KissatSolveResponse::Sat => SolveResponse::Sat,
             KissatSolveResponse::Unsat => SolveResponse::Unsat,
             KissatSolveResponse::Interrupted => SolveResponse::Unknown,
@@ -106,9 +141,10 @@ impl Solver for KissatDynamicSolver {
     {
         use kissat::LitValue as KissatLitValue;
         match self.inner.value(lit.into().into()) {
-            KissatLitValue::True => LitValue::True,
-            KissatLitValue::False => LitValue::False,
-            KissatLitValue::Any => LitValue::DontCare,
+            Ok(KissatLitValue::True) => LitValue::True,
+            Ok(KissatLitValue::False) => LitValue::False,
+            Ok(KissatLitValue::Any) => LitValue::DontCare,
+            Err(e) => panic!("Could not get literal value: {}", e),
         }
     }
 }
@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
+use std::time::Duration;
 
 use itertools::Itertools;
 use tap::Pipe;
@@ -7,11 +8,14 @@ use tap::Pipe;
 use minisat::dynamic::Lit as MiniSatLit;
 use minisat::dynamic::{LBool, MiniSat};
 use sat_nexus_core::lit::Lit;
-use sat_nexus_core::solver::{LitValue, SolveResponse, Solver};
+use sat_nexus_core::solver::{Capabilities, LitValue, SolveResponse, Solver};
+
+use crate::timing::SolveTimer;
 
 pub struct MiniSatDynamicSolver {
     inner: MiniSat,
     assumptions: Vec<MiniSatLit>,
+    solve_timer: SolveTimer,
 }
 
 impl MiniSatDynamicSolver {
@@ -23,8 +27,19 @@ impl MiniSatDynamicSolver {
         Self {
             inner,
             assumptions: Vec::new(),
+            solve_timer: SolveTimer::new(),
         }
     }
+
+    /// Duration of the last call to `solve`.
+    pub fn last_solve_time(&self) -> Duration {
+        self.solve_timer.last()
+    }
+
+    /// Total duration of all calls to `solve`.
+    pub fn total_solve_time(&self) -> Duration {
+        self.solve_timer.total()
+    }
 }
 
 impl Default for MiniSatDynamicSolver {
@@ -58,11 +73,22 @@ impl Solver for MiniSatDynamicSolver {
 
     fn reset(&mut self) {
         self.inner.reset();
+        self.assumptions.clear();
+        self.solve_timer.reset();
     }
     fn release(&mut self) {
         self.inner.release();
     }
 
+    fn set_deterministic(&mut self, _seed: u64) {
+        // Note: MiniSat only uses its random generator with non-default options
+        //  (`rnd-freq`, `rnd-init`), which are not exposed here, so it is deterministic already.
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::INCREMENTAL
+    }
+
     fn num_vars(&self) -> usize {
         self.inner.num_vars() as usize
     }
@@ -92,7 +118,8 @@ impl Solver for MiniSatDynamicSolver {
     fn solve(&mut self) -> SolveResponse {
         // let assumptions = std::mem::replace(&mut self.assumptions, Vec::new());
         // match self.inner.solve_under_assumptions(assumptions) {
-        match self.inner.solve_under_assumptions(self.assumptions.drain(..)) {
+        let assumptions = self.assumptions.drain(..);
+        match self.solve_timer.time(|| self.inner.solve_under_assumptions(assumptions)) {
             true => SolveResponse::Sat,
             false => SolveResponse::Unsat,
         }
@@ -103,19 +130,16 @@ impl Solver for MiniSatDynamicSolver {
         L: Into<Lit>,
     {
         match self.inner.model_value_lit(lit.into().pipe(to_ms)) {
-            LBool::True => LitValue::True,
-            LBool::False => LitValue::False,
-            LBool::Undef => panic!("model_value_lit returned Undef"),
+            Ok(LBool::True) => LitValue::True,
+            Ok(LBool::False) => LitValue::False,
+            Ok(LBool::Undef) => panic!("model_value_lit returned Undef"),
+            Err(e) => panic!("Could not get literal value: {}", e),
         }
     }
 }
 
 fn to_ms(lit: Lit) -> MiniSatLit {
-    let lit = lit.get();
-    debug_assert_ne!(lit, 0, "Literal must be non-zero");
-    let var = (lit.abs() - 1) as _; // 0-based variable index
-    let sign = if lit > 0 { 0 } else { 1 }; // 0 if positive, 1 if negative
-    MiniSatLit::mk(var, sign)
+    MiniSatLit::from_external(lit.get())
 }
 
 fn from_ms(lit: MiniSatLit) -> Lit {
@@ -1,17 +1,24 @@
 use std::borrow::Cow;
+use std::cell::Cell;
 use std::fmt::{Debug, Display, Formatter};
+use std::time::Duration;
 
 use itertools::Itertools;
 use tap::Pipe;
 
 use sat_nexus_core::lit::Lit;
-use sat_nexus_core::solver::{LitValue, SolveResponse, Solver};
+use sat_nexus_core::solver::{Capabilities, LitValue, SolveResponse, Solver};
 use simple_sat::lit::Lit as SimpleSatLit;
 use simple_sat::solver::Solver as SimpleSat;
 
+use crate::timing::SolveTimer;
+
 pub struct SimpleSatSolver {
     inner: SimpleSat,
     assumptions: Vec<SimpleSatLit>,
+    /// Response of the last `solve`, reset when clauses or assumptions are added.
+    last_response: Cell<Option<SolveResponse>>,
+    solve_timer: SolveTimer,
 }
 
 impl SimpleSatSolver {
@@ -23,8 +30,20 @@ impl SimpleSatSolver {
         Self {
             inner,
             assumptions: Vec::new(),
+            last_response: Cell::new(None),
+            solve_timer: SolveTimer::new(),
         }
     }
+
+    /// Duration of the last call to `solve`.
+    pub fn last_solve_time(&self) -> Duration {
+        self.solve_timer.last()
+    }
+
+    /// Total duration of all calls to `solve`.
+    pub fn total_solve_time(&self) -> Duration {
+        self.solve_timer.total()
+    }
 }
 
 impl Default for SimpleSatSolver {
@@ -53,15 +72,26 @@ impl Display for SimpleSatSolver {
 
 impl Solver for SimpleSatSolver {
     fn signature(&self) -> Cow<str> {
-        "simple-sat".into()
+        format!("simple-sat {}", simple_sat::VERSION).into()
     }
 
     fn reset(&mut self) {
         self.inner.reset();
+        self.assumptions.clear();
+        self.last_response.set(None);
+        self.solve_timer.reset();
     }
     fn release(&mut self) {
-        todo!()
-        // self.inner.release();
+        // Note: simple-sat holds no external resources, so releasing just drops all its data.
+        self.reset();
+    }
+
+    fn set_deterministic(&mut self, _seed: u64) {
+        // Note: simple-sat is deterministic by construction, nothing to do here.
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::INCREMENTAL
     }
 
     fn num_vars(&self) -> usize {
@@ -77,10 +107,18 @@ impl Solver for SimpleSatSolver {
         from_ss(lit)
     }
 
+    fn reserve_vars(&mut self, num_vars: usize) {
+        self.inner.reserve(num_vars);
+        while self.num_vars() < num_vars {
+            self.new_var();
+        }
+    }
+
     fn assume<L>(&mut self, lit: L)
     where
         L: Into<Lit>,
     {
+        self.last_response.set(None);
         self.assumptions.push(lit.into().pipe(to_ss));
     }
 
@@ -89,6 +127,7 @@ impl Solver for SimpleSatSolver {
         I: IntoIterator,
         I::Item: Into<Lit>,
     {
+        self.last_response.set(None);
         let lits = lits.into_iter().map_into::<Lit>().map(to_ss).collect_vec();
         self.inner.add_clause(&lits);
     }
@@ -96,11 +135,13 @@ impl Solver for SimpleSatSolver {
     fn solve(&mut self) -> SolveResponse {
         use simple_sat::solver::SolveResult;
         let assumptions = std::mem::take(&mut self.assumptions);
-        match self.inner.solve_under_assumptions(&assumptions) {
+        let response = match self.solve_timer.time(|| self.inner.solve_under_assumptions(&assumptions)) {
             SolveResult::Sat => SolveResponse::Sat,
             SolveResult::Unsat => SolveResponse::Unsat,
             SolveResult::Unknown => SolveResponse::Unknown,
-        }
+        };
+        self.last_response.set(Some(response));
+        response
     }
 
     fn value<L>(&self, lit: L) -> LitValue
@@ -108,10 +149,14 @@ impl Solver for SimpleSatSolver {
         L: Into<Lit>,
     {
         use simple_sat::lbool::LBool;
+        // Without a model (no SAT since the last modification), the assignment is meaningless.
+        if self.last_response.get() != Some(SolveResponse::Sat) {
+            return LitValue::DontCare;
+        }
         match self.inner.value(lit.into().pipe(to_ss)) {
             LBool::True => LitValue::True,
             LBool::False => LitValue::False,
-            LBool::Undef => panic!("value returned Undef"),
+            LBool::Undef => LitValue::DontCare,
         }
     }
 }
@@ -171,4 +216,81 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_value_before_solve() {
+        let mut solver = SimpleSatSolver::new();
+        solver.add_clause([1, 2]);
+        assert_eq!(solver.value(1), LitValue::DontCare);
+    }
+
+    #[test]
+    fn test_value_after_modification() {
+        let mut solver = SimpleSatSolver::new();
+        solver.add_clause([1, 2]);
+        assert_eq!(solver.solve(), SolveResponse::Sat);
+        assert_ne!(solver.value(1), LitValue::DontCare);
+        solver.add_clause([-1, -2]);
+        assert_eq!(solver.value(1), LitValue::DontCare);
+    }
+
+    #[test]
+    fn test_value_after_unsat() {
+        let mut solver = SimpleSatSolver::new();
+        solver.add_clause([1]);
+        solver.assume(-1);
+        assert_eq!(solver.solve(), SolveResponse::Unsat);
+        assert_eq!(solver.value(1), LitValue::DontCare);
+    }
+
+    #[test]
+    fn test_release() {
+        let mut solver = SimpleSatSolver::new();
+        solver.add_clause([1, 2]);
+        assert_eq!(solver.solve(), SolveResponse::Sat);
+        solver.release();
+        assert_eq!(solver.num_vars(), 0);
+        assert_eq!(solver.num_clauses(), 0);
+        assert_eq!(solver.value(1), LitValue::DontCare);
+    }
+
+    #[test]
+    fn test_solve_time() {
+        let mut solver = SimpleSatSolver::new();
+        solver.add_clause([1, 2]);
+        solver.add_clause([-1, -2]);
+        assert_eq!(solver.last_solve_time(), Duration::ZERO);
+
+        assert_eq!(solver.solve(), SolveResponse::Sat);
+        let first = solver.last_solve_time();
+        assert!(first > Duration::ZERO);
+        assert_eq!(solver.total_solve_time(), first);
+
+        solver.add_clause([1]);
+        assert_eq!(solver.solve(), SolveResponse::Sat);
+        assert!(solver.last_solve_time() > Duration::ZERO);
+        assert!(solver.total_solve_time() >= solver.last_solve_time());
+        assert_eq!(solver.total_solve_time(), first + solver.last_solve_time());
+    }
+
+    #[test]
+    fn test_assume_all() {
+        let mut solver = SimpleSatSolver::new();
+        // (1 or 2) and (3 or 4) and not(1 and 2) and not(3 and 4)
+        solver.add_clause([1, 2]);
+        solver.add_clause([3, 4]);
+        solver.add_clause([-1, -2]);
+        solver.add_clause([-3, -4]);
+
+        for assumptions in [vec![1, 2], vec![1, 3], vec![-1, -3, 4], vec![-3, -4], vec![]] {
+            for &lit in assumptions.iter() {
+                solver.assume(lit);
+            }
+            let expected = solver.solve();
+
+            solver.assume_all(assumptions.iter().copied());
+            let response = solver.solve();
+            assert_eq!(response, expected, "Mismatch under assumptions {:?}", assumptions);
+        }
+    }
 }
@@ -0,0 +1,34 @@
+use itertools::Itertools;
+
+use sat_nexus::core::card::amo::AmoEncoding;
+use sat_nexus::core::card::Cardinality;
+use sat_nexus::core::op::allsat::AllSat;
+use sat_nexus::core::solver::ext::SolverExt;
+use sat_nexus::core::solver::Solver;
+use sat_nexus::wrappers::ipasir::IpasirSolver;
+
+#[test]
+fn test_at_most_one_encodings() {
+    for encoding in [AmoEncoding::Pairwise, AmoEncoding::Commander, AmoEncoding::Product] {
+        for n in 1..=12 {
+            let mut solver = IpasirSolver::new_cadical();
+            let lits = solver.new_var_vec(n);
+            solver.encode_at_most_one_with(&lits, encoding);
+
+            let solutions = solver
+                .all_sat_essential(lits.clone(), |solver| lits.iter().map(|&x| solver.value(x).bool()).collect_vec())
+                .collect_vec();
+            for solution in solutions.iter() {
+                assert!(
+                    solution.iter().filter(|&&x| x).count() <= 1,
+                    "{} (n = {}): {:?}",
+                    encoding,
+                    n,
+                    solution
+                );
+            }
+            // All-false and each single-hot assignment:
+            assert_eq!(solutions.len(), n + 1, "{} (n = {})", encoding, n);
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use sat_nexus::core::solver::simple::SimpleSolver;
+use sat_nexus::core::solver::wrap::WrapSolver;
+use sat_nexus::wrappers::cadical_dynamic::CadicalDynamicSolver;
+use sat_nexus::wrappers::cadical_static::CadicalStaticSolver;
+use sat_nexus::wrappers::ipasir::IpasirSolver;
+use sat_nexus::wrappers::kissat_dynamic::KissatDynamicSolver;
+use sat_nexus::wrappers::kissat_static::KissatStaticSolver;
+use sat_nexus::wrappers::minisat_dynamic::MiniSatDynamicSolver;
+use sat_nexus::wrappers::simplesat::SimpleSatSolver;
+use sat_nexus_test_utils::run_test_simple;
+
+#[test]
+fn test_boxed_via_into() -> color_eyre::Result<()> {
+    let solvers: Vec<(Box<dyn SimpleSolver>, bool)> = vec![
+        (MiniSatDynamicSolver::new().into(), true),
+        (CadicalDynamicSolver::new().into(), true),
+        (KissatDynamicSolver::new().into(), false),
+        (IpasirSolver::new_glucose().into(), true),
+        (SimpleSatSolver::new().into(), true),
+        (CadicalStaticSolver::default().into(), true),
+        (KissatStaticSolver::default().into(), false),
+    ];
+    for (mut solver, use_assumptions) in solvers {
+        run_test_simple(solver.as_mut(), use_assumptions)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_boxed() -> color_eyre::Result<()> {
+    let solvers = vec![
+        (WrapSolver::new(MiniSatDynamicSolver::new()).boxed(), true),
+        (WrapSolver::new(CadicalDynamicSolver::new()).boxed(), true),
+        (WrapSolver::new(KissatDynamicSolver::new()).boxed(), false),
+        (WrapSolver::new(IpasirSolver::new_glucose()).boxed(), true),
+        (WrapSolver::new(SimpleSatSolver::new()).boxed(), true),
+        (WrapSolver::new(CadicalStaticSolver::default()).boxed(), true),
+        (WrapSolver::new(KissatStaticSolver::default()).boxed(), false),
+    ];
+    for (mut solver, use_assumptions) in solvers {
+        run_test_simple(solver.as_mut(), use_assumptions)?;
+    }
+    Ok(())
+}
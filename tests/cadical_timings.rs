@@ -0,0 +1,19 @@
+use std::time::Duration;
+
+use cadical::statik::Cadical;
+use sat_nexus::core::solver::SolveResponse;
+use sat_nexus::wrappers::cadical_static::CadicalStaticSolver;
+use sat_nexus_test_utils::{pigeonhole, time_bootstrap_and_solve};
+
+#[test]
+fn test_cadical_bootstrap_and_solve_timings() {
+    let cnf = pigeonhole(7, 6);
+    let mut solver = CadicalStaticSolver::new(Cadical::new());
+    let timings = time_bootstrap_and_solve(&mut solver, &cnf);
+    println!("timings = {:?}", timings);
+    assert_eq!(timings.response, SolveResponse::Unsat);
+
+    // Very coarse bounds, only to catch gross regressions (e.g. quadratic clause addition):
+    assert!(timings.bootstrap < Duration::from_secs(1));
+    assert!(timings.solve < Duration::from_secs(60));
+}
@@ -0,0 +1,60 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use sat_nexus::core::count::{approx_model_count_with_rng, exact_model_count, exact_model_count_with_limit};
+use sat_nexus::core::solver::ext::SolverExt;
+use sat_nexus::core::solver::Solver;
+use sat_nexus::wrappers::cadical_static::CadicalStaticSolver;
+
+#[test]
+fn test_approx_model_count() {
+    let mut solver = CadicalStaticSolver::default();
+
+    let n = 12;
+    let lits = solver.new_var_vec(n);
+    // (x1 or x2) and (-x3 or -x4) leave 3*3*2^8 = 2304 models:
+    solver.add_clause([lits[0], lits[1]]);
+    solver.add_clause([-lits[2], -lits[3]]);
+    let exact = 2304.0;
+
+    let vars: Vec<i32> = (1..=n as i32).collect();
+    let mut rng = StdRng::seed_from_u64(42);
+    let estimate = approx_model_count_with_rng(&mut solver, &vars, 64, 9, &mut rng);
+    println!("estimate = {}, exact = {}", estimate, exact);
+    assert!(estimate >= exact / 2.0 && estimate <= exact * 2.0, "Bad estimate {}", estimate);
+
+    // Hash and blocking clauses are disabled, so all models are still there:
+    solver.assume(lits[0]);
+    solver.assume(lits[1]);
+    assert!(solver.solve().is_sat());
+}
+
+#[test]
+fn test_exact_model_count() {
+    let mut solver = CadicalStaticSolver::default();
+
+    let lits = solver.new_var_vec(3);
+    // (x1 or x2) and (x1 or x3) has 4 models with x1, and 1 model without x1:
+    solver.add_clause([lits[0], lits[1]]);
+    solver.add_clause([lits[0], lits[2]]);
+
+    assert_eq!(exact_model_count(&mut solver, &[1, 2, 3]), 5);
+    // Counting again gives the same result, since the blocking clauses are disabled:
+    assert_eq!(exact_model_count(&mut solver, &[1, 2, 3]), 5);
+    // Projected onto x1, both values are possible:
+    assert_eq!(exact_model_count(&mut solver, &[1]), 2);
+}
+
+#[test]
+fn test_exact_model_count_limit() {
+    let mut solver = CadicalStaticSolver::default();
+
+    // 20 unconstrained variables have 2^20 models:
+    let lits = solver.new_var_vec(20);
+    let x = lits[19];
+    solver.add_clause([x, -x]);
+
+    let vars: Vec<i32> = (1..=20).collect();
+    assert_eq!(exact_model_count_with_limit(&mut solver, &vars, 100), None);
+    assert_eq!(exact_model_count_with_limit(&mut solver, &vars[..5], 100), Some(32));
+}
@@ -0,0 +1,44 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use sat_nexus::core::cnf::Cnf;
+use sat_nexus::core::solver::{SolveResponse, Solver};
+use sat_nexus::core::utils::{bootstrap_solver_from_cnf, random_3sat_with_ratio};
+use sat_nexus::wrappers::cadical_static::CadicalStaticSolver;
+use sat_nexus::wrappers::dispatch::DispatchSolver;
+use sat_nexus::wrappers::kissat_static::KissatStaticSolver;
+use sat_nexus::wrappers::minisat_dynamic::MiniSatDynamicSolver;
+use sat_nexus::wrappers::simplesat::SimpleSatSolver;
+
+/// Solve the `cnf` in deterministic mode and return the model as a string of `0`s and `1`s.
+fn solve_deterministic<S: Solver>(mut solver: S, cnf: &Cnf, seed: u64) -> String {
+    solver.set_deterministic(seed);
+    bootstrap_solver_from_cnf(&mut solver, cnf);
+    assert_eq!(solver.solve(), SolveResponse::Sat);
+    (1..=cnf.max_var as i32).map(|v| solver.value(v).to_string()).collect()
+}
+
+#[test]
+fn test_deterministic_models() {
+    // Well below the threshold, so the instance is satisfiable with many models:
+    let mut rng = StdRng::seed_from_u64(42);
+    let cnf = random_3sat_with_ratio(200, 3.0, &mut rng);
+
+    for seed in [0, 7, 1 << 40] {
+        let first = solve_deterministic(CadicalStaticSolver::default(), &cnf, seed);
+        let second = solve_deterministic(CadicalStaticSolver::default(), &cnf, seed);
+        assert_eq!(first, second, "CaDiCaL models differ for seed {}", seed);
+
+        let first = solve_deterministic(KissatStaticSolver::default(), &cnf, seed);
+        let second = solve_deterministic(KissatStaticSolver::default(), &cnf, seed);
+        assert_eq!(first, second, "Kissat models differ for seed {}", seed);
+
+        let first = solve_deterministic(MiniSatDynamicSolver::default(), &cnf, seed);
+        let second = solve_deterministic(MiniSatDynamicSolver::default(), &cnf, seed);
+        assert_eq!(first, second, "MiniSat models differ for seed {}", seed);
+
+        let first = solve_deterministic(DispatchSolver::new_delegate_wrap(SimpleSatSolver::new()), &cnf, seed);
+        let second = solve_deterministic(DispatchSolver::new_delegate_wrap(SimpleSatSolver::new()), &cnf, seed);
+        assert_eq!(first, second, "simple-sat models differ for seed {}", seed);
+    }
+}
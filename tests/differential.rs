@@ -0,0 +1,26 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use sat_nexus::core::solver::SolveResponse;
+use sat_nexus::core::utils::{random_3sat_with_ratio, RANDOM_3SAT_THRESHOLD};
+use sat_nexus_test_utils::differential_check;
+
+#[test]
+fn test_differential_random_3sat() {
+    // Near the threshold, so both SAT and UNSAT instances occur:
+    let num_vars = 20;
+    let mut num_sat = 0;
+    let mut num_unsat = 0;
+    for seed in 0..300 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let cnf = random_3sat_with_ratio(num_vars, RANDOM_3SAT_THRESHOLD, &mut rng);
+        match differential_check(&cnf) {
+            SolveResponse::Sat => num_sat += 1,
+            SolveResponse::Unsat => num_unsat += 1,
+            response => panic!("Unexpected response {} for seed {}", response, seed),
+        }
+    }
+    println!("SAT: {}, UNSAT: {}", num_sat, num_unsat);
+    assert!(num_sat > 0);
+    assert!(num_unsat > 0);
+}
@@ -0,0 +1,57 @@
+use sat_nexus::core::lit::Lit;
+use sat_nexus::core::op::optimize::Optimize;
+use sat_nexus::core::solver::{SolveResponse, Solver};
+use sat_nexus::wrappers::cadical_static::CadicalStaticSolver;
+use sat_nexus::wrappers::simplesat::SimpleSatSolver;
+
+fn check_minimize_sum<S: Solver>(mut solver: S) {
+    // Vertex cover of the 5-cycle 1-2-3-4-5-1, the minimum is 3
+    let edges = [(1, 2), (2, 3), (3, 4), (4, 5), (5, 1)];
+    for (a, b) in edges {
+        solver.add_clause([a, b]);
+    }
+
+    let objective = (1..=5).map(Lit::new).collect::<Vec<_>>();
+    let (cost, model) = solver.minimize_sum(&objective).unwrap();
+    println!("cost = {}, model = {:?}", cost, model);
+    assert_eq!(cost, 3);
+    assert_eq!(model.len(), 5);
+    assert_eq!(model.iter().filter(|lit| lit.get() > 0).count(), 3);
+    for (a, b) in edges {
+        assert!(model[a as usize - 1].get() > 0 || model[b as usize - 1].get() > 0);
+    }
+
+    // The bound is only assumed, so the formula is still satisfiable with all vertices
+    solver.assume_all(objective);
+    assert_eq!(solver.solve(), SolveResponse::Sat);
+}
+
+#[test]
+fn test_minimize_sum_cadical() {
+    check_minimize_sum(CadicalStaticSolver::new());
+}
+
+#[test]
+fn test_minimize_sum_simple() {
+    check_minimize_sum(SimpleSatSolver::new());
+}
+
+#[test]
+fn test_minimize_sum_unknown_vars() {
+    let mut solver = CadicalStaticSolver::new();
+    solver.add_clause([1, 2]);
+
+    // Variable 3 was never seen by the solver, so it is simply set to false:
+    let (cost, model) = solver.minimize_sum(&[Lit::new(1), Lit::new(2), Lit::new(3)]).unwrap();
+    assert_eq!(cost, 1);
+    assert_eq!(model.len(), 3);
+    assert_eq!(model[2], Lit::new(-3));
+}
+
+#[test]
+fn test_minimize_sum_unsat() {
+    let mut solver = CadicalStaticSolver::new();
+    solver.add_clause([1]);
+    solver.add_clause([-1]);
+    assert_eq!(solver.minimize_sum(&[Lit::new(1)]), None);
+}
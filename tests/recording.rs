@@ -0,0 +1,48 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use sat_nexus::core::solver::delegate::RecordingSolver;
+use sat_nexus::core::solver::Solver;
+use sat_nexus::core::utils::{bootstrap_solver_from_cnf, random_3sat_with_ratio};
+use sat_nexus::wrappers::cadical_static::CadicalStaticSolver;
+use sat_nexus::wrappers::simplesat::SimpleSatSolver;
+
+#[test]
+fn test_recorded_cnf_reproduces_response() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let cnf = random_3sat_with_ratio(50, 4.0, &mut rng);
+
+    let mut solver = RecordingSolver::new(SimpleSatSolver::new());
+    bootstrap_solver_from_cnf(&mut solver, &cnf);
+    for assumptions in [vec![], vec![1, -2, 3], vec![-1, -2, -3, -4, -5, 6, 7, 8]] {
+        solver.assume_all(assumptions);
+        let response = solver.solve();
+
+        // Replay the recorded instance in a fresh solver:
+        let mut fresh = CadicalStaticSolver::default();
+        bootstrap_solver_from_cnf(&mut fresh, solver.recorded_cnf());
+        fresh.assume_all(solver.recorded_assumptions());
+        assert_eq!(fresh.solve(), response);
+    }
+
+    // Clauses added after solving are recorded as well:
+    solver.add_clause([1]);
+    solver.add_clause([-1]);
+    assert!(solver.solve().is_unsat());
+    let mut fresh = CadicalStaticSolver::default();
+    bootstrap_solver_from_cnf(&mut fresh, solver.recorded_cnf());
+    assert!(fresh.solve().is_unsat());
+}
+
+#[test]
+fn test_recorded_empty_clause_is_replayed() {
+    let mut solver = RecordingSolver::new(SimpleSatSolver::new());
+    solver.add_clause([1, 2]);
+    solver.add_clause(Vec::<i32>::new());
+    assert!(solver.solve().is_unsat());
+    assert!(solver.to_dimacs().ends_with("\n0\n"));
+
+    let mut fresh = CadicalStaticSolver::default();
+    bootstrap_solver_from_cnf(&mut fresh, solver.recorded_cnf());
+    assert!(fresh.solve().is_unsat());
+}
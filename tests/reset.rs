@@ -0,0 +1,59 @@
+use std::time::Duration;
+
+use sat_nexus::core::solver::{LitValue, SolveResponse, Solver};
+use sat_nexus::wrappers::cadical_dynamic::CadicalDynamicSolver;
+use sat_nexus::wrappers::dispatch::DispatchSolver;
+use sat_nexus::wrappers::kissat_dynamic::KissatDynamicSolver;
+use sat_nexus::wrappers::minisat_dynamic::MiniSatDynamicSolver;
+use sat_nexus::wrappers::simplesat::SimpleSatSolver;
+
+fn check_reset<S: Solver>(solver: &mut S) {
+    // (x1 or x2) and (~x1) and (~x2) is UNSAT:
+    solver.add_clause([1, 2]);
+    solver.add_clause([-1]);
+    solver.add_clause([-2]);
+    assert_eq!(solver.solve(), SolveResponse::Unsat);
+
+    solver.reset();
+    assert_eq!(solver.num_clauses(), 0);
+
+    // Unrelated problem: (x1) and (~x1 or x3) is SAT with x1 = x3 = true:
+    solver.add_clause([1]);
+    solver.add_clause([-1, 3]);
+    assert_eq!(solver.solve(), SolveResponse::Sat);
+    assert_eq!(solver.value(1), LitValue::True);
+    assert_eq!(solver.value(3), LitValue::True);
+}
+
+#[test]
+fn test_reset() {
+    for mut solver in [
+        DispatchSolver::new_delegate_wrap(SimpleSatSolver::new()),
+        DispatchSolver::new_cadical(),
+        DispatchSolver::new_kissat(),
+        DispatchSolver::new_minisat(),
+    ] {
+        println!("Checking reset for {}", solver.signature());
+        check_reset(&mut solver);
+    }
+}
+
+// Note: solve times are not a part of the `Solver` trait, so each wrapper is checked separately.
+macro_rules! check_reset_solve_time {
+    ($solver:expr) => {{
+        let mut solver = $solver;
+        check_reset(&mut solver);
+        assert!(solver.total_solve_time() > Duration::ZERO);
+        solver.reset();
+        assert_eq!(solver.last_solve_time(), Duration::ZERO);
+        assert_eq!(solver.total_solve_time(), Duration::ZERO);
+    }};
+}
+
+#[test]
+fn test_reset_solve_time() {
+    check_reset_solve_time!(SimpleSatSolver::new());
+    check_reset_solve_time!(CadicalDynamicSolver::new());
+    check_reset_solve_time!(KissatDynamicSolver::new());
+    check_reset_solve_time!(MiniSatDynamicSolver::new());
+}
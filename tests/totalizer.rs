@@ -37,3 +37,51 @@ fn test_totalizer() {
         });
     assert_eq!(num_solutions, ub - lb + 1);
 }
+
+#[test]
+fn test_exactly_k() {
+    for n in 1..=5 {
+        for k in 0..=n {
+            let mut solver = IpasirSolver::new_cadical();
+            let lits = solver.new_var_vec(n);
+            solver.encode_exactly_k(&lits, k);
+
+            let solutions = solver
+                .all_sat_essential(lits.clone(), |solver| lits.iter().map(|&x| solver.value(x)).collect_vec())
+                .collect_vec();
+            for solution in solutions.iter() {
+                assert_eq!(solution.iter().filter(|x| x.bool()).count(), k, "n = {}, k = {}", n, k);
+            }
+            assert_eq!(solutions.len(), binomial(n, k), "n = {}, k = {}", n, k);
+        }
+    }
+}
+
+#[test]
+fn test_at_least_k() {
+    for n in 1..=5 {
+        for k in 0..=n {
+            let mut solver = IpasirSolver::new_cadical();
+            let lits = solver.new_var_vec(n);
+            solver.encode_at_least_k(&lits, k);
+
+            let solutions = solver
+                .all_sat_essential(lits.clone(), |solver| lits.iter().map(|&x| solver.value(x)).collect_vec())
+                .collect_vec();
+            for solution in solutions.iter() {
+                assert!(solution.iter().filter(|x| x.bool()).count() >= k, "n = {}, k = {}", n, k);
+            }
+            assert_eq!(
+                solutions.len(),
+                (k..=n).map(|i| binomial(n, i)).sum::<usize>(),
+                "n = {}, k = {}",
+                n,
+                k
+            );
+        }
+    }
+}
+
+fn binomial(n: usize, k: usize) -> usize {
+    (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
@@ -0,0 +1,40 @@
+use sat_nexus::core::solver::Solver;
+use sat_nexus::wrappers::cadical_dynamic::CadicalDynamicSolver;
+use sat_nexus::wrappers::cadical_static::CadicalStaticSolver;
+use sat_nexus::wrappers::dispatch::DispatchSolver;
+use sat_nexus::wrappers::ipasir::IpasirSolver;
+use sat_nexus::wrappers::kissat_dynamic::KissatDynamicSolver;
+use sat_nexus::wrappers::kissat_static::KissatStaticSolver;
+use sat_nexus::wrappers::minisat_dynamic::MiniSatDynamicSolver;
+use sat_nexus::wrappers::simplesat::SimpleSatSolver;
+use sat_nexus_test_utils::assert_value_sign_consistency;
+
+/// Solve the formula forcing variables of both polarities and check the values of both literals.
+fn check<S: Solver>(mut solver: S) {
+    solver.reserve_vars(11);
+    // Odd variables are true, even variables are false:
+    for var in 1..=10 {
+        solver.add_clause([if var % 2 == 1 { var } else { -var }]);
+    }
+    solver.add_clause([1, 2, -3, 11]);
+    assert!(solver.solve().is_sat());
+
+    assert_value_sign_consistency(&solver);
+    assert!(solver.value(3).bool());
+    assert!(!solver.value(-3).bool());
+    assert!(!solver.value(4).bool());
+    assert!(solver.value(-4).bool());
+}
+
+#[test]
+fn test_value_sign_consistency() {
+    check(MiniSatDynamicSolver::new());
+    check(CadicalDynamicSolver::new());
+    check(CadicalStaticSolver::default());
+    check(KissatDynamicSolver::new());
+    check(KissatStaticSolver::default());
+    check(IpasirSolver::new_cadical());
+    check(IpasirSolver::new_glucose());
+    check(SimpleSatSolver::new());
+    check(DispatchSolver::new_delegate_wrap(SimpleSatSolver::new()));
+}